@@ -264,6 +264,12 @@ export enum BuiltinPluginName {
   RuntimeChunkPlugin = 'RuntimeChunkPlugin',
   SizeLimitsPlugin = 'SizeLimitsPlugin',
   NoEmitOnErrorsPlugin = 'NoEmitOnErrorsPlugin',
+  DllEntryPlugin = 'DllEntryPlugin',
+  DllReferenceAgencyPlugin = 'DllReferenceAgencyPlugin',
+  LibManifestPlugin = 'LibManifestPlugin',
+  FlagAllModulesAsUsedPlugin = 'FlagAllModulesAsUsedPlugin',
+  NormalModuleReplacementPlugin = 'NormalModuleReplacementPlugin',
+  ContextReplacementPlugin = 'ContextReplacementPlugin',
   HttpExternalsRspackPlugin = 'HttpExternalsRspackPlugin',
   CopyRspackPlugin = 'CopyRspackPlugin',
   HtmlRspackPlugin = 'HtmlRspackPlugin',
@@ -463,12 +469,15 @@ export interface JsContextModuleFactoryAfterResolveData {
   resource: string
   context: string
   request: string
+  recursive: boolean
   regExp?: RawRegex
 }
 
 export interface JsContextModuleFactoryBeforeResolveData {
   context: string
   request?: string
+  recursive: boolean
+  regExp?: RawRegex
 }
 
 export interface JsCreateData {
@@ -492,6 +501,7 @@ export interface JsEntryOptions {
   name?: string
   runtime?: false | string
   chunkLoading?: string
+  wasmLoading?: string
   asyncChunks?: boolean
   publicPath?: "auto" | JsFilename
   baseUri?: string
@@ -812,6 +822,9 @@ export interface JsStatsCompilation {
   modules?: Array<JsStatsModule>
   namedChunkGroups?: Array<JsStatsChunkGroup>
   warnings: Array<JsStatsWarning>
+  /** Number of the assets and modules left out by the exclude filters */
+  filteredAssets?: number
+  filteredModules?: number
 }
 
 export interface JsStatsError {
@@ -937,6 +950,8 @@ export interface JsStatsOptions {
   source: boolean
   usedExports: boolean
   warnings: boolean
+  excludeModules?: (string | RegExp)[]
+  excludeAssets?: (string | RegExp)[]
 }
 
 export interface JsStatsSize {
@@ -1119,6 +1134,14 @@ export interface RawContainerReferencePluginOptions {
   enhanced: boolean
 }
 
+export interface RawContextReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newContentResource?: string
+  newContentRecursive?: boolean
+  newContentRegExp?: RegExp
+  newContentCreateContextMap?: Record<string, string>
+}
+
 export interface RawCopyGlobOptions {
   caseSensitiveMatch?: boolean
   dot?: boolean
@@ -1153,6 +1176,7 @@ export interface RawCssAutoGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  removeUnusedRules?: boolean
 }
 
 export interface RawCssAutoParserOptions {
@@ -1180,6 +1204,7 @@ export interface RawCssModuleGeneratorOptions {
   exportsOnly?: boolean
   localIdentName?: string
   esModule?: boolean
+  removeUnusedRules?: boolean
 }
 
 export interface RawCssModuleParserOptions {
@@ -1190,6 +1215,23 @@ export interface RawCssParserOptions {
   namedExports?: boolean
 }
 
+export interface RawDllEntryPluginOptions {
+  context: string
+  entries: Array<string>
+  name: string
+}
+
+export interface RawDllReferencePluginOptions {
+  context?: string
+  extensions: Array<string>
+  manifest?: Record<string, any>
+  name?: string
+  content?: Record<string, any>
+  scope?: string
+  sourceType?: string
+  type?: "require" | "object"
+}
+
 export interface RawDraft {
   customMedia: boolean
 }
@@ -1207,6 +1249,16 @@ export interface RawEntryDynamicResult {
 export interface RawEnvironment {
   const?: boolean
   arrowFunction?: boolean
+  destructuring?: boolean
+  forOf?: boolean
+  optionalChaining?: boolean
+  templateLiteral?: boolean
+  bigIntLiteral?: boolean
+  dynamicImport?: boolean
+  dynamicImportInWorker?: boolean
+  module?: boolean
+  globalThis?: boolean
+  asyncFunction?: boolean
 }
 
 export interface RawEvalDevToolModulePluginOptions {
@@ -1297,8 +1349,8 @@ export interface RawHtmlRspackPluginOptions {
   templateFn?: (data: string) => Promise<string>
   templateContent?: string
   templateParameters?: boolean | Record<string, any> | ((params: string) => Promise<string>)
-  /** "head", "body" or "false" */
-  inject: "head" | "body" | "false"
+  /** "head", "body", "head-prepend", "body-prepend" or "false" */
+  inject: "head" | "body" | "head-prepend" | "body-prepend" | "false"
   /** path or `auto` */
   publicPath?: string
   /** `blocking`, `defer`, `module` or `systemjs-module` */
@@ -1313,6 +1365,7 @@ export interface RawHtmlRspackPluginOptions {
   meta?: Record<string, Record<string, string>>
   hash?: boolean
   base?: RawHtmlRspackPluginBaseOptions
+  attributes?: (tag: JsHtmlPluginTag) => Promise<Record<string, string | boolean>>
 }
 
 export interface RawHttpExternalsRspackPluginOptions {
@@ -1355,13 +1408,33 @@ export interface RawJavascriptParserOptions {
 }
 
 export interface RawLazyCompilationOption {
-  module: (err: Error | null, arg: RawModuleArg) => any
+  /** The JS backend, used when `server_backend` is unset */
+  module?: (err: Error | null, arg: RawModuleArg) => any
+  serverBackend?: RawLazyCompilationServerBackendOptions
   test?: RawLazyCompilationTest
   entries: boolean
   imports: boolean
   cacheable: boolean
 }
 
+/** Options of the native backend, which serves the clients with its own server. */
+export interface RawLazyCompilationServerBackendOptions {
+  client: string
+  host?: string
+  port?: number
+  /** Called with the files of the activated modules to rebuild them */
+  invalidate: (err: Error | null, files: string[]) => void
+}
+
+export interface RawLibManifestPluginOptions {
+  context?: string
+  entryOnly?: boolean
+  name?: string
+  path: string
+  format?: boolean
+  type?: string
+}
+
 export interface RawLightningCssBrowsers {
   android?: number
   chrome?: number
@@ -1491,15 +1564,20 @@ export interface RawModuleRuleUse {
 }
 
 export interface RawNodeOption {
-  dirname: string
-  filename: string
-  global: string
+  dirname?: string
+  filename?: string
+  global?: string
 }
 
 export interface RawNonStandard {
   deepSelectorCombinator: boolean
 }
 
+export interface RawNormalModuleReplacementPluginOptions {
+  resourceRegExp: RegExp
+  newResource: string
+}
+
 export interface RawOptimizationOptions {
   removeAvailableModules: boolean
   sideEffects: string
@@ -1525,6 +1603,7 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node?: RawNodeOption
+  amd?: string
   profile: boolean
   bail: boolean
   __references: Record<string, any>
@@ -1536,7 +1615,7 @@ export interface RawOutputOptions {
   clean: boolean
   publicPath: "auto" | JsFilename
   assetModuleFilename: JsFilename
-  wasmLoading: string
+  wasmLoading?: string
   enabledWasmLoadingTypes: Array<string>
   webassemblyModuleFilename: string
   filename: JsFilename
@@ -1553,12 +1632,12 @@ export interface RawOutputOptions {
   library?: JsLibraryOptions
   strictModuleErrorHandling: boolean
   enabledLibraryTypes?: Array<string>
-  globalObject: string
+  globalObject?: string
   importFunctionName: string
   importMetaName: string
   iife: boolean
   module: boolean
-  chunkLoading: string
+  chunkLoading?: string
   chunkLoadTimeout: number
   charset: boolean
   enabledChunkLoadingTypes?: Array<string>
@@ -1569,8 +1648,8 @@ export interface RawOutputOptions {
   hashDigestLength: number
   hashSalt?: string
   asyncChunks: boolean
-  workerChunkLoading: string
-  workerWasmLoading: string
+  workerChunkLoading?: string
+  workerWasmLoading?: string
   workerPublicPath: string
   scriptType: "module" | "text/javascript" | "false"
   environment: RawEnvironment
@@ -1724,7 +1803,16 @@ export interface RawSizeLimitsPluginOptions {
 }
 
 export interface RawSnapshotOptions {
+  resolve?: RawSnapshotStrategy
+  module?: RawSnapshotStrategy
+  buildDependencies?: RawSnapshotStrategy
+  managedPaths?: (string | RegExp)[]
+  immutablePaths?: (string | RegExp)[]
+}
 
+export interface RawSnapshotStrategy {
+  hash?: boolean
+  timestamp?: boolean
 }
 
 export interface RawSourceMapDevToolPluginOptions {
@@ -1769,6 +1857,8 @@ export interface RawSplitChunksOptions {
 
 export interface RawStatsOptions {
   colors: boolean
+  excludeModules?: (string | RegExp)[]
+  excludeAssets?: (string | RegExp)[]
 }
 
 export interface RawSwcJsMinimizerOptions {
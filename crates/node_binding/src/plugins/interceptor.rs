@@ -1510,6 +1510,8 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
         JsContextModuleFactoryBeforeResolveResult::B(JsContextModuleFactoryBeforeResolveData {
           context: d.context,
           request: d.request,
+          recursive: d.recursive,
+          reg_exp: d.reg_exp.map(|r| r.into()),
         })
       }
     };
@@ -1520,6 +1522,11 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
           let data = BeforeResolveData {
             context: d.context,
             request: d.request,
+            recursive: d.recursive,
+            reg_exp: match d.reg_exp {
+              Some(r) => Some(r.try_into()?),
+              None => None,
+            },
           };
           Ok(BeforeResolveResult::Data(Box::new(data)))
         }
@@ -1536,13 +1543,16 @@ impl ContextModuleFactoryBeforeResolve for ContextModuleFactoryBeforeResolveTap
 #[async_trait]
 impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
   async fn run(&self, result: AfterResolveResult) -> rspack_error::Result<AfterResolveResult> {
+    let mut resolve_dependencies = None;
     let js_result = match result {
       AfterResolveResult::Ignored => JsContextModuleFactoryAfterResolveResult::A(false),
       AfterResolveResult::Data(d) => {
+        resolve_dependencies = d.resolve_dependencies;
         JsContextModuleFactoryAfterResolveResult::B(JsContextModuleFactoryAfterResolveData {
           resource: d.resource.as_str().to_owned(),
           context: d.context.to_owned(),
           request: d.request.to_owned(),
+          recursive: d.recursive,
           reg_exp: d.reg_exp.clone().map(|r| r.into()),
         })
       }
@@ -1554,10 +1564,12 @@ impl ContextModuleFactoryAfterResolve for ContextModuleFactoryAfterResolveTap {
           resource: d.resource.into(),
           context: d.context,
           request: d.request,
+          recursive: d.recursive,
           reg_exp: match d.reg_exp {
             Some(r) => Some(r.try_into()?),
             None => None,
           },
+          resolve_dependencies,
         };
         Ok(AfterResolveResult::Data(Box::new(data)))
       }
@@ -53,6 +53,7 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: Option<RawNodeOption>,
+  pub amd: Option<String>,
   pub profile: bool,
   pub bail: bool,
   #[napi(js_name = "__references", ts_type = "Record<string, any>")]
@@ -64,12 +65,16 @@ impl TryFrom<RawOptions> for CompilerOptions {
 
   fn try_from(value: RawOptions) -> Result<Self, rspack_error::Error> {
     let context: Context = value.context.into();
-    let output: OutputOptions = value.output.try_into()?;
+    let target = Target::new(&value.target, &context)?;
+    // the options left unset fall back to the defaults of the target platform
+    let platform = target.platform_or_web();
+    let output_module = value.output.module;
+    let mut output: OutputOptions = value.output.into_output_options(&platform)?;
     let resolve = value.resolve.try_into()?;
     let resolve_loader = value.resolve_loader.try_into()?;
     let mode = value.mode.unwrap_or_default().into();
     let module: ModuleOptions = value.module.try_into()?;
-    let target = Target::new(&value.target)?;
+    output.environment.fill_unset(&target.environment);
     let cache = value.cache.into();
     let experiments = Experiments {
       incremental_rebuild: IncrementalRebuild {
@@ -87,7 +92,10 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let optimization = value.optimization.try_into()?;
     let stats = value.stats.into();
     let snapshot = value.snapshot.into();
-    let node = value.node.map(|n| n.into());
+    let node = value
+      .node
+      .map(|n| n.into_node_option(platform.default_node_option(output_module)))
+      .transpose()?;
 
     Ok(CompilerOptions {
       context,
@@ -103,6 +111,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       snapshot,
       optimization,
       node,
+      amd: value.amd,
       dev_server: Default::default(),
       profile: value.profile,
       bail: value.bail,
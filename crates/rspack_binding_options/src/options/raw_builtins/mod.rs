@@ -1,13 +1,16 @@
 mod raw_banner;
 mod raw_bundle_info;
+mod raw_context_replacement;
 mod raw_copy;
 mod raw_css_extract;
+mod raw_dll;
 mod raw_html;
 mod raw_ignore;
 mod raw_lazy_compilation;
 mod raw_lightning_css_minimizer;
 mod raw_limit_chunk_count;
 mod raw_mf;
+mod raw_normal_module_replacement;
 mod raw_progress;
 mod raw_runtime_chunk;
 mod raw_size_limits;
@@ -17,7 +20,7 @@ use napi::{bindgen_prelude::FromNapiValue, Env, JsUnknown};
 use napi_derive::napi;
 use raw_lightning_css_minimizer::RawLightningCssMinimizerRspackPluginOptions;
 use rspack_binding_values::entry::JsEntryPluginOptions;
-use rspack_core::{BoxPlugin, Plugin, PluginExt};
+use rspack_core::{BoxPlugin, PluginExt};
 use rspack_error::Result;
 use rspack_ids::{
   DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, NamedChunkIdsPlugin,
@@ -26,6 +29,7 @@ use rspack_ids::{
 use rspack_napi::NapiResultExt;
 use rspack_plugin_asset::AssetPlugin;
 use rspack_plugin_banner::BannerPlugin;
+use rspack_plugin_context_replacement::ContextReplacementPlugin;
 use rspack_plugin_copy::{CopyRspackPlugin, CopyRspackPluginOptions};
 use rspack_plugin_css::CssPlugin;
 use rspack_plugin_devtool::{
@@ -33,6 +37,9 @@ use rspack_plugin_devtool::{
   SourceMapDevToolModuleOptionsPluginOptions, SourceMapDevToolPlugin,
   SourceMapDevToolPluginOptions,
 };
+use rspack_plugin_dll::{
+  DllEntryPlugin, DllReferencePlugin, FlagAllModulesAsUsedPlugin, LibManifestPlugin,
+};
 use rspack_plugin_dynamic_entry::DynamicEntryPlugin;
 use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
 use rspack_plugin_entry::EntryPlugin;
@@ -48,6 +55,9 @@ use rspack_plugin_javascript::{
   MangleExportsPlugin, ModuleConcatenationPlugin, SideEffectsFlagPlugin,
 };
 use rspack_plugin_json::JsonPlugin;
+use rspack_plugin_lazy_compilation::{
+  plugin::LazyCompilationPlugin, server_backend::ServerBackend,
+};
 use rspack_plugin_library::enable_library_plugin;
 use rspack_plugin_lightning_css_minimizer::LightningCssMinimizerRspackPlugin;
 use rspack_plugin_limit_chunk_count::LimitChunkCountPlugin;
@@ -57,6 +67,7 @@ use rspack_plugin_mf::{
   ProvideSharedPlugin, ShareRuntimePlugin,
 };
 use rspack_plugin_no_emit_on_errors::NoEmitOnErrorsPlugin;
+use rspack_plugin_normal_module_replacement::NormalModuleReplacementPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use rspack_plugin_real_content_hash::RealContentHashPlugin;
 use rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin;
@@ -84,9 +95,12 @@ pub use self::{
 };
 use self::{
   raw_bundle_info::{RawBundlerInfoModeWrapper, RawBundlerInfoPluginOptions},
+  raw_context_replacement::RawContextReplacementPluginOptions,
   raw_css_extract::RawCssExtractPluginOption,
+  raw_dll::{RawDllEntryPluginOptions, RawDllReferencePluginOptions, RawLibManifestPluginOptions},
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
   raw_mf::{RawConsumeSharedPluginOptions, RawContainerReferencePluginOptions, RawProvideOptions},
+  raw_normal_module_replacement::RawNormalModuleReplacementPluginOptions,
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
 };
@@ -162,6 +176,12 @@ pub enum BuiltinPluginName {
   RuntimeChunkPlugin,
   SizeLimitsPlugin,
   NoEmitOnErrorsPlugin,
+  DllEntryPlugin,
+  DllReferenceAgencyPlugin,
+  LibManifestPlugin,
+  FlagAllModulesAsUsedPlugin,
+  NormalModuleReplacementPlugin,
+  ContextReplacementPlugin,
 
   // rspack specific plugins
   // naming format follow XxxRspackPlugin
@@ -219,7 +239,7 @@ impl BuiltinPlugin {
         let plugin_options = downcast_into::<JsEntryPluginOptions>(self.options)?;
         let context = plugin_options.context.into();
         let entry_request = plugin_options.entry;
-        let options = plugin_options.options.into();
+        let options = plugin_options.options.try_into()?;
         let plugin = EntryPlugin::new(context, entry_request, options).boxed();
         plugins.push(plugin);
       }
@@ -256,7 +276,7 @@ impl BuiltinPlugin {
       BuiltinPluginName::EnableWasmLoadingPlugin => {
         let wasm_loading_type = downcast_into::<String>(self.options)?;
         plugins.push(enable_wasm_loading_plugin(
-          wasm_loading_type.as_str().into(),
+          wasm_loading_type.as_str().try_into()?,
         ));
       }
       BuiltinPluginName::FetchCompileAsyncWasmPlugin => {
@@ -433,6 +453,42 @@ impl BuiltinPlugin {
             .boxed();
         plugins.push(plugin)
       }
+      BuiltinPluginName::DllEntryPlugin => {
+        let plugin =
+          DllEntryPlugin::from(downcast_into::<RawDllEntryPluginOptions>(self.options)?).boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::DllReferenceAgencyPlugin => {
+        let plugin = DllReferencePlugin::new(
+          downcast_into::<RawDllReferencePluginOptions>(self.options)?.try_into()?,
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::LibManifestPlugin => {
+        let plugin = LibManifestPlugin::new(
+          downcast_into::<RawLibManifestPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::FlagAllModulesAsUsedPlugin => {
+        plugins.push(FlagAllModulesAsUsedPlugin::default().boxed())
+      }
+      BuiltinPluginName::NormalModuleReplacementPlugin => {
+        let plugin = NormalModuleReplacementPlugin::new(
+          downcast_into::<RawNormalModuleReplacementPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::ContextReplacementPlugin => {
+        let plugin = ContextReplacementPlugin::new(
+          downcast_into::<RawContextReplacementPluginOptions>(self.options)?.into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
 
       // rspack specific plugins
       BuiltinPluginName::HttpExternalsRspackPlugin => {
@@ -493,16 +549,29 @@ impl BuiltinPlugin {
       }
       BuiltinPluginName::LazyCompilationPlugin => {
         let options = downcast_into::<RawLazyCompilationOption>(self.options)?;
-        let js_backend = JsBackend::from(&options);
-        plugins.push(Box::new(
-          rspack_plugin_lazy_compilation::plugin::LazyCompilationPlugin::new(
+        let test = options.test.map(|test| test.into());
+        let plugin = match (options.server_backend, options.module) {
+          (Some(server_backend), _) => LazyCompilationPlugin::new(
             options.cacheable,
-            js_backend,
-            options.test.map(|test| test.into()),
+            ServerBackend::try_from(server_backend)?,
+            test,
             options.entries,
             options.imports,
-          ),
-        ) as Box<dyn Plugin>)
+          )
+          .boxed(),
+          (None, Some(module)) => LazyCompilationPlugin::new(
+            options.cacheable,
+            JsBackend::new(module),
+            test,
+            options.entries,
+            options.imports,
+          )
+          .boxed(),
+          (None, None) => return Err(rspack_error::error!(
+            "LazyCompilationPlugin requires either a module function or the server backend options"
+          )),
+        };
+        plugins.push(plugin);
       }
       BuiltinPluginName::NoEmitOnErrorsPlugin => {
         plugins.push(NoEmitOnErrorsPlugin::default().boxed());
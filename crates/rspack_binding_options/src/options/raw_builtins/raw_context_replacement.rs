@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use napi_derive::napi;
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+use rspack_plugin_context_replacement::ContextReplacementPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawContextReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  #[napi(ts_type = "RegExp")]
+  pub new_content_reg_exp: Option<JsRegExp>,
+  pub new_content_create_context_map: Option<HashMap<String, String>>,
+}
+
+impl From<RawContextReplacementPluginOptions> for ContextReplacementPluginOptions {
+  fn from(value: RawContextReplacementPluginOptions) -> Self {
+    Self {
+      resource_reg_exp: value.resource_reg_exp.to_rspack_regex(),
+      new_content_resource: value.new_content_resource,
+      new_content_recursive: value.new_content_recursive,
+      new_content_reg_exp: value
+        .new_content_reg_exp
+        .map(|new_content_reg_exp| new_content_reg_exp.to_rspack_regex()),
+      new_content_create_context_map: value
+        .new_content_create_context_map
+        .map(|context_map| context_map.into_iter().collect()),
+    }
+  }
+}
@@ -0,0 +1,102 @@
+use napi_derive::napi;
+use rspack_core::EntryOptions;
+use rspack_error::{miette::IntoDiagnostic, Result};
+use rspack_plugin_dll::{
+  DllEntryPlugin, DllReferencePluginOptions, DllReferenceType, LibManifestPluginOptions,
+};
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawDllEntryPluginOptions {
+  pub context: String,
+  pub entries: Vec<String>,
+  pub name: String,
+}
+
+impl From<RawDllEntryPluginOptions> for DllEntryPlugin {
+  fn from(value: RawDllEntryPluginOptions) -> Self {
+    DllEntryPlugin::new(
+      value.context.into(),
+      value.entries,
+      EntryOptions {
+        name: Some(value.name),
+        ..Default::default()
+      },
+    )
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawLibManifestPluginOptions {
+  pub context: Option<String>,
+  pub entry_only: Option<bool>,
+  pub name: Option<String>,
+  pub path: String,
+  pub format: Option<bool>,
+  pub r#type: Option<String>,
+}
+
+impl From<RawLibManifestPluginOptions> for LibManifestPluginOptions {
+  fn from(value: RawLibManifestPluginOptions) -> Self {
+    Self {
+      context: value.context.map(Into::into),
+      entry_only: value.entry_only.unwrap_or(true),
+      name: value.name.map(Into::into),
+      path: value.path.into(),
+      format: value.format.unwrap_or_default(),
+      r#type: value.r#type,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawDllReferencePluginOptions {
+  pub context: Option<String>,
+  pub extensions: Vec<String>,
+  #[napi(ts_type = "Record<string, any>")]
+  pub manifest: Option<serde_json::Value>,
+  pub name: Option<String>,
+  #[napi(ts_type = "Record<string, any>")]
+  pub content: Option<serde_json::Value>,
+  pub scope: Option<String>,
+  pub source_type: Option<String>,
+  #[napi(ts_type = "\"require\" | \"object\"")]
+  pub r#type: Option<String>,
+}
+
+impl TryFrom<RawDllReferencePluginOptions> for DllReferencePluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawDllReferencePluginOptions) -> Result<Self> {
+    let r#type = match value.r#type.as_deref() {
+      None | Some("require") => DllReferenceType::Require,
+      Some("object") => DllReferenceType::Object,
+      Some(r#type) => {
+        return Err(rspack_error::error!(
+          "DllReferencePlugin: unknown type \"{}\", expected \"require\" or \"object\"",
+          r#type
+        ))
+      }
+    };
+    Ok(Self {
+      context: value.context.map(Into::into),
+      extensions: value.extensions,
+      manifest: value
+        .manifest
+        .map(serde_json::from_value)
+        .transpose()
+        .into_diagnostic()?,
+      name: value.name,
+      content: value
+        .content
+        .map(serde_json::from_value)
+        .transpose()
+        .into_diagnostic()?,
+      scope: value.scope,
+      source_type: value.source_type,
+      r#type,
+    })
+  }
+}
@@ -2,16 +2,20 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use napi::bindgen_prelude::Either3;
+use napi::Either;
 use napi_derive::napi;
+use rspack_binding_values::JsHtmlPluginTag;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_html::config::HtmlInject;
 use rspack_plugin_html::config::HtmlRspackPluginBaseOptions;
 use rspack_plugin_html::config::HtmlRspackPluginOptions;
 use rspack_plugin_html::config::HtmlScriptLoading;
+use rspack_plugin_html::config::TagAttributesFn;
 use rspack_plugin_html::config::TemplateParameterFn;
 use rspack_plugin_html::config::TemplateParameters;
 use rspack_plugin_html::config::TemplateRenderFn;
 use rspack_plugin_html::sri::HtmlSriHashFunction;
+use rspack_plugin_html::tag::HtmlPluginAttribute;
 
 pub type RawHtmlScriptLoading = String;
 pub type RawHtmlInject = String;
@@ -20,6 +24,9 @@ pub type RawHtmlFilename = String;
 
 type RawTemplateRenderFn = ThreadsafeFunction<String, String>;
 
+type RawTagAttributesFn =
+  ThreadsafeFunction<JsHtmlPluginTag, HashMap<String, Either<String, bool>>>;
+
 type RawTemplateParameter =
   Either3<HashMap<String, String>, bool, ThreadsafeFunction<String, String>>;
 
@@ -36,8 +43,8 @@ pub struct RawHtmlRspackPluginOptions {
   pub template_content: Option<String>,
   #[napi(ts_type = "boolean | Record<string, any> | ((params: string) => Promise<string>)")]
   pub template_parameters: Option<RawTemplateParameter>,
-  /// "head", "body" or "false"
-  #[napi(ts_type = "\"head\" | \"body\" | \"false\"")]
+  /// "head", "body", "head-prepend", "body-prepend" or "false"
+  #[napi(ts_type = "\"head\" | \"body\" | \"head-prepend\" | \"body-prepend\" | \"false\"")]
   pub inject: RawHtmlInject,
   /// path or `auto`
   pub public_path: Option<String>,
@@ -56,6 +63,8 @@ pub struct RawHtmlRspackPluginOptions {
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
   pub hash: Option<bool>,
   pub base: Option<RawHtmlRspackPluginBaseOptions>,
+  #[napi(ts_type = "(tag: JsHtmlPluginTag) => Promise<Record<string, string | boolean>>")]
+  pub attributes: Option<RawTagAttributesFn>,
 }
 
 impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
@@ -110,6 +119,30 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       meta: value.meta,
       hash: value.hash,
       base: value.base.map(|v| v.into()),
+      attributes: value.attributes.map(|func| TagAttributesFn {
+        inner: Box::new(move |tag| {
+          let f = func.clone();
+          Box::pin(async move {
+            let attributes = f.call(tag.into()).await?;
+            Ok(
+              attributes
+                .into_iter()
+                .filter_map(|(attr_name, attr_value)| match attr_value {
+                  Either::A(attr_value) => Some(HtmlPluginAttribute {
+                    attr_name,
+                    attr_value: Some(attr_value),
+                  }),
+                  Either::B(true) => Some(HtmlPluginAttribute {
+                    attr_name,
+                    attr_value: None,
+                  }),
+                  Either::B(false) => None,
+                })
+                .collect(),
+            )
+          })
+        }),
+      }),
     }
   }
 }
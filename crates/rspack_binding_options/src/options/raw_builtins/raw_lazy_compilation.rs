@@ -1,3 +1,8 @@
+use std::{
+  net::{SocketAddr, ToSocketAddrs},
+  sync::Arc,
+};
+
 use napi::{
   bindgen_prelude::{FromNapiValue, ToNapiValue, ValidateNapiValue},
   Either,
@@ -5,10 +10,12 @@ use napi::{
 use napi_derive::napi;
 use rspack_binding_values::{JsModule, RawRegex, ToJsModule};
 use rspack_core::ModuleIdentifier;
+use rspack_error::error;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_lazy_compilation::{
   backend::{Backend, ModuleInfo},
   plugin::{LazyCompilationTest, LazyCompilationTestCheck},
+  server_backend::{ServerBackend, ServerBackendOptions},
 };
 use rspack_regex::RspackRegex;
 
@@ -77,13 +84,56 @@ pub struct RawModuleInfo {
 
 #[napi(object, object_to_js = false)]
 pub struct RawLazyCompilationOption {
-  pub module: ThreadsafeFunction<RawModuleArg, RawModuleInfo>,
+  /// The JS backend, used when `server_backend` is unset
+  pub module: Option<ThreadsafeFunction<RawModuleArg, RawModuleInfo>>,
+  pub server_backend: Option<RawLazyCompilationServerBackendOptions>,
   pub test: Option<RawLazyCompilationTest>,
   pub entries: bool,
   pub imports: bool,
   pub cacheable: bool,
 }
 
+/// Options of the native backend, which serves the clients with its own server.
+#[napi(object, object_to_js = false)]
+pub struct RawLazyCompilationServerBackendOptions {
+  pub client: String,
+  pub host: Option<String>,
+  pub port: Option<u32>,
+  /// Called with the files of the activated modules to rebuild them
+  #[napi(ts_type = "(err: Error | null, files: string[]) => void")]
+  pub invalidate: ThreadsafeFunction<Vec<String>, ()>,
+}
+
+impl TryFrom<RawLazyCompilationServerBackendOptions> for ServerBackend {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawLazyCompilationServerBackendOptions) -> rspack_error::Result<Self> {
+    let mut options = ServerBackendOptions {
+      client: value.client,
+      ..Default::default()
+    };
+    let port = value
+      .port
+      .map(u16::try_from)
+      .transpose()
+      .map_err(|_| error!("Invalid port of the lazy compilation server"))?
+      .unwrap_or(options.listen.port());
+    options.listen = match value.host {
+      Some(host) => (host.as_str(), port)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addrs| addrs.next())
+        .ok_or_else(|| error!("Invalid host \"{host}\" of the lazy compilation server"))?,
+      None => SocketAddr::new(options.listen.ip(), port),
+    };
+    let invalidate = value.invalidate;
+    options.invalidate = Some(Arc::new(move |files| {
+      invalidate.call_with_fatal(files.into_iter().collect())
+    }));
+    Ok(ServerBackend::new(options))
+  }
+}
+
 #[napi(object)]
 pub struct RawModuleArg {
   pub module: String,
@@ -100,11 +150,9 @@ impl std::fmt::Debug for JsBackend {
   }
 }
 
-impl From<&RawLazyCompilationOption> for JsBackend {
-  fn from(value: &RawLazyCompilationOption) -> Self {
-    Self {
-      module: value.module.clone(),
-    }
+impl JsBackend {
+  pub(crate) fn new(module: ThreadsafeFunction<RawModuleArg, RawModuleInfo>) -> Self {
+    Self { module }
   }
 }
 
@@ -0,0 +1,20 @@
+use napi_derive::napi;
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+use rspack_plugin_normal_module_replacement::NormalModuleReplacementPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawNormalModuleReplacementPluginOptions {
+  #[napi(ts_type = "RegExp")]
+  pub resource_reg_exp: JsRegExp,
+  pub new_resource: String,
+}
+
+impl From<RawNormalModuleReplacementPluginOptions> for NormalModuleReplacementPluginOptions {
+  fn from(value: RawNormalModuleReplacementPluginOptions) -> Self {
+    Self {
+      resource_reg_exp: value.resource_reg_exp.to_rspack_regex(),
+      new_resource: value.new_resource,
+    }
+  }
+}
@@ -1,5 +1,6 @@
 use napi_derive::napi;
 use rspack_binding_values::entry::JsEntryOptions;
+use rspack_error::Result;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_dynamic_entry::{DynamicEntryPluginOptions, EntryDynamicResult};
 
@@ -28,16 +29,15 @@ impl From<RawDynamicEntryPluginOptions> for DynamicEntryPluginOptions {
         let f = opts.entry.clone();
         Box::pin(async move {
           let raw_result = f.call(()).await?;
-          let result = raw_result
+          raw_result
             .into_iter()
-            .map(
-              |RawEntryDynamicResult { import, options }| EntryDynamicResult {
+            .map(|RawEntryDynamicResult { import, options }| {
+              Ok(EntryDynamicResult {
                 import,
-                options: options.into(),
-              },
-            )
-            .collect::<Vec<_>>();
-          Ok(result)
+                options: options.try_into()?,
+              })
+            })
+            .collect::<Result<Vec<_>>>()
         })
       }),
     }
@@ -590,6 +590,7 @@ pub struct RawCssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
@@ -599,6 +600,7 @@ impl From<RawCssAutoGeneratorOptions> for CssAutoGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      remove_unused_rules: value.remove_unused_rules,
     }
   }
 }
@@ -611,6 +613,7 @@ pub struct RawCssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<String>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
@@ -620,6 +623,7 @@ impl From<RawCssModuleGeneratorOptions> for CssModuleGeneratorOptions {
       exports_only: value.exports_only,
       local_ident_name: value.local_ident_name.map(|n| n.into()),
       es_module: value.es_module,
+      remove_unused_rules: value.remove_unused_rules,
     }
   }
 }
@@ -4,17 +4,27 @@ use rspack_core::NodeOption;
 #[derive(Debug, Default)]
 #[napi(object)]
 pub struct RawNodeOption {
-  pub dirname: String,
-  pub filename: String,
-  pub global: String,
+  pub dirname: Option<String>,
+  pub filename: Option<String>,
+  pub global: Option<String>,
 }
 
-impl From<RawNodeOption> for NodeOption {
-  fn from(value: RawNodeOption) -> Self {
-    Self {
-      dirname: value.dirname,
-      filename: value.filename,
-      global: value.global,
-    }
+impl RawNodeOption {
+  /// Convert to [NodeOption], the values left unset fall back to the ones of `default`.
+  pub fn into_node_option(self, default: NodeOption) -> rspack_error::Result<NodeOption> {
+    Ok(NodeOption {
+      dirname: match self.dirname {
+        Some(dirname) => dirname.as_str().try_into()?,
+        None => default.dirname,
+      },
+      filename: match self.filename {
+        Some(filename) => filename.as_str().try_into()?,
+        None => default.filename,
+      },
+      global: match self.global {
+        Some(global) => global.as_str().try_into()?,
+        None => default.global,
+      },
+    })
   }
 }
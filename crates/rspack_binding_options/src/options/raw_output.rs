@@ -2,8 +2,8 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_binding_values::library::JsLibraryOptions;
 use rspack_binding_values::JsFilename;
-use rspack_core::{CrossOriginLoading, Environment, PathInfo};
-use rspack_core::{OutputOptions, TrustedTypes};
+use rspack_core::{ChunkLoading, CrossOriginLoading, Environment, PathInfo, WasmLoading};
+use rspack_core::{OutputOptions, TargetPlatform, TrustedTypes};
 
 #[derive(Debug)]
 #[napi(object)]
@@ -47,6 +47,16 @@ impl From<RawCrossOriginLoading> for CrossOriginLoading {
 pub struct RawEnvironment {
   pub r#const: Option<bool>,
   pub arrow_function: Option<bool>,
+  pub destructuring: Option<bool>,
+  pub for_of: Option<bool>,
+  pub optional_chaining: Option<bool>,
+  pub template_literal: Option<bool>,
+  pub big_int_literal: Option<bool>,
+  pub dynamic_import: Option<bool>,
+  pub dynamic_import_in_worker: Option<bool>,
+  pub module: Option<bool>,
+  pub global_this: Option<bool>,
+  pub async_function: Option<bool>,
 }
 
 impl From<RawEnvironment> for Environment {
@@ -54,6 +64,16 @@ impl From<RawEnvironment> for Environment {
     Self {
       r#const: value.r#const,
       arrow_function: value.arrow_function,
+      destructuring: value.destructuring,
+      for_of: value.for_of,
+      optional_chaining: value.optional_chaining,
+      template_literal: value.template_literal,
+      big_int_literal: value.big_int_literal,
+      dynamic_import: value.dynamic_import,
+      dynamic_import_in_worker: value.dynamic_import_in_worker,
+      module: value.module,
+      global_this: value.global_this,
+      async_function: value.async_function,
     }
   }
 }
@@ -68,7 +88,7 @@ pub struct RawOutputOptions {
   #[napi(ts_type = "\"auto\" | JsFilename")]
   pub public_path: JsFilename,
   pub asset_module_filename: JsFilename,
-  pub wasm_loading: String,
+  pub wasm_loading: Option<String>,
   pub enabled_wasm_loading_types: Vec<String>,
   pub webassembly_module_filename: String,
   pub filename: JsFilename,
@@ -85,12 +105,12 @@ pub struct RawOutputOptions {
   pub library: Option<JsLibraryOptions>,
   pub strict_module_error_handling: bool,
   pub enabled_library_types: Option<Vec<String>>,
-  pub global_object: String,
+  pub global_object: Option<String>,
   pub import_function_name: String,
   pub import_meta_name: String,
   pub iife: bool,
   pub module: bool,
-  pub chunk_loading: String,
+  pub chunk_loading: Option<String>,
   pub chunk_load_timeout: u32,
   pub charset: bool,
   pub enabled_chunk_loading_types: Option<Vec<String>>,
@@ -101,65 +121,89 @@ pub struct RawOutputOptions {
   pub hash_digest_length: u32,
   pub hash_salt: Option<String>,
   pub async_chunks: bool,
-  pub worker_chunk_loading: String,
-  pub worker_wasm_loading: String,
+  pub worker_chunk_loading: Option<String>,
+  pub worker_wasm_loading: Option<String>,
   pub worker_public_path: String,
   #[napi(ts_type = r#""module" | "text/javascript" | "false""#)]
   pub script_type: String,
   pub environment: RawEnvironment,
 }
 
-impl TryFrom<RawOutputOptions> for OutputOptions {
-  type Error = rspack_error::Error;
-
-  fn try_from(value: RawOutputOptions) -> rspack_error::Result<Self> {
-    let pathinfo = match value.pathinfo {
+impl RawOutputOptions {
+  /// Convert to [OutputOptions], the chunk loadings, wasm loadings and global object left unset
+  /// fall back to the defaults of the target `platform`.
+  pub fn into_output_options(
+    self,
+    platform: &TargetPlatform,
+  ) -> rspack_error::Result<OutputOptions> {
+    let pathinfo = match self.pathinfo {
       Either::A(b) => PathInfo::Bool(b),
       Either::B(s) => PathInfo::String(s),
     };
+    let wasm_loading = match self.wasm_loading {
+      Some(wasm_loading) => wasm_loading.as_str().try_into()?,
+      None => WasmLoading::Enable(platform.default_wasm_loading(self.module)),
+    };
+    let chunk_loading = match self.chunk_loading {
+      Some(chunk_loading) => chunk_loading.as_str().into(),
+      None => ChunkLoading::Enable(platform.default_chunk_loading(self.module)),
+    };
+    let worker_chunk_loading = match self.worker_chunk_loading {
+      Some(worker_chunk_loading) => worker_chunk_loading.as_str().into(),
+      None => ChunkLoading::Enable(platform.default_worker_chunk_loading(self.module)),
+    };
+    // workers load wasm like the chunks of the entries by default
+    let worker_wasm_loading = match self.worker_wasm_loading {
+      Some(worker_wasm_loading) => worker_wasm_loading.as_str().try_into()?,
+      None => wasm_loading.clone(),
+    };
+    let global_object = self
+      .global_object
+      .unwrap_or_else(|| platform.default_global_object().to_string());
 
     Ok(OutputOptions {
-      path: value.path.into(),
+      path: self.path.into(),
       pathinfo,
-      clean: value.clean,
-      public_path: value.public_path.into(),
-      asset_module_filename: value.asset_module_filename.into(),
-      wasm_loading: value.wasm_loading.as_str().into(),
-      webassembly_module_filename: value.webassembly_module_filename.into(),
-      unique_name: value.unique_name,
-      chunk_loading: value.chunk_loading.as_str().into(),
-      chunk_loading_global: value.chunk_loading_global.as_str().into(),
-      filename: value.filename.into(),
-      chunk_filename: value.chunk_filename.into(),
-      cross_origin_loading: value.cross_origin_loading.into(),
-      css_filename: value.css_filename.into(),
-      css_chunk_filename: value.css_chunk_filename.into(),
-      css_head_data_compression: value.css_head_data_compression,
-      hot_update_main_filename: value.hot_update_main_filename.into(),
-      hot_update_chunk_filename: value.hot_update_chunk_filename.into(),
-      hot_update_global: value.hot_update_global,
-      library: value.library.map(Into::into),
-      strict_module_error_handling: value.strict_module_error_handling,
-      enabled_library_types: value.enabled_library_types,
-      global_object: value.global_object,
-      import_function_name: value.import_function_name,
-      import_meta_name: value.import_meta_name,
-      iife: value.iife,
-      module: value.module,
-      trusted_types: value.trusted_types.map(Into::into),
-      source_map_filename: value.source_map_filename.into(),
-      hash_function: value.hash_function.as_str().into(),
-      hash_digest: value.hash_digest.as_str().into(),
-      hash_digest_length: value.hash_digest_length as usize,
-      hash_salt: value.hash_salt.into(),
-      async_chunks: value.async_chunks,
-      worker_chunk_loading: value.worker_chunk_loading.as_str().into(),
-      worker_wasm_loading: value.worker_wasm_loading.as_str().into(),
-      worker_public_path: value.worker_public_path,
-      script_type: value.script_type,
-      environment: value.environment.into(),
-      charset: value.charset,
-      chunk_load_timeout: value.chunk_load_timeout,
+      clean: self.clean,
+      public_path: self.public_path.into(),
+      asset_module_filename: self.asset_module_filename.into(),
+      wasm_loading,
+      webassembly_module_filename: self.webassembly_module_filename.into(),
+      unique_name: self.unique_name,
+      chunk_loading,
+      custom_chunk_loading_types: Default::default(),
+      chunk_loading_global: self.chunk_loading_global.as_str().into(),
+      filename: self.filename.into(),
+      chunk_filename: self.chunk_filename.into(),
+      cross_origin_loading: self.cross_origin_loading.into(),
+      css_filename: self.css_filename.into(),
+      css_chunk_filename: self.css_chunk_filename.into(),
+      css_head_data_compression: self.css_head_data_compression,
+      hot_update_main_filename: self.hot_update_main_filename.into(),
+      hot_update_chunk_filename: self.hot_update_chunk_filename.into(),
+      hot_update_global: self.hot_update_global,
+      library: self.library.map(Into::into),
+      strict_module_error_handling: self.strict_module_error_handling,
+      enabled_library_types: self.enabled_library_types,
+      global_object,
+      import_function_name: self.import_function_name,
+      import_meta_name: self.import_meta_name,
+      iife: self.iife,
+      module: self.module,
+      trusted_types: self.trusted_types.map(Into::into),
+      source_map_filename: self.source_map_filename.into(),
+      hash_function: self.hash_function.as_str().try_into()?,
+      hash_digest: self.hash_digest.as_str().try_into()?,
+      hash_digest_length: self.hash_digest_length as usize,
+      hash_salt: self.hash_salt.into(),
+      async_chunks: self.async_chunks,
+      worker_chunk_loading,
+      worker_wasm_loading,
+      worker_public_path: self.worker_public_path,
+      script_type: self.script_type,
+      environment: self.environment.into(),
+      charset: self.charset,
+      chunk_load_timeout: self.chunk_load_timeout,
     })
   }
 }
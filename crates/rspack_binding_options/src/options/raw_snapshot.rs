@@ -1,12 +1,66 @@
+use napi::Either;
 use napi_derive::napi;
-use rspack_core::SnapshotOptions;
+use rspack_core::{SnapshotOptions, SnapshotPath, SnapshotStrategy};
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+
+type RawSnapshotPath = Either<String, JsRegExp>;
 
 #[derive(Debug, Default)]
 #[napi(object)]
-pub struct RawSnapshotOptions;
+pub struct RawSnapshotStrategy {
+  pub hash: Option<bool>,
+  pub timestamp: Option<bool>,
+}
+
+impl From<RawSnapshotStrategy> for SnapshotStrategy {
+  fn from(value: RawSnapshotStrategy) -> Self {
+    SnapshotStrategy::new(
+      value.timestamp.unwrap_or_default(),
+      value.hash.unwrap_or_default(),
+    )
+  }
+}
+
+#[derive(Debug, Default)]
+#[napi(object, object_to_js = false)]
+pub struct RawSnapshotOptions {
+  pub resolve: Option<RawSnapshotStrategy>,
+  pub module: Option<RawSnapshotStrategy>,
+  pub build_dependencies: Option<RawSnapshotStrategy>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub managed_paths: Option<Vec<RawSnapshotPath>>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub immutable_paths: Option<Vec<RawSnapshotPath>>,
+}
+
+fn into_snapshot_paths(paths: Vec<RawSnapshotPath>) -> Vec<SnapshotPath> {
+  paths
+    .into_iter()
+    .map(|path| match path {
+      Either::A(s) => SnapshotPath::String(s),
+      Either::B(r) => SnapshotPath::Regexp(r.to_rspack_regex()),
+    })
+    .collect()
+}
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
-  fn from(_value: RawSnapshotOptions) -> Self {
-    SnapshotOptions
+  fn from(value: RawSnapshotOptions) -> Self {
+    let default = SnapshotOptions::default();
+    Self {
+      resolve: value.resolve.map(Into::into).unwrap_or(default.resolve),
+      module: value.module.map(Into::into).unwrap_or(default.module),
+      build_dependencies: value
+        .build_dependencies
+        .map(Into::into)
+        .unwrap_or(default.build_dependencies),
+      managed_paths: value
+        .managed_paths
+        .map(into_snapshot_paths)
+        .unwrap_or(default.managed_paths),
+      immutable_paths: value
+        .immutable_paths
+        .map(into_snapshot_paths)
+        .unwrap_or(default.immutable_paths),
+    }
   }
 }
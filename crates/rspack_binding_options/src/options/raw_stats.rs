@@ -1,16 +1,37 @@
+use napi::Either;
 use napi_derive::napi;
-use rspack_core::StatsOptions;
+use rspack_core::{StatsFilter, StatsOptions};
+use rspack_napi::regexp::{JsRegExp, JsRegExpExt};
+
+type RawStatsFilter = Either<String, JsRegExp>;
 
 #[derive(Debug, Default)]
-#[napi(object)]
+#[napi(object, object_to_js = false)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub exclude_modules: Option<Vec<RawStatsFilter>>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub exclude_assets: Option<Vec<RawStatsFilter>>,
+}
+
+fn into_stats_filters(filters: Option<Vec<RawStatsFilter>>) -> Vec<StatsFilter> {
+  filters
+    .unwrap_or_default()
+    .into_iter()
+    .map(|filter| match filter {
+      Either::A(s) => StatsFilter::String(s),
+      Either::B(r) => StatsFilter::Regexp(r.to_rspack_regex()),
+    })
+    .collect()
 }
 
 impl From<RawStatsOptions> for StatsOptions {
   fn from(value: RawStatsOptions) -> Self {
     Self {
       colors: value.colors,
+      exclude_modules: into_stats_filters(value.exclude_modules),
+      exclude_assets: into_stats_filters(value.exclude_assets),
     }
   }
 }
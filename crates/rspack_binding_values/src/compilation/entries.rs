@@ -157,9 +157,11 @@ pub struct JsEntryData {
   pub options: JsEntryOptions,
 }
 
-impl From<JsEntryData> for EntryData {
-  fn from(value: JsEntryData) -> Self {
-    Self {
+impl TryFrom<JsEntryData> for EntryData {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: JsEntryData) -> rspack_error::Result<Self> {
+    Ok(Self {
       dependencies: value
         .dependencies
         .into_iter()
@@ -170,8 +172,8 @@ impl From<JsEntryData> for EntryData {
         .into_iter()
         .map(|dep| dep.dependency_id)
         .collect::<Vec<_>>(),
-      options: value.options.into(),
-    }
+      options: value.options.try_into()?,
+    })
   }
 }
 
@@ -251,9 +253,15 @@ impl JsEntries {
   }
 
   #[napi]
-  pub fn set(&mut self, key: String, value: Either<JsEntryData, ClassInstance<EntryDataDTO>>) {
+  pub fn set(
+    &mut self,
+    key: String,
+    value: Either<JsEntryData, ClassInstance<EntryDataDTO>>,
+  ) -> Result<()> {
     let entry_data = match value {
-      Either::A(js) => js.into(),
+      Either::A(js) => js
+        .try_into()
+        .map_err(|e: rspack_error::Error| napi::Error::from_reason(e.to_string()))?,
       Either::B(dto) => {
         assert!(
           std::ptr::eq(dto.compilation, self.compilation),
@@ -263,6 +271,7 @@ impl JsEntries {
       }
     };
     self.compilation.entries.insert(key, entry_data);
+    Ok(())
   }
 
   #[napi]
@@ -7,6 +7,8 @@ use crate::RawRegex;
 pub struct JsContextModuleFactoryBeforeResolveData {
   pub context: String,
   pub request: Option<String>,
+  pub recursive: bool,
+  pub reg_exp: Option<RawRegex>,
 }
 
 pub type JsContextModuleFactoryBeforeResolveResult =
@@ -17,6 +19,7 @@ pub struct JsContextModuleFactoryAfterResolveData {
   pub resource: String,
   pub context: String,
   pub request: String,
+  pub recursive: bool,
   pub reg_exp: Option<RawRegex>,
 }
 
@@ -35,6 +35,7 @@ pub struct JsEntryOptions {
   #[napi(ts_type = "false | string")]
   pub runtime: Option<JsEntryRuntime>,
   pub chunk_loading: Option<String>,
+  pub wasm_loading: Option<String>,
   pub async_chunks: Option<bool>,
   #[napi(ts_type = "\"auto\" | JsFilename")]
   pub public_path: Option<JsFilename>,
@@ -45,12 +46,19 @@ pub struct JsEntryOptions {
   pub layer: Option<String>,
 }
 
-impl From<JsEntryOptions> for EntryOptions {
-  fn from(value: JsEntryOptions) -> Self {
-    Self {
+impl TryFrom<JsEntryOptions> for EntryOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: JsEntryOptions) -> rspack_error::Result<Self> {
+    Ok(Self {
       name: value.name,
       runtime: value.runtime.map(|r| JsEntryRuntimeWrapper(r).into()),
       chunk_loading: value.chunk_loading.as_deref().map(Into::into),
+      wasm_loading: value
+        .wasm_loading
+        .as_deref()
+        .map(TryInto::try_into)
+        .transpose()?,
       async_chunks: value.async_chunks,
       public_path: value.public_path.map(Into::into),
       base_uri: value.base_uri,
@@ -58,6 +66,6 @@ impl From<JsEntryOptions> for EntryOptions {
       library: value.library.map(Into::into),
       depend_on: value.depend_on.map(Into::into),
       layer: value.layer,
-    }
+    })
   }
 }
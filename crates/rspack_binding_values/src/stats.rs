@@ -3,13 +3,15 @@ use std::cell::RefCell;
 use napi_derive::napi;
 use rspack_collections::Identifier;
 use rspack_core::{
-  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsModule, StatsUsedExports,
+  EntrypointsStatsOption, ExtendedStatsOptions, Stats, StatsChunk, StatsFilter, StatsModule,
+  StatsOptions, StatsUsedExports,
 };
 use rspack_napi::{
   napi::{
     bindgen_prelude::{Buffer, FromNapiValue, Result, SharedReference, ToNapiValue},
     Either,
   },
+  regexp::{JsRegExp, JsRegExpExt},
   Ref,
 };
 use rustc_hash::FxHashMap as HashMap;
@@ -1151,38 +1153,61 @@ pub struct JsStatsOptions {
   pub source: bool,
   pub used_exports: bool,
   pub warnings: bool,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub exclude_modules: Option<Vec<Either<String, JsRegExp>>>,
+  #[napi(ts_type = "(string | RegExp)[]")]
+  pub exclude_assets: Option<Vec<Either<String, JsRegExp>>>,
+}
+
+fn into_stats_filters(filters: Vec<Either<String, JsRegExp>>) -> Vec<StatsFilter> {
+  filters
+    .into_iter()
+    .map(|filter| match filter {
+      Either::A(s) => StatsFilter::String(s),
+      Either::B(r) => StatsFilter::Regexp(r.to_rspack_regex()),
+    })
+    .collect()
 }
 
-impl From<JsStatsOptions> for ExtendedStatsOptions {
-  fn from(value: JsStatsOptions) -> Self {
-    let entrypoints = match value.entrypoints {
+impl JsStatsOptions {
+  /// The sections are resolved by JS for each call. The exclude filters passed to the call take
+  /// precedence over the ones of the `stats` config, which are used when the call leaves them
+  /// unset, so that excluded modules and assets are never serialized.
+  fn into_extended(self, stats_options: &StatsOptions) -> ExtendedStatsOptions {
+    let entrypoints = match self.entrypoints {
       Either::A(b) => EntrypointsStatsOption::Bool(b),
       Either::B(s) => EntrypointsStatsOption::String(s),
     };
 
-    Self {
-      assets: value.assets,
-      cached_modules: value.cached_modules,
-      chunks: value.chunks,
-      chunk_group_auxiliary: value.chunk_group_auxiliary,
-      chunk_group_children: value.chunk_group_children,
-      chunk_groups: value.chunk_groups,
-      chunk_modules: value.chunk_modules,
-      chunk_relations: value.chunk_relations,
-      depth: value.depth,
+    ExtendedStatsOptions {
+      assets: self.assets,
+      cached_modules: self.cached_modules,
+      chunks: self.chunks,
+      chunk_group_auxiliary: self.chunk_group_auxiliary,
+      chunk_group_children: self.chunk_group_children,
+      chunk_groups: self.chunk_groups,
+      chunk_modules: self.chunk_modules,
+      chunk_relations: self.chunk_relations,
+      depth: self.depth,
       entrypoints,
-      errors: value.errors,
-      hash: value.hash,
-      ids: value.ids,
-      modules: value.modules,
-      module_assets: value.module_assets,
-      nested_modules: value.nested_modules,
-      optimization_bailout: value.optimization_bailout,
-      provided_exports: value.provided_exports,
-      reasons: value.reasons,
-      source: value.source,
-      used_exports: value.used_exports,
-      warnings: value.warnings,
+      errors: self.errors,
+      hash: self.hash,
+      ids: self.ids,
+      modules: self.modules,
+      module_assets: self.module_assets,
+      nested_modules: self.nested_modules,
+      optimization_bailout: self.optimization_bailout,
+      provided_exports: self.provided_exports,
+      reasons: self.reasons,
+      source: self.source,
+      used_exports: self.used_exports,
+      warnings: self.warnings,
+      exclude_modules: self
+        .exclude_modules
+        .map_or_else(|| stats_options.exclude_modules.clone(), into_stats_filters),
+      exclude_assets: self
+        .exclude_assets
+        .map_or_else(|| stats_options.exclude_assets.clone(), into_stats_filters),
     }
   }
 }
@@ -1213,6 +1238,9 @@ pub struct JsStatsCompilation {
   pub modules: Option<Vec<JsStatsModule>>,
   pub named_chunk_groups: Option<Vec<JsStatsChunkGroup>>,
   pub warnings: Vec<JsStatsWarning>,
+  /// Number of the assets and modules left out by the exclude filters
+  pub filtered_assets: Option<u32>,
+  pub filtered_modules: Option<u32>,
 }
 
 impl FromNapiValue for JsStatsCompilation {
@@ -1263,22 +1291,26 @@ impl JsStats {
 impl JsStats {
   #[napi(ts_return_type = "JsStatsCompilation")]
   pub fn to_json(&self, js_options: JsStatsOptions) -> Result<JsStatsCompilationWrapper> {
-    let options = ExtendedStatsOptions::from(js_options);
+    let options = js_options.into_extended(&self.inner.compilation.options.stats);
 
     let hash = options.hash.then(|| self.hash()).flatten();
 
-    let (assets, assets_by_chunk_name) = if options.assets {
-      let asts = self.assets();
-      (Some(asts.assets), Some(asts.assets_by_chunk_name))
+    let (assets, assets_by_chunk_name, filtered_assets) = if options.assets {
+      let (asts, filtered) = self.assets(&options);
+      (
+        Some(asts.assets),
+        Some(asts.assets_by_chunk_name),
+        Some(filtered),
+      )
     } else {
-      (None, None)
+      (None, None, None)
     };
 
-    let modules = if options.modules {
-      let mds = self.modules(&options)?;
-      Some(mds)
+    let (modules, filtered_modules) = if options.modules {
+      let (mds, filtered) = self.modules(&options)?;
+      (Some(mds), Some(filtered))
     } else {
-      None
+      (None, None)
     };
 
     let chunks = if options.chunks {
@@ -1313,26 +1345,35 @@ impl JsStats {
       modules,
       named_chunk_groups,
       warnings,
+      filtered_assets,
+      filtered_modules,
     }))
   }
 
-  fn assets(&self) -> JsStatsGetAssets {
-    let (assets, assets_by_chunk_name) = self.inner.get_assets();
+  fn assets(&self, options: &ExtendedStatsOptions) -> (JsStatsGetAssets, u32) {
+    let (assets, assets_by_chunk_name, filtered_assets) = self.inner.get_assets(options);
     let assets = assets.into_iter().map(Into::into).collect();
     let assets_by_chunk_name = assets_by_chunk_name.into_iter().map(Into::into).collect();
-    JsStatsGetAssets {
-      assets,
-      assets_by_chunk_name,
-    }
+    (
+      JsStatsGetAssets {
+        assets,
+        assets_by_chunk_name,
+      },
+      filtered_assets as u32,
+    )
   }
 
-  fn modules(&self, options: &ExtendedStatsOptions) -> Result<Vec<JsStatsModule>> {
-    self
+  fn modules(&self, options: &ExtendedStatsOptions) -> Result<(Vec<JsStatsModule>, u32)> {
+    let (modules, filtered_modules) = self
       .inner
       .get_modules(options, |res| {
-        res.into_iter().map(JsStatsModule::try_from).collect()
+        res
+          .into_iter()
+          .map(JsStatsModule::try_from)
+          .collect::<Result<Vec<_>>>()
       })
-      .map_err(|e| napi::Error::from_reason(e.to_string()))?
+      .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok((modules?, filtered_modules as u32))
   }
 
   fn chunks(&self, options: &ExtendedStatsOptions) -> Result<Vec<JsStatsChunk>> {
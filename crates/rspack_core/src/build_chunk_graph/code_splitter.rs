@@ -1479,24 +1479,32 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       .or_default();
     let block = module.into();
     map.insert(block, Vec::new());
-    for b in block.get_blocks(self.compilation) {
-      map.insert(b.into(), Vec::new());
+    // blocks can be nested, e.g. an `import()` in the callback of `require.ensure()`
+    let mut blocks = block.get_blocks(self.compilation);
+    while let Some(b) = blocks.pop() {
+      let b = DependenciesBlockIdentifier::from(b);
+      blocks.extend(b.get_blocks(self.compilation));
+      map.insert(b, Vec::new());
     }
 
     let sorted_connections = module_graph
       .get_ordered_connections(&module)
       .expect("should have module")
       .into_iter()
-      .map(|conn_id| {
+      .filter_map(|conn_id| {
         let conn = module_graph
           .connection_by_connection_id(conn_id)
           .expect("should have connection");
+        // weak connections never pull the referenced module into a chunk
+        if conn.weak {
+          return None;
+        }
 
         let dep = module_graph
           .dependency_by_id(&conn.dependency_id)
           .expect("should have dependency");
 
-        (dep, conn_id)
+        Some((dep, conn_id))
       });
 
     // keep the dependency order sorted by span
@@ -1510,9 +1518,6 @@ Or do you want to use the entrypoints '{name}' and '{runtime}' independently on
       if dep.as_module_dependency().is_none() && dep.as_context_dependency().is_none() {
         continue;
       }
-      if matches!(dep.as_module_dependency().map(|d| d.weak()), Some(true)) {
-        continue;
-      }
       let dep_id = dep.id();
       // Dependency created but no module is available.
       // This could happen when module factorization is failed, but `options.bail` set to `false`
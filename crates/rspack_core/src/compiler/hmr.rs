@@ -53,7 +53,13 @@ where
     // build without stats
     {
       let mut modified_files = HashSet::default();
-      modified_files.extend(changed_files.iter().map(PathBuf::from));
+      // the content of immutable paths never changes, skip checking the modules built from them
+      modified_files.extend(
+        changed_files
+          .iter()
+          .map(PathBuf::from)
+          .filter(|file| !self.options.snapshot.is_immutable_path(file)),
+      );
       let mut removed_files = HashSet::default();
       removed_files.extend(deleted_files.iter().map(PathBuf::from));
 
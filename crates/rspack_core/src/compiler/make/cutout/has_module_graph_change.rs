@@ -4,7 +4,8 @@ use swc_core::atoms::Atom;
 
 use super::super::MakeArtifact;
 use crate::{
-  AsyncDependenciesBlockIdentifier, DependencyId, GroupOptions, ModuleGraph, ModuleIdentifier,
+  AsyncDependenciesBlockIdentifier, DependenciesBlock, DependencyId, GroupOptions, ModuleGraph,
+  ModuleIdentifier,
 };
 
 #[derive(Debug, Default, Eq, PartialEq, Clone)]
@@ -46,13 +47,15 @@ impl ModuleDeps {
     }
 
     res.child_modules = child_deps;
-    for block_id in module.get_blocks() {
+    let mut block_ids = module.get_blocks().to_vec();
+    while let Some(block_id) = block_ids.pop() {
       let block = module_graph
-        .block_by_id(block_id)
+        .block_by_id(&block_id)
         .expect("should have block");
       res
         .module_blocks
-        .push((*block_id, block.get_group_options().cloned()));
+        .push((block_id, block.get_group_options().cloned()));
+      block_ids.extend_from_slice(block.get_blocks());
     }
 
     res
@@ -135,6 +135,11 @@ impl Task<MakeTaskContext> for BuildResultTask {
       .build_dependencies
       .add_batch_file(&build_result.build_info.build_dependencies);
 
+    // nested blocks are already referenced by their parent block
+    for block in &build_result.blocks {
+      module.add_block_id(block.identifier());
+    }
+
     let mut queue = VecDeque::new();
     let mut all_dependencies = vec![];
     let mut handle_block = |dependencies: Vec<BoxDependency>,
@@ -157,7 +162,6 @@ impl Task<MakeTaskContext> for BuildResultTask {
         module_graph.add_dependency(dependency);
       }
       if let Some(current_block) = current_block {
-        module.add_block_id(current_block.identifier());
         module_graph.add_block(current_block);
       }
       blocks
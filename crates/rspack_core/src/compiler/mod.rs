@@ -4,14 +4,20 @@ mod make;
 mod module_executor;
 
 use std::sync::Arc;
+#[cfg(feature = "watch")]
+use std::time::{Duration, SystemTime};
 
 use rspack_error::Result;
 use rspack_fs::AsyncWritableFileSystem;
+#[cfg(feature = "watch")]
+use rspack_fs::{FileSystemChanges, WatchCallback, WatchFileSystem, WatchOptions, Watcher};
 use rspack_futures::FuturesResults;
 use rspack_hook::define_hook;
 use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_sources::BoxSource;
 use rustc_hash::FxHashMap as HashMap;
+#[cfg(feature = "watch")]
+use tokio::sync::mpsc::UnboundedSender;
 use tracing::instrument;
 
 pub use self::compilation::*;
@@ -24,6 +30,11 @@ use crate::{
 };
 use crate::{ContextModuleFactory, NormalModuleFactory};
 
+/// The modification times of files lag behind the clock by up to the granularity of the file
+/// system timestamps.
+#[cfg(feature = "watch")]
+const FS_ACCURACY: Duration = Duration::from_millis(10);
+
 // should be SyncHook, but rspack need call js hook
 define_hook!(CompilerThisCompilation: AsyncSeries(compilation: &mut Compilation, params: &mut CompilationParams));
 // should be SyncHook, but rspack need call js hook
@@ -123,6 +134,123 @@ where
     Ok(())
   }
 
+  /// Build, then rebuild whenever the dependencies of the last compilation change.
+  ///
+  /// `on_done` is called after each compilation, watching stops once it returns `false`.
+  #[cfg(feature = "watch")]
+  pub async fn watch(
+    &mut self,
+    watch_file_system: &dyn WatchFileSystem,
+    options: &WatchOptions,
+    mut on_done: impl FnMut(&Compilation) -> bool + Send,
+  ) -> Result<()> {
+    let start_time = SystemTime::now();
+    self.build().await?;
+    if !on_done(&self.compilation) {
+      return Ok(());
+    }
+    // all the watchers report to the same channel, so the changes of a closed watcher are kept
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = self.watch_dependencies(watch_file_system, options, sender.clone())?;
+    // no watcher existed during the first build, report the edits made meanwhile
+    let changes = self.changes_since(start_time);
+    if !changes.is_empty() {
+      let _ = sender.send(changes);
+    }
+    while let Some(mut changes) = receiver.recv().await {
+      while let Ok(FileSystemChanges {
+        changed_files,
+        removed_files,
+      }) = receiver.try_recv()
+      {
+        for file in changed_files {
+          changes.removed_files.remove(&file);
+          changes.changed_files.insert(file);
+        }
+        for file in removed_files {
+          changes.changed_files.remove(&file);
+          changes.removed_files.insert(file);
+        }
+      }
+      self
+        .rebuild(changes.changed_files, changes.removed_files)
+        .await?;
+      // the dependencies may have changed, watch them before closing the previous watcher,
+      // which keeps reporting the changes made during the rebuild until then
+      let next_watcher = self.watch_dependencies(watch_file_system, options, sender.clone())?;
+      std::mem::replace(&mut watcher, next_watcher).close();
+      if !on_done(&self.compilation) {
+        break;
+      }
+    }
+    watcher.close();
+    Ok(())
+  }
+
+  /// Watch the dependencies of the last compilation, the changes are sent to `sender`.
+  #[cfg(feature = "watch")]
+  fn watch_dependencies(
+    &self,
+    watch_file_system: &dyn WatchFileSystem,
+    options: &WatchOptions,
+    sender: UnboundedSender<FileSystemChanges>,
+  ) -> Result<Box<dyn Watcher>> {
+    let (file_dependencies, _, _) = self.compilation.file_dependencies();
+    let (missing_dependencies, _, _) = self.compilation.missing_dependencies();
+    let (build_dependencies, _, _) = self.compilation.build_dependencies();
+    let (context_dependencies, _, _) = self.compilation.context_dependencies();
+    let files = file_dependencies
+      .chain(missing_dependencies)
+      .chain(build_dependencies)
+      .filter_map(|file| Utf8PathBuf::from_path_buf(file.clone()).ok())
+      .collect::<Vec<_>>();
+    let directories = context_dependencies
+      .filter_map(|dir| Utf8PathBuf::from_path_buf(dir.clone()).ok())
+      .collect::<Vec<_>>();
+    let callback: WatchCallback = Box::new(move |changes| {
+      let _ = sender.send(changes);
+    });
+    Ok(watch_file_system.watch(&files, &directories, options, callback)?)
+  }
+
+  /// The dependencies of the last compilation created, modified or removed since `time`.
+  #[cfg(feature = "watch")]
+  fn changes_since(&self, time: SystemTime) -> FileSystemChanges {
+    let (file_dependencies, _, _) = self.compilation.file_dependencies();
+    let (missing_dependencies, _, _) = self.compilation.missing_dependencies();
+    let (build_dependencies, _, _) = self.compilation.build_dependencies();
+    let (context_dependencies, _, _) = self.compilation.context_dependencies();
+    let time = time.checked_sub(FS_ACCURACY).unwrap_or(time);
+    let mut changes = FileSystemChanges::default();
+    for path in file_dependencies
+      .chain(build_dependencies)
+      .chain(context_dependencies)
+    {
+      match std::fs::metadata(path) {
+        Ok(metadata) => {
+          if metadata.modified().is_ok_and(|modified| modified >= time) {
+            changes
+              .changed_files
+              .insert(path.to_string_lossy().into_owned());
+          }
+        }
+        Err(_) => {
+          changes
+            .removed_files
+            .insert(path.to_string_lossy().into_owned());
+        }
+      }
+    }
+    for path in missing_dependencies {
+      if path.exists() {
+        changes
+          .changed_files
+          .insert(path.to_string_lossy().into_owned());
+      }
+    }
+    changes
+  }
+
   #[instrument(name = "build", skip_all)]
   pub async fn build(&mut self) -> Result<()> {
     self.old_cache.end_idle();
@@ -381,3 +509,194 @@ pub struct AssetEmittedInfo {
   pub output_path: Utf8PathBuf,
   pub target_path: Utf8PathBuf,
 }
+
+#[cfg(test)]
+mod test {
+  use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+
+  use super::*;
+  use crate::{
+    rspack_sources::{RawSource, SourceExt},
+    testing::compiler_options,
+    ApplyContext, AssetInfo, CompilationAsset, Plugin, PluginContext,
+  };
+
+  /// Adds a `main.js` asset before the assets are emitted.
+  #[derive(Debug)]
+  struct EmitAssetPlugin;
+
+  #[::rspack_hook::__macro_helper::async_trait]
+  impl CompilerEmit for EmitAssetPlugin {
+    async fn run(&self, compilation: &mut Compilation) -> Result<()> {
+      compilation.emit_asset(
+        "main.js".to_string(),
+        CompilationAsset::new(Some(RawSource::from("main").boxed()), AssetInfo::default()),
+      );
+      Ok(())
+    }
+  }
+
+  impl Plugin for EmitAssetPlugin {
+    fn apply(
+      &self,
+      ctx: PluginContext<&mut ApplyContext>,
+      _options: &mut CompilerOptions,
+    ) -> Result<()> {
+      ctx.context.compiler_hooks.emit.tap(EmitAssetPlugin);
+      Ok(())
+    }
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_emit_to_memory_file_system() {
+    let context = Utf8Path::new("/virtual");
+    let mut compiler = Compiler::new(
+      compiler_options(context),
+      vec![Box::new(EmitAssetPlugin)],
+      MemoryFileSystem::default(),
+      None,
+      None,
+    );
+    compiler.build().await.expect("should build");
+
+    assert_eq!(
+      ReadableFileSystem::read(&compiler.output_filesystem, &context.join("dist/main.js"))
+        .expect("should emit the asset to memory"),
+      b"main"
+    );
+  }
+
+  #[cfg(feature = "watch")]
+  mod watch {
+    use std::{
+      sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+      },
+      time::Duration,
+    };
+
+    use rspack_fs::{NativeWatchFileSystem, WatchOptions};
+
+    use super::*;
+
+    /// Adds `file` to the file dependencies, and edits it while the compilation of index
+    /// `edit_during` is made to simulate an edit during a build.
+    #[derive(Debug, Clone)]
+    struct WatchFilePlugin {
+      file: Utf8PathBuf,
+      edit_during: usize,
+      makes: Arc<AtomicUsize>,
+    }
+
+    #[::rspack_hook::__macro_helper::async_trait]
+    impl CompilerMake for WatchFilePlugin {
+      async fn run(&self, compilation: &mut Compilation) -> Result<()> {
+        compilation
+          .file_dependencies
+          .insert(self.file.clone().into_std_path_buf());
+        if self.makes.fetch_add(1, Ordering::SeqCst) == self.edit_during {
+          std::fs::write(&self.file, "c").expect("should write file");
+          // let the watcher of the previous compilation see the edit before the rebuild ends
+          tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+        Ok(())
+      }
+    }
+
+    impl Plugin for WatchFilePlugin {
+      fn apply(
+        &self,
+        ctx: PluginContext<&mut ApplyContext>,
+        _options: &mut CompilerOptions,
+      ) -> Result<()> {
+        ctx.context.compiler_hooks.make.tap(self.clone());
+        Ok(())
+      }
+    }
+
+    /// A directory with an `index.js` file, which was modified long before the build.
+    fn create_fixture() -> tempfile::TempDir {
+      let dir = tempfile::tempdir().expect("should create temp dir");
+      let file = std::fs::File::create(dir.path().join("index.js")).expect("should create file");
+      file
+        .set_modified(std::time::UNIX_EPOCH)
+        .expect("should set modified time");
+      dir
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_watch_edit_during_build() {
+      let dir = create_fixture();
+      let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+      let file = context.join("index.js");
+      let plugin = WatchFilePlugin {
+        file: file.clone(),
+        edit_during: 0,
+        makes: Default::default(),
+      };
+      let mut compiler = Compiler::new(
+        compiler_options(context),
+        vec![Box::new(plugin.clone())],
+        MemoryFileSystem::default(),
+        None,
+        None,
+      );
+
+      let mut compilations = 0;
+      let watch_options = WatchOptions::default();
+      let watching = compiler.watch(&NativeWatchFileSystem, &watch_options, |_| {
+        compilations += 1;
+        compilations < 2
+      });
+      tokio::time::timeout(Duration::from_secs(10), watching)
+        .await
+        .expect("should rebuild after the edit made during the first build")
+        .expect("should watch");
+
+      assert_eq!(compilations, 2);
+      assert_eq!(plugin.makes.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_watch_edit_during_rebuild() {
+      let dir = create_fixture();
+      let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+      let file = context.join("index.js");
+      let plugin = WatchFilePlugin {
+        file: file.clone(),
+        edit_during: 1,
+        makes: Default::default(),
+      };
+      let mut compiler = Compiler::new(
+        compiler_options(context),
+        vec![Box::new(plugin.clone())],
+        MemoryFileSystem::default(),
+        None,
+        None,
+      );
+
+      let mut compilations = 0;
+      let watch_options = WatchOptions::default();
+      let watching = compiler.watch(&NativeWatchFileSystem, &watch_options, |_| {
+        compilations += 1;
+        if compilations == 1 {
+          // edit the file once the watcher of the first compilation is created
+          let file = file.clone();
+          std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(200));
+            std::fs::write(file, "b").expect("should write file");
+          });
+        }
+        compilations < 3
+      });
+      tokio::time::timeout(Duration::from_secs(10), watching)
+        .await
+        .expect("should rebuild after the edit made during the rebuild")
+        .expect("should watch");
+
+      assert_eq!(compilations, 3);
+      assert_eq!(plugin.makes.load(Ordering::SeqCst), 3);
+    }
+  }
+}
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 use std::sync::LazyLock;
 use std::{borrow::Cow, fs, hash::Hash, sync::Arc};
 
+use derivative::Derivative;
 use indoc::formatdoc;
 use itertools::Itertools;
 use regex::{Captures, Regex};
@@ -137,7 +138,13 @@ pub struct ContextOptions {
   pub attributes: Option<ImportAttributes>,
 }
 
-#[derive(Debug, Clone)]
+/// Creates the dependencies of a context module instead of reading the files of the
+/// context directory, e.g. a static map of the requests provided by a plugin.
+pub type ResolveContextModuleDependencies =
+  Arc<dyn Fn(&ContextModuleOptions) -> Result<Vec<ContextElementDependency>> + Send + Sync>;
+
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
 pub struct ContextModuleOptions {
   pub addon: String,
   pub resource: Utf8PathBuf,
@@ -147,6 +154,8 @@ pub struct ContextModuleOptions {
   pub layer: Option<ModuleLayer>,
   pub resolve_options: Option<Box<Resolve>>,
   pub type_prefix: ContextTypePrefix,
+  #[derivative(Debug = "ignore")]
+  pub resolve_dependencies: Option<ResolveContextModuleDependencies>,
 }
 
 #[derive(Debug)]
@@ -1092,13 +1101,17 @@ impl ContextModule {
     });
 
     let mut context_element_dependencies = vec![];
-    Self::visit_dirs(
-      self.options.resource.as_str(),
-      &self.options.resource,
-      &mut context_element_dependencies,
-      &self.options,
-      &resolver.options(),
-    )?;
+    if let Some(resolve_dependencies) = &self.options.resolve_dependencies {
+      context_element_dependencies = resolve_dependencies(&self.options)?;
+    } else {
+      Self::visit_dirs(
+        self.options.resource.as_str(),
+        &self.options.resource,
+        &mut context_element_dependencies,
+        &self.options,
+        &resolver.options(),
+      )?;
+    }
     context_element_dependencies.sort_by_cached_key(|d| d.user_request.to_string());
 
     tracing::trace!(
@@ -7,9 +7,10 @@ use rspack_regex::RspackRegex;
 use tracing::instrument;
 
 use crate::{
-  resolve, ContextModule, ContextModuleOptions, DependencyCategory, ModuleExt, ModuleFactory,
-  ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, RawModule, ResolveArgs,
-  ResolveOptionsWithDependencyType, ResolveResult, Resolver, ResolverFactory, SharedPluginDriver,
+  resolve, ContextModule, ContextModuleOptions, ContextOptions, DependencyCategory, ModuleExt,
+  ModuleFactory, ModuleFactoryCreateData, ModuleFactoryResult, ModuleIdentifier, RawModule,
+  ResolveArgs, ResolveContextModuleDependencies, ResolveOptionsWithDependencyType, ResolveResult,
+  Resolver, ResolverFactory, SharedPluginDriver,
 };
 
 #[derive(Clone)]
@@ -24,6 +25,8 @@ pub struct BeforeResolveData {
   // resolve_options
   pub context: String,
   pub request: Option<String>,
+  pub recursive: bool,
+  pub reg_exp: Option<RspackRegex>,
   // assertions
   // dependencies
   // dependency_type
@@ -52,7 +55,7 @@ pub struct AfterResolveData {
   // context_dependencies: HashSet<String>,
   pub request: String,
   // mode
-  // recursive: bool,
+  pub recursive: bool,
   pub reg_exp: Option<RspackRegex>,
   // namespace_object
   // addon: String,
@@ -63,6 +66,7 @@ pub struct AfterResolveData {
   // type_prefix: String,
   // category: String,
   // referenced_exports
+  pub resolve_dependencies: Option<ResolveContextModuleDependencies>,
 }
 
 define_hook!(ContextModuleFactoryBeforeResolve: AsyncSeriesWaterfall(data: BeforeResolveResult) -> BeforeResolveResult);
@@ -85,11 +89,13 @@ pub struct ContextModuleFactory {
 impl ModuleFactory for ContextModuleFactory {
   #[instrument(name = "context_module_factory:create", skip_all)]
   async fn create(&self, data: &mut ModuleFactoryCreateData) -> Result<ModuleFactoryResult> {
-    if let Some(before_resolve_result) = self.before_resolve(data).await? {
-      return Ok(before_resolve_result);
-    }
+    let before_resolve_data = match self.before_resolve(data).await? {
+      BeforeResolveResult::Ignored => return Ok(ModuleFactoryResult::default()),
+      BeforeResolveResult::Data(before_resolve_data) => before_resolve_data,
+    };
 
-    let (factorize_result, mut context_module_options) = self.resolve(data).await?;
+    let (factorize_result, mut context_module_options) =
+      self.resolve(data, *before_resolve_data).await?;
 
     if let Some(context_module_options) = context_module_options.as_mut() {
       if let Some(factorize_result) = self.after_resolve(context_module_options).await? {
@@ -114,28 +120,24 @@ impl ContextModuleFactory {
     }
   }
 
-  async fn before_resolve(
-    &self,
-    data: &mut ModuleFactoryCreateData,
-  ) -> Result<Option<ModuleFactoryResult>> {
+  async fn before_resolve(&self, data: &ModuleFactoryCreateData) -> Result<BeforeResolveResult> {
+    let dependency = data
+      .dependency
+      .as_context_dependency()
+      .expect("should be context dependency");
     let before_resolve_data = BeforeResolveData {
       context: data.context.to_string(),
       request: data.request().map(|r| r.to_string()),
+      recursive: dependency.options().recursive,
+      reg_exp: dependency.options().reg_exp.clone(),
     };
 
-    match self
+    self
       .plugin_driver
       .context_module_factory_hooks
       .before_resolve
       .call(BeforeResolveResult::Data(Box::new(before_resolve_data)))
-      .await?
-    {
-      BeforeResolveResult::Ignored => Ok(Some(ModuleFactoryResult::default())),
-      BeforeResolveResult::Data(d) => {
-        data.context = d.context.into();
-        Ok(None)
-      }
-    }
+      .await
   }
 
   fn get_loader_resolver(&self) -> Arc<Resolver> {
@@ -151,8 +153,10 @@ impl ContextModuleFactory {
   async fn resolve(
     &self,
     data: &mut ModuleFactoryCreateData,
+    before_resolve_data: BeforeResolveData,
   ) -> Result<(ModuleFactoryResult, Option<ContextModuleOptions>)> {
     let plugin_driver = &self.plugin_driver;
+    data.context = before_resolve_data.context.into();
     let dependency = data
       .dependency
       .as_context_dependency()
@@ -160,7 +164,10 @@ impl ContextModuleFactory {
     let mut file_dependencies = Default::default();
     let mut missing_dependencies = Default::default();
     // let context_dependencies = Default::default();
-    let request = dependency.request();
+    let request = before_resolve_data
+      .request
+      .as_deref()
+      .unwrap_or_else(|| dependency.request());
     let (loader_request, specifier) = match request.rfind('!') {
       Some(idx) => {
         let mut loaders_prefix = String::new();
@@ -240,8 +247,13 @@ impl ContextModuleFactory {
           resource_fragment: resource.fragment,
           layer: data.issuer_layer.clone(),
           resolve_options: data.resolve_options.clone(),
-          context_options: dependency.options().clone(),
+          context_options: ContextOptions {
+            recursive: before_resolve_data.recursive,
+            reg_exp: before_resolve_data.reg_exp.clone(),
+            ..dependency.options().clone()
+          },
           type_prefix: dependency.type_prefix(),
+          resolve_dependencies: None,
         };
         let module = Box::new(ContextModule::new(
           options.clone(),
@@ -285,7 +297,9 @@ impl ContextModuleFactory {
       resource: context_module_options.resource.clone(),
       context: context_options.context.clone(),
       request: context_options.request.clone(),
+      recursive: context_options.recursive,
       reg_exp: context_options.reg_exp.clone(),
+      resolve_dependencies: context_module_options.resolve_dependencies.clone(),
     };
 
     match self
@@ -298,7 +312,9 @@ impl ContextModuleFactory {
       AfterResolveResult::Ignored => Ok(Some(ModuleFactoryResult::default())),
       AfterResolveResult::Data(d) => {
         context_module_options.resource = d.resource;
+        context_module_options.context_options.recursive = d.recursive;
         context_module_options.context_options.reg_exp = d.reg_exp;
+        context_module_options.resolve_dependencies = d.resolve_dependencies;
 
         let module = ContextModule::new(
           context_module_options.clone(),
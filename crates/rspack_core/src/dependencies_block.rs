@@ -123,10 +123,10 @@ impl AsyncDependenciesBlock {
     std::mem::take(&mut self.dependencies)
   }
 
-  pub fn add_block(&mut self, _block: AsyncDependenciesBlock) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block.id);
-    // self.blocks.push(block);
+  /// Add a block nested in this block, e.g. an `import()` in the callback of `require.ensure()`.
+  pub fn add_block(&mut self, block: Box<AsyncDependenciesBlock>) {
+    self.block_ids.push(block.id);
+    self.blocks.push(block);
   }
 
   pub fn take_blocks(&mut self) -> Vec<Box<AsyncDependenciesBlock>> {
@@ -169,9 +169,8 @@ impl AsyncDependenciesBlock {
 }
 
 impl DependenciesBlock for AsyncDependenciesBlock {
-  fn add_block_id(&mut self, _block: AsyncDependenciesBlockIdentifier) {
-    unimplemented!("Nested block are not implemented");
-    // self.block_ids.push(block);
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.block_ids.push(block)
   }
 
   fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
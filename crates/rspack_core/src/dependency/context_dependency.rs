@@ -11,6 +11,11 @@ pub trait ContextDependency: Dependency {
     false
   }
 
+  /// Whether the context module is only used when it's already available, e.g. `require.resolveWeak`
+  fn weak(&self) -> bool {
+    false
+  }
+
   fn type_prefix(&self) -> ContextTypePrefix;
 }
 
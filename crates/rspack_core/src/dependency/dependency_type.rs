@@ -23,6 +23,8 @@ pub enum DependencyType {
   DynamicImport,
   // import() eager
   DynamicImportEager,
+  // import() weak
+  DynamicImportWeak,
   // cjs require
   CjsRequire,
   // cjs full require
@@ -33,6 +35,16 @@ pub enum DependencyType {
   CjsExportRequire,
   // cjs self reference
   CjsSelfReference,
+  // amd define()
+  AmdDefine,
+  // amd require array
+  AmdRequireArray,
+  // amd require()
+  AmdRequire,
+  // amd require item
+  AmdRequireItem,
+  // amd local module
+  LocalModule,
   // new URL("./foo", import.meta.url)
   NewUrl,
   // new Worker()
@@ -51,6 +63,8 @@ pub enum DependencyType {
   CssImport,
   // css modules compose
   CssCompose,
+  // css modules :import
+  CssIcssImport,
   // css :export
   CssExport,
   // css modules local ident
@@ -67,6 +81,14 @@ pub enum DependencyType {
   RequireContext,
   // require.resolve
   RequireResolve,
+  // require.resolve context
+  RequireResolveContext,
+  // require.ensure()
+  RequireEnsure,
+  // require.ensure item
+  RequireEnsureItem,
+  // require.include
+  RequireInclude,
   /// wasm import
   WasmImport,
   /// wasm export import
@@ -95,6 +117,10 @@ pub enum DependencyType {
   LoaderImport,
   LazyImport,
   ModuleDecorator,
+  /// dll entry
+  DllEntry,
+  /// delegated source
+  DelegatedSource,
   Custom(&'static str),
 }
 
@@ -116,6 +142,11 @@ impl DependencyType {
       DependencyType::CjsExports => "cjs exports",
       DependencyType::CjsExportRequire => "cjs export require",
       DependencyType::CjsSelfReference => "cjs self exports reference",
+      DependencyType::AmdDefine => "amd define",
+      DependencyType::AmdRequireArray => "amd require array",
+      DependencyType::AmdRequire => "amd",
+      DependencyType::AmdRequireItem => "amd require",
+      DependencyType::LocalModule => "local module",
       DependencyType::NewUrl => "new URL()",
       DependencyType::NewWorker => "new Worker()",
       DependencyType::ImportMetaHotAccept => "import.meta.webpackHot.accept",
@@ -125,6 +156,7 @@ impl DependencyType {
       DependencyType::CssUrl => "css url",
       DependencyType::CssImport => "css import",
       DependencyType::CssCompose => "css compose",
+      DependencyType::CssIcssImport => "css :import",
       DependencyType::CssExport => "css export",
       DependencyType::CssLocalIdent => "css local ident",
       DependencyType::ContextElement(type_prefix) => match type_prefix {
@@ -134,9 +166,14 @@ impl DependencyType {
       // TODO: mode
       DependencyType::ImportContext => "import context",
       DependencyType::DynamicImportEager => "import() eager",
+      DependencyType::DynamicImportWeak => "import() weak",
       DependencyType::CommonJSRequireContext => "commonjs require context",
       DependencyType::RequireContext => "require.context",
       DependencyType::RequireResolve => "require.resolve",
+      DependencyType::RequireResolveContext => "require.resolve context",
+      DependencyType::RequireEnsure => "require.ensure",
+      DependencyType::RequireEnsureItem => "require.ensure item",
+      DependencyType::RequireInclude => "require.include",
       DependencyType::WasmImport => "wasm import",
       DependencyType::WasmExportImported => "wasm export imported",
       DependencyType::StaticExports => "static exports",
@@ -157,6 +194,8 @@ impl DependencyType {
       DependencyType::WebpackIsIncluded => "__webpack_is_included__",
       DependencyType::LazyImport => "lazy import()",
       DependencyType::ModuleDecorator => "module decorator",
+      DependencyType::DllEntry => "dll entry",
+      DependencyType::DelegatedSource => "delegated source",
     }
   }
 }
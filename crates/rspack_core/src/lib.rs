@@ -106,6 +106,8 @@ pub use rspack_sources;
 
 #[cfg(debug_assertions)]
 pub mod debug_info;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SourceType {
@@ -156,6 +158,7 @@ impl From<&str> for SourceType {
       "consume-shared" => Self::ConsumeShared,
       "unknown" => Self::Unknown,
       "css-import" => Self::CssImport,
+      "runtime" => Self::Runtime,
       other => SourceType::Custom(other.into()),
     }
   }
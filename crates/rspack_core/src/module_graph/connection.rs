@@ -47,6 +47,9 @@ pub struct ModuleGraphConnection {
   pub dependency_id: DependencyId,
   pub active: bool,
   pub conditional: bool,
+  /// A weak connection never adds the referenced module to a chunk, it only uses the module
+  /// when it is already available
+  pub weak: bool,
 }
 
 impl Hash for ModuleGraphConnection {
@@ -68,6 +71,7 @@ impl ModuleGraphConnection {
     module_identifier: ModuleIdentifier,
     active: bool,
     conditional: bool,
+    weak: bool,
   ) -> Self {
     Self {
       id: ConnectionId::new(),
@@ -76,6 +80,7 @@ impl ModuleGraphConnection {
       dependency_id,
       active,
       conditional,
+      weak,
       resolved_original_module_identifier: original_module_identifier,
     }
   }
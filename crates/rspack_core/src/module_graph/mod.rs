@@ -7,8 +7,8 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use swc_core::ecma::atoms::Atom;
 
 use crate::{
-  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, Dependency, ExportProvided,
-  ProvidedExports, RuntimeSpec, UsedExports,
+  AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, DependenciesBlock, Dependency,
+  ExportProvided, ProvidedExports, RuntimeSpec, UsedExports,
 };
 mod module;
 pub use module::*;
@@ -313,10 +313,18 @@ impl<'a> ModuleGraph<'a> {
   }
 
   pub fn revoke_module(&mut self, module_id: &ModuleIdentifier) -> Vec<BuildDependency> {
-    let blocks = self
+    let mut blocks = self
       .module_by_identifier(module_id)
       .map(|m| Vec::from(m.get_blocks()))
       .unwrap_or_default();
+    // nested blocks are only referenced by their parent block
+    let mut index = 0;
+    while let Some(block_id) = blocks.get(index).copied() {
+      if let Some(block) = self.block_by_id(&block_id) {
+        blocks.extend_from_slice(block.get_blocks());
+      }
+      index += 1;
+    }
 
     let (outgoing_connections, incoming_connections) = self
       .module_graph_module_by_identifier(module_id)
@@ -790,6 +798,9 @@ impl<'a> ModuleGraph<'a> {
     let condition = dependency
       .as_module_dependency()
       .and_then(|dep| dep.get_condition());
+    let weak = dependency
+      .as_module_dependency()
+      .is_some_and(|dep| dep.weak());
     let Some(active_partial) = &mut self.active else {
       panic!("should have active partial");
     };
@@ -809,6 +820,7 @@ impl<'a> ModuleGraph<'a> {
       module_identifier,
       active,
       conditional,
+      weak,
     );
     active_partial
       .dependency_id_to_connection_id
@@ -180,14 +180,20 @@ impl NormalModule {
   fn create_id<'request>(
     module_type: &ModuleType,
     layer: Option<&ModuleLayer>,
+    identifier_suffix: Option<&str>,
     request: &'request str,
   ) -> Cow<'request, str> {
-    if let Some(layer) = layer {
+    let id: Cow<str> = if let Some(layer) = layer {
       format!("{module_type}|{request}|{layer}").into()
     } else if *module_type == ModuleType::JsAuto {
       request.into()
     } else {
       format!("{module_type}|{request}").into()
+    };
+    if let Some(identifier_suffix) = identifier_suffix {
+      format!("{id}|{identifier_suffix}").into()
+    } else {
+      id
     }
   }
 
@@ -198,6 +204,7 @@ impl NormalModule {
     raw_request: String,
     module_type: impl Into<ModuleType>,
     layer: Option<ModuleLayer>,
+    identifier_suffix: Option<&str>,
     parser_and_generator: Box<dyn ParserAndGenerator>,
     parser_options: Option<ParserOptions>,
     generator_options: Option<GeneratorOptions>,
@@ -207,7 +214,7 @@ impl NormalModule {
     loaders: Vec<BoxLoader>,
   ) -> Self {
     let module_type = module_type.into();
-    let id = Self::create_id(&module_type, layer.as_ref(), &request);
+    let id = Self::create_id(&module_type, layer.as_ref(), identifier_suffix, &request);
     Self {
       blocks: Vec::new(),
       dependencies: Vec::new(),
@@ -559,6 +559,7 @@ impl NormalModuleFactory {
         resource_resolve_data: resource_data,
         match_resource: match_resource_data.as_ref().map(|d| d.resource.clone()),
         side_effects: resolved_side_effects,
+        identifier_suffix: None,
       };
       if let Some(plugin_result) = self
         .plugin_driver
@@ -592,6 +593,7 @@ impl NormalModuleFactory {
         create_data.raw_request.clone(),
         resolved_module_type,
         resolved_module_layer,
+        create_data.identifier_suffix.as_deref(),
         resolved_parser_and_generator,
         resolved_parser_options,
         resolved_generator_options,
@@ -846,6 +848,9 @@ pub struct NormalModuleCreateData {
   pub resource_resolve_data: ResourceData,
   pub match_resource: Option<String>,
   pub side_effects: Option<bool>,
+  /// Appended to the identifier of the created module, so a plugin can create distinct
+  /// modules for the same request, e.g. for the conditions of a css `@import`
+  pub identifier_suffix: Option<String>,
 }
 
 #[test]
@@ -6,20 +6,26 @@ use std::{
   },
 };
 
-use crate::CompilerOptions;
+use rspack_collections::Identifier;
+
+use crate::{CacheOptions, CompilerOptions, SnapshotOptions};
 
 mod local;
 mod occasion;
+mod snapshot;
 mod storage;
 pub use local::*;
+pub use occasion::OccasionStats;
 use occasion::{
   CodeGenerateOccasion, CreateChunkAssetsOccasion, ProcessRuntimeRequirementsOccasion,
 };
+pub use snapshot::Snapshot;
 use storage::new_storage;
 
 #[derive(Debug)]
 pub struct Cache {
   is_idle: AtomicBool,
+  snapshot_options: SnapshotOptions,
   pub code_generate_occasion: CodeGenerateOccasion,
   pub process_runtime_requirements_occasion: ProcessRuntimeRequirementsOccasion,
   pub create_chunk_assets_occasion: CreateChunkAssetsOccasion,
@@ -27,27 +33,134 @@ pub struct Cache {
 
 impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
+    Self::with_cache_options(&options.cache, &options.snapshot)
+  }
+
+  fn with_cache_options(options: &CacheOptions, snapshot_options: &SnapshotOptions) -> Self {
     Self {
       is_idle: true.into(),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
+      snapshot_options: snapshot_options.clone(),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(
+        options,
+        snapshot_options,
+        "code-generation",
+      )),
       process_runtime_requirements_occasion: ProcessRuntimeRequirementsOccasion::new(new_storage(
-        &options.cache,
+        options,
+        snapshot_options,
+        "runtime-requirements",
+      )),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        options,
+        snapshot_options,
+        "chunk-assets",
       )),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
     }
   }
 
-  pub fn set_modified_files(&self, _modified_files: Vec<PathBuf>) {
-    // TODO
+  /// Drop the entries of modules built from the modified files.
+  ///
+  /// Chunk assets are keyed by chunk id, they are validated against the built modules
+  /// when being used, so only the module based occasions are invalidated here.
+  /// Files in immutable paths never invalidate anything.
+  pub fn set_modified_files(&self, modified_files: Vec<PathBuf>) {
+    let modified_files = modified_files
+      .iter()
+      .filter(|file| !self.snapshot_options.is_immutable_path(file))
+      .filter_map(|file| file.to_str())
+      .collect::<Vec<_>>();
+    if modified_files.is_empty() {
+      return;
+    }
+    let is_unaffected = |id: &Identifier| {
+      !id
+        .split(['|', '!', '?'])
+        .any(|part| modified_files.contains(&part))
+    };
+    self.code_generate_occasion.retain(&is_unaffected);
+    self
+      .process_runtime_requirements_occasion
+      .retain(&is_unaffected);
   }
 
   pub fn begin_idle(&self) {
-    if self.is_idle.load(Ordering::Relaxed) {
-      // TODO clean cache
+    if self.is_idle.swap(true, Ordering::Relaxed) {
+      return;
     }
+    self.code_generate_occasion.begin_idle();
+    self.process_runtime_requirements_occasion.begin_idle();
+    self.create_chunk_assets_occasion.begin_idle();
   }
 
   pub fn end_idle(&self) {
-    self.is_idle.store(false, Ordering::Relaxed);
+    if !self.is_idle.swap(false, Ordering::Relaxed) {
+      return;
+    }
+    self.code_generate_occasion.end_idle();
+    self.process_runtime_requirements_occasion.end_idle();
+    self.create_chunk_assets_occasion.end_idle();
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_hash::RspackHashDigest;
+
+  use super::*;
+  use crate::{CodeGenerationJob, CodeGenerationResult, MemoryCacheOptions, RuntimeSpec};
+
+  fn code_generate(cache: &Cache, module: &str) -> bool {
+    let job = CodeGenerationJob {
+      module: Identifier::from(module),
+      hash: RspackHashDigest::from("hash"),
+      runtime: RuntimeSpec::default(),
+      runtimes: vec![],
+    };
+    let (_, _, from_cache) = cache
+      .code_generate_occasion
+      .use_cache(job, |_, _| Ok(CodeGenerationResult::default()))
+      .expect("should generate code");
+    from_cache
+  }
+
+  #[test]
+  fn test_invalidate_modified_files() {
+    let cache = Cache::with_cache_options(
+      &CacheOptions::Memory(MemoryCacheOptions::default()),
+      &SnapshotOptions::default(),
+    );
+    let immutable = "/node_modules/.pnpm/lib@1.0.0/node_modules/lib/index.js";
+    cache.end_idle();
+    assert!(!code_generate(&cache, "javascript/auto|/src/a.js"));
+    assert!(!code_generate(&cache, "javascript/auto|/src/b.js"));
+    assert!(!code_generate(&cache, "javascript/auto|/src/a.jsx"));
+    assert!(!code_generate(
+      &cache,
+      "javascript/auto|/loader.js!/src/a.js?query"
+    ));
+    assert!(!code_generate(
+      &cache,
+      &format!("javascript/auto|{immutable}")
+    ));
+    cache.begin_idle();
+
+    cache.end_idle();
+    cache.set_modified_files(vec![PathBuf::from("/src/a.js"), PathBuf::from(immutable)]);
+    assert!(!code_generate(&cache, "javascript/auto|/src/a.js"));
+    assert!(code_generate(&cache, "javascript/auto|/src/b.js"));
+    assert!(code_generate(&cache, "javascript/auto|/src/a.jsx"));
+    assert!(!code_generate(
+      &cache,
+      "javascript/auto|/loader.js!/src/a.js?query"
+    ));
+    assert!(code_generate(
+      &cache,
+      &format!("javascript/auto|{immutable}")
+    ));
+    cache.begin_idle();
+
+    let stats = cache.code_generate_occasion.stats();
+    assert_eq!(stats.hits(), 3);
+    assert_eq!(stats.misses(), 7);
   }
 }
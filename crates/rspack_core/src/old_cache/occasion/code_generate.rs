@@ -1,6 +1,7 @@
 use rspack_collections::Identifier;
 use rspack_error::Result;
 
+use super::OccasionStats;
 use crate::{old_cache::storage, CodeGenerationResult};
 use crate::{CodeGenerationJob, ModuleIdentifier, RuntimeSpec};
 
@@ -9,11 +10,37 @@ type Storage = dyn storage::Storage<CodeGenerationResult>;
 #[derive(Debug)]
 pub struct CodeGenerateOccasion {
   storage: Option<Box<Storage>>,
+  stats: OccasionStats,
 }
 
 impl CodeGenerateOccasion {
   pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+    Self {
+      storage,
+      stats: Default::default(),
+    }
+  }
+
+  pub fn stats(&self) -> &OccasionStats {
+    &self.stats
+  }
+
+  pub fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.retain(f);
+    }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.end_idle();
+    }
   }
 
   #[tracing::instrument(skip_all, fields(module = ?job.module))]
@@ -31,8 +58,10 @@ impl CodeGenerateOccasion {
     };
     let cache_key = Identifier::from(format!("{}|{}", job.module, job.hash.encoded()));
     if let Some(value) = storage.get(&cache_key) {
+      self.stats.record(true);
       Ok((value, job.runtimes, true))
     } else {
+      self.stats.record(false);
       let res = provide(job.module, &job.runtime)?;
       storage.set(cache_key, res.clone());
       Ok((res, job.runtimes, false))
@@ -2,6 +2,7 @@ use futures::Future;
 use rspack_collections::Identifier;
 use rspack_error::Result;
 
+use super::OccasionStats;
 use crate::{old_cache::storage, Chunk, Compilation, NormalModuleSource, RenderManifestEntry};
 
 type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
@@ -9,11 +10,37 @@ type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
   storage: Option<Box<Storage>>,
+  stats: OccasionStats,
 }
 
 impl CreateChunkAssetsOccasion {
   pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+    Self {
+      storage,
+      stats: Default::default(),
+    }
+  }
+
+  pub fn stats(&self) -> &OccasionStats {
+    &self.stats
+  }
+
+  pub fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.retain(f);
+    }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.end_idle();
+    }
   }
 
   pub async fn use_cache<G, F>(
@@ -51,9 +78,11 @@ impl CreateChunkAssetsOccasion {
     if is_cache_valid {
       // read
       if let Some(data) = storage.get(&chunk_id) {
+        self.stats.record(true);
         return Ok(data);
       }
     }
+    self.stats.record(false);
     // run generator and save to cache
     let data = generator().await?;
     // TODO sometime may not run save
@@ -1,6 +1,33 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 mod code_generate;
 pub use code_generate::*;
 mod process_runtime_requirements;
 pub use process_runtime_requirements::*;
 mod create_chunk_assets;
 pub use create_chunk_assets::*;
+
+/// Hit and miss counters of an occasion.
+#[derive(Debug, Default)]
+pub struct OccasionStats {
+  hits: AtomicU32,
+  misses: AtomicU32,
+}
+
+impl OccasionStats {
+  pub fn hits(&self) -> u32 {
+    self.hits.load(Ordering::Relaxed)
+  }
+
+  pub fn misses(&self) -> u32 {
+    self.misses.load(Ordering::Relaxed)
+  }
+
+  pub(crate) fn record(&self, hit: bool) {
+    if hit {
+      self.hits.fetch_add(1, Ordering::Relaxed);
+    } else {
+      self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+  }
+}
@@ -1,6 +1,7 @@
 use rspack_collections::Identifier;
 use rspack_error::Result;
 
+use super::OccasionStats;
 use crate::old_cache::storage;
 use crate::{
   get_runtime_key, ChunkGraph, Compilation, ModuleIdentifier, RuntimeGlobals, RuntimeSpec,
@@ -11,11 +12,37 @@ type Storage = dyn storage::Storage<RuntimeGlobals>;
 #[derive(Debug)]
 pub struct ProcessRuntimeRequirementsOccasion {
   storage: Option<Box<Storage>>,
+  stats: OccasionStats,
 }
 
 impl ProcessRuntimeRequirementsOccasion {
   pub fn new(storage: Option<Box<Storage>>) -> Self {
-    Self { storage }
+    Self {
+      storage,
+      stats: Default::default(),
+    }
+  }
+
+  pub fn stats(&self) -> &OccasionStats {
+    &self.stats
+  }
+
+  pub fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    if let Some(storage) = &self.storage {
+      storage.retain(f);
+    }
+  }
+
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  pub fn end_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.end_idle();
+    }
   }
 
   #[tracing::instrument(skip_all, fields(module = ?module))]
@@ -42,8 +69,10 @@ impl ProcessRuntimeRequirementsOccasion {
       get_runtime_key(runtime)
     ));
     if let Some(value) = storage.get(&cache_key) {
+      self.stats.record(true);
       Ok(value)
     } else {
+      self.stats.record(false);
       let res = provide(module, runtime)?;
       storage.set(cache_key, res);
       Ok(res)
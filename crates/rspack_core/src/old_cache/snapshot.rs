@@ -0,0 +1,179 @@
+use std::{
+  collections::BTreeMap,
+  fs,
+  hash::Hash,
+  path::{Path, PathBuf},
+  time::UNIX_EPOCH,
+};
+
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use serde::{Deserialize, Serialize};
+
+use crate::{SnapshotOptions, SnapshotStrategy};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct FileState {
+  /// Milliseconds since unix epoch of the last modification
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  timestamp: Option<u64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  hash: Option<String>,
+}
+
+/// The state of a set of files at some point, used to tell whether any of them changed since.
+///
+/// Files in immutable paths are never recorded, and files in managed paths are recorded
+/// through the `package.json` of the package they belong to, which is checked with the
+/// `resolve` strategy as it decides how the package is resolved.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+  /// `None` for files missing when the snapshot was taken
+  files: BTreeMap<String, Option<FileState>>,
+}
+
+impl Snapshot {
+  pub fn new<'a>(
+    files: impl IntoIterator<Item = &'a Path>,
+    strategy: SnapshotStrategy,
+    options: &SnapshotOptions,
+  ) -> Self {
+    let mut snapshot = Self::default();
+    for file in files {
+      if options.is_immutable_path(file) {
+        continue;
+      }
+      let (file, strategy) = if options.is_managed_path(file)
+        && let Some(package_json) = get_package_json(file)
+      {
+        (package_json, options.resolve)
+      } else {
+        (file.to_path_buf(), strategy)
+      };
+      let key = file.to_string_lossy().to_string();
+      if snapshot.files.contains_key(&key) {
+        continue;
+      }
+      let state = get_file_state(&file, strategy.timestamp(), strategy.hash());
+      snapshot.files.insert(key, state);
+    }
+    snapshot
+  }
+
+  /// Whether none of the recorded files changed.
+  pub fn is_valid(&self) -> bool {
+    self.files.iter().all(|(file, state)| {
+      let file = Path::new(file);
+      let Some(state) = state else {
+        return !file.exists();
+      };
+      if let Some(timestamp) = state.timestamp
+        && get_timestamp(file) == Some(timestamp)
+      {
+        return true;
+      }
+      match &state.hash {
+        Some(hash) => get_hash(file).as_ref() == Some(hash),
+        None => false,
+      }
+    })
+  }
+}
+
+fn get_file_state(file: &Path, timestamp: bool, hash: bool) -> Option<FileState> {
+  if !file.exists() {
+    return None;
+  }
+  Some(FileState {
+    timestamp: if timestamp { get_timestamp(file) } else { None },
+    hash: if hash { get_hash(file) } else { None },
+  })
+}
+
+fn get_timestamp(file: &Path) -> Option<u64> {
+  let modified = fs::metadata(file).ok()?.modified().ok()?;
+  Some(modified.duration_since(UNIX_EPOCH).ok()?.as_millis() as u64)
+}
+
+fn get_hash(file: &Path) -> Option<String> {
+  let content = fs::read(file).ok()?;
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  content.hash(&mut hasher);
+  Some(hasher.digest(&HashDigest::Hex).encoded().to_string())
+}
+
+/// Find the `package.json` of the package containing `file`, which is the closest
+/// directory inside a `node_modules` directory, or inside a scope of it.
+fn get_package_json(file: &Path) -> Option<PathBuf> {
+  let is_node_modules =
+    |dir: Option<&Path>| dir.and_then(|dir| dir.file_name()) == Some("node_modules".as_ref());
+  let mut dir = file.parent();
+  while let Some(current) = dir {
+    let parent = current.parent();
+    let is_scope = current
+      .file_name()
+      .is_some_and(|name| name.to_string_lossy().starts_with('@'));
+    if !is_scope
+      && (is_node_modules(parent)
+        || parent.is_some_and(|parent| {
+          parent
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('@'))
+            && is_node_modules(parent.parent())
+        }))
+    {
+      return Some(current.join("package.json"));
+    }
+    dir = parent;
+  }
+  None
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_get_package_json() {
+    assert_eq!(
+      get_package_json(Path::new("/app/node_modules/react/cjs/react.js")),
+      Some(PathBuf::from("/app/node_modules/react/package.json"))
+    );
+    assert_eq!(
+      get_package_json(Path::new("/app/node_modules/@babel/core/lib/index.js")),
+      Some(PathBuf::from("/app/node_modules/@babel/core/package.json"))
+    );
+    assert_eq!(get_package_json(Path::new("/app/src/index.js")), None);
+  }
+
+  #[test]
+  fn test_snapshot_strategies() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let file = dir.path().join("config.js");
+    fs::write(&file, "a").expect("should write file");
+    let options = SnapshotOptions::default();
+
+    let timestamp = Snapshot::new([file.as_path()], SnapshotStrategy::Timestamp, &options);
+    let hash = Snapshot::new([file.as_path()], SnapshotStrategy::Hash, &options);
+    assert!(timestamp.is_valid());
+    assert!(hash.is_valid());
+
+    let file_time = fs::File::options()
+      .write(true)
+      .open(&file)
+      .expect("should open file");
+    file_time
+      .set_modified(UNIX_EPOCH)
+      .expect("should set modified time");
+    assert!(!timestamp.is_valid());
+    assert!(hash.is_valid());
+
+    fs::write(&file, "b").expect("should write file");
+    assert!(!hash.is_valid());
+
+    fs::remove_file(&file).expect("should remove file");
+    let missing = Snapshot::new([file.as_path()], SnapshotStrategy::Timestamp, &options);
+    assert!(missing.is_valid());
+    fs::write(&file, "c").expect("should write file");
+    assert!(!missing.is_valid());
+  }
+}
@@ -0,0 +1,333 @@
+use std::{
+  fs,
+  hash::Hash,
+  path::{Path, PathBuf},
+  sync::atomic::{AtomicBool, Ordering},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_collections::{Identifier, IdentifierDashMap};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Persistable, Storage};
+use crate::{old_cache::Snapshot, FileSystemCacheOptions, SnapshotOptions};
+
+/// Unused entries stay one month in the filesystem cache when `max_age` is not set.
+const DEFAULT_MAX_AGE: u64 = 1000 * 60 * 60 * 24 * 30;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Pack {
+  /// Hash of the cache version and the build dependency paths,
+  /// the pack is dropped when it doesn't match the current one.
+  fingerprint: String,
+  /// The pack is dropped when any of the build dependencies changed.
+  build_dependencies: Snapshot,
+  entries: Vec<PackEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PackEntry {
+  key: String,
+  /// Milliseconds since unix epoch of the last access
+  last_used: u64,
+  /// The entry is dropped when any of the files in its key changed.
+  #[serde(default)]
+  snapshot: Snapshot,
+  data: Value,
+}
+
+#[derive(Debug)]
+struct Entry<Item> {
+  data: Item,
+  last_used: u64,
+  /// Taken when the entry is first written to the pack
+  snapshot: Option<Snapshot>,
+}
+
+/// A storage backed by a pack file in the cache location, entries are restored when the
+/// storage is created and written back when the compiler becomes idle.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  data: IdentifierDashMap<Entry<Item>>,
+  pack_path: PathBuf,
+  fingerprint: String,
+  build_dependencies: Snapshot,
+  snapshot_options: SnapshotOptions,
+  max_age: u64,
+  dirty: AtomicBool,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: Persistable,
+{
+  /// Returns `None` when no cache location is configured.
+  pub fn new(
+    options: &FileSystemCacheOptions,
+    snapshot_options: &SnapshotOptions,
+    name: &str,
+  ) -> Option<Self> {
+    let cache_location = get_cache_location(options)?;
+    let max_age = match options.max_age {
+      0 => DEFAULT_MAX_AGE,
+      max_age => max_age as u64,
+    };
+    let storage = Self {
+      data: DashMap::default(),
+      pack_path: cache_location.join(format!("{name}.pack")),
+      fingerprint: get_fingerprint(options),
+      build_dependencies: Snapshot::new(
+        options.build_dependencies.iter().map(Path::new),
+        snapshot_options.build_dependencies,
+        snapshot_options,
+      ),
+      snapshot_options: snapshot_options.clone(),
+      max_age,
+      dirty: AtomicBool::new(false),
+    };
+    storage.restore();
+    Some(storage)
+  }
+
+  fn restore(&self) {
+    let Ok(content) = fs::read(&self.pack_path) else {
+      return;
+    };
+    let Ok(pack) = serde_json::from_slice::<Pack>(&content) else {
+      self.dirty.store(true, Ordering::Relaxed);
+      return;
+    };
+    if pack.fingerprint != self.fingerprint || !pack.build_dependencies.is_valid() {
+      self.dirty.store(true, Ordering::Relaxed);
+      return;
+    }
+    let now = now();
+    for entry in pack.entries {
+      if now.saturating_sub(entry.last_used) > self.max_age || !entry.snapshot.is_valid() {
+        self.dirty.store(true, Ordering::Relaxed);
+        continue;
+      }
+      if let Some(data) = Item::restore(entry.data) {
+        self.data.insert(
+          Identifier::from(entry.key),
+          Entry {
+            data,
+            last_used: entry.last_used,
+            snapshot: Some(entry.snapshot),
+          },
+        );
+      }
+    }
+  }
+
+  fn store(&self) -> std::io::Result<()> {
+    let now = now();
+    let entries = self
+      .data
+      .iter_mut()
+      .filter(|entry| now.saturating_sub(entry.last_used) <= self.max_age)
+      .filter_map(|mut entry| {
+        let data = entry.data.persist()?;
+        let key = entry.key().to_string();
+        let snapshot = entry
+          .snapshot
+          .get_or_insert_with(|| {
+            Snapshot::new(
+              get_entry_files(&key),
+              self.snapshot_options.module,
+              &self.snapshot_options,
+            )
+          })
+          .clone();
+        Some(PackEntry {
+          key,
+          last_used: entry.last_used,
+          snapshot,
+          data,
+        })
+      })
+      .collect();
+    let pack = Pack {
+      fingerprint: self.fingerprint.clone(),
+      build_dependencies: self.build_dependencies.clone(),
+      entries,
+    };
+    let content = serde_json::to_vec(&pack)?;
+    if let Some(dir) = self.pack_path.parent() {
+      fs::create_dir_all(dir)?;
+    }
+    // write to a temporary file first, so an interrupted write never leaves a broken pack
+    let temp_path = self.pack_path.with_extension("pack_");
+    fs::write(&temp_path, content)?;
+    fs::rename(temp_path, &self.pack_path)
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Persistable + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    let mut entry = self.data.get_mut(id)?;
+    entry.last_used = now();
+    self.dirty.store(true, Ordering::Relaxed);
+    Some(entry.data.clone())
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(
+      id,
+      Entry {
+        data,
+        last_used: now(),
+        snapshot: None,
+      },
+    );
+    self.dirty.store(true, Ordering::Relaxed);
+  }
+
+  fn remove(&self, id: &Identifier) {
+    if self.data.remove(id).is_some() {
+      self.dirty.store(true, Ordering::Relaxed);
+    }
+  }
+
+  fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    let len = self.data.len();
+    self.data.retain(|id, _| f(id));
+    if self.data.len() != len {
+      self.dirty.store(true, Ordering::Relaxed);
+    }
+  }
+
+  fn begin_idle(&self) {
+    if !self.dirty.swap(false, Ordering::Relaxed) {
+      return;
+    }
+    if let Err(e) = self.store() {
+      self.dirty.store(true, Ordering::Relaxed);
+      tracing::warn!(
+        "Failed to write cache pack {}: {e}",
+        self.pack_path.display()
+      );
+    }
+  }
+}
+
+fn get_cache_location(options: &FileSystemCacheOptions) -> Option<PathBuf> {
+  if !options.cache_location.is_empty() {
+    return Some(PathBuf::from(&options.cache_location));
+  }
+  if !options.cache_directory.is_empty() {
+    return Some(Path::new(&options.cache_directory).join(&options.name));
+  }
+  None
+}
+
+/// The module files an entry is keyed by, e.g. the resource and loaders of a module identifier.
+fn get_entry_files(key: &str) -> impl Iterator<Item = &Path> {
+  key
+    .split(['|', '!', '?'])
+    .map(Path::new)
+    .filter(|path| path.is_absolute())
+}
+
+fn get_fingerprint(options: &FileSystemCacheOptions) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  options.name.hash(&mut hasher);
+  options.version.hash(&mut hasher);
+  options.build_dependencies.hash(&mut hasher);
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{RuntimeGlobals, SnapshotStrategy};
+
+  fn options(cache_location: &Path, version: &str) -> FileSystemCacheOptions {
+    FileSystemCacheOptions {
+      cache_location: cache_location.to_string_lossy().to_string(),
+      version: version.to_string(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_restore_after_restart() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let cache_location = dir.path().join("cache");
+    let key = Identifier::from("module");
+
+    let storage = FileSystemStorage::<RuntimeGlobals>::new(
+      &options(&cache_location, "1"),
+      &SnapshotOptions::default(),
+      "test",
+    )
+    .expect("should create storage");
+    storage.set(key, RuntimeGlobals::PUBLIC_PATH);
+    storage.begin_idle();
+
+    let storage = FileSystemStorage::<RuntimeGlobals>::new(
+      &options(&cache_location, "1"),
+      &SnapshotOptions::default(),
+      "test",
+    )
+    .expect("should create storage");
+    assert_eq!(storage.get(&key), Some(RuntimeGlobals::PUBLIC_PATH));
+
+    let storage = FileSystemStorage::<RuntimeGlobals>::new(
+      &options(&cache_location, "2"),
+      &SnapshotOptions::default(),
+      "test",
+    )
+    .expect("should create storage");
+    assert_eq!(storage.get(&key), None);
+  }
+
+  #[test]
+  fn test_invalidate_modified_modules() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let cache_location = dir.path().join("cache");
+    let a = dir.path().join("a.js");
+    let b = dir.path().join("b.js");
+    fs::write(&a, "a").expect("should write file");
+    fs::write(&b, "b").expect("should write file");
+    let key_a = Identifier::from(format!("javascript/auto|{}|hash", a.display()));
+    let key_b = Identifier::from(format!("javascript/auto|{}|hash", b.display()));
+    let snapshot_options = SnapshotOptions {
+      module: SnapshotStrategy::Hash,
+      ..Default::default()
+    };
+
+    let storage = FileSystemStorage::<RuntimeGlobals>::new(
+      &options(&cache_location, "1"),
+      &snapshot_options,
+      "test",
+    )
+    .expect("should create storage");
+    storage.set(key_a, RuntimeGlobals::PUBLIC_PATH);
+    storage.set(key_b, RuntimeGlobals::PUBLIC_PATH);
+    storage.begin_idle();
+
+    fs::write(&a, "changed").expect("should write file");
+    let storage = FileSystemStorage::<RuntimeGlobals>::new(
+      &options(&cache_location, "1"),
+      &snapshot_options,
+      "test",
+    )
+    .expect("should create storage");
+    assert_eq!(storage.get(&key_a), None);
+    assert_eq!(storage.get(&key_b), Some(RuntimeGlobals::PUBLIC_PATH));
+  }
+}
@@ -1,17 +1,31 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use dashmap::DashMap;
 use rspack_collections::{Identifier, IdentifierDashMap};
 
 use super::Storage;
 
+#[derive(Debug)]
+struct Entry<Item> {
+  data: Item,
+  /// The generation in which the entry was used last time
+  generation: u32,
+}
+
 #[derive(Debug)]
 pub struct MemoryStorage<Item> {
-  data: IdentifierDashMap<Item>,
+  data: IdentifierDashMap<Entry<Item>>,
+  generation: AtomicU32,
+  /// Entries unused for this many generations are dropped when idle, `0` keeps them forever.
+  max_generations: u32,
 }
 
 impl<Item> MemoryStorage<Item> {
-  pub fn new() -> Self {
+  pub fn new(max_generations: u32) -> Self {
     Self {
       data: DashMap::default(),
+      generation: AtomicU32::new(0),
+      max_generations,
     }
   }
 }
@@ -21,12 +35,61 @@ where
   Item: Clone + std::fmt::Debug + Send + Sync,
 {
   fn get(&self, id: &Identifier) -> Option<Item> {
-    self.data.get(id).map(|item| item.clone())
+    let mut entry = self.data.get_mut(id)?;
+    entry.generation = self.generation.load(Ordering::Relaxed);
+    Some(entry.data.clone())
   }
   fn set(&self, id: Identifier, data: Item) {
-    self.data.insert(id, data);
+    self.data.insert(
+      id,
+      Entry {
+        data,
+        generation: self.generation.load(Ordering::Relaxed),
+      },
+    );
   }
   fn remove(&self, id: &Identifier) {
     self.data.remove(id);
   }
+  fn retain(&self, f: &dyn Fn(&Identifier) -> bool) {
+    self.data.retain(|id, _| f(id));
+  }
+  fn begin_idle(&self) {
+    if self.max_generations == 0 {
+      return;
+    }
+    let generation = self.generation.load(Ordering::Relaxed);
+    self
+      .data
+      .retain(|_, entry| generation - entry.generation < self.max_generations);
+  }
+  fn end_idle(&self) {
+    self.generation.fetch_add(1, Ordering::Relaxed);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_drop_unused_generations() {
+    let storage = MemoryStorage::new(2);
+    let used = Identifier::from("used");
+    let unused = Identifier::from("unused");
+    storage.set(used, 1);
+    storage.set(unused, 2);
+    storage.begin_idle();
+
+    storage.end_idle();
+    assert_eq!(storage.get(&used), Some(1));
+    storage.begin_idle();
+    assert!(storage.data.contains_key(&unused));
+
+    storage.end_idle();
+    assert_eq!(storage.get(&used), Some(1));
+    storage.begin_idle();
+    assert!(storage.data.contains_key(&used));
+    assert!(!storage.data.contains_key(&unused));
+  }
 }
@@ -2,26 +2,46 @@ use std::fmt::Debug;
 
 use rspack_collections::Identifier;
 
-use crate::CacheOptions;
+use crate::{CacheOptions, SnapshotOptions};
 
+mod filesystem;
 mod memory;
+mod persistable;
+use filesystem::FileSystemStorage;
 use memory::MemoryStorage;
+pub use persistable::Persistable;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
   fn set(&self, id: Identifier, data: Item);
   fn remove(&self, id: &Identifier);
-  // fn begin_idle(&self);
-  // fn end_idle(&self);
+  /// Keep only the entries whose key satisfies `f`.
+  fn retain(&self, f: &dyn Fn(&Identifier) -> bool);
+  /// Called when the compiler becomes idle, unused entries are cleaned
+  /// and persistent storages write their data here.
+  fn begin_idle(&self) {}
+  /// Called when a new compilation starts.
+  fn end_idle(&self) {}
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Create the storage of an occasion, `name` identifies the occasion in the persistent cache.
+pub fn new_storage<Item>(
+  options: &CacheOptions,
+  snapshot_options: &SnapshotOptions,
+  name: &str,
+) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: Persistable + Debug + Clone + Send + Sync + 'static,
 {
   match options {
     CacheOptions::Disabled => None,
-    _ => Some(Box::new(MemoryStorage::new())),
+    CacheOptions::FileSystem(options) => {
+      match FileSystemStorage::new(options, snapshot_options, name) {
+        Some(storage) => Some(Box::new(storage)),
+        None => Some(Box::new(MemoryStorage::new(0))),
+      }
+    }
+    CacheOptions::Memory(options) => Some(Box::new(MemoryStorage::new(options.max_generations))),
   }
 }
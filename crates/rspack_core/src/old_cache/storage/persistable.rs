@@ -0,0 +1,234 @@
+use rspack_hash::RspackHashDigest;
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, SourceExt, SourceMap, SourceMapSource, WithoutOriginalOptions,
+};
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+  AssetInfo, AssetInfoRelated, CodeGenerationResult, RenderManifestEntry, RuntimeGlobals,
+  SourceType,
+};
+
+/// Items which can be written to the persistent cache.
+pub trait Persistable: Sized {
+  /// Returns `None` when the item holds state that can't be serialized,
+  /// such an item is only kept in memory.
+  fn persist(&self) -> Option<Value>;
+  fn restore(value: Value) -> Option<Self>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedSource {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  code: Option<String>,
+  /// Base64 encoded content for non-utf8 sources
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  buffer: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  map: Option<String>,
+}
+
+impl PersistedSource {
+  fn new(source: &BoxSource) -> Option<Self> {
+    let buffer = source.buffer();
+    let (code, buffer) = match std::str::from_utf8(&buffer) {
+      Ok(code) => (Some(code.to_string()), None),
+      Err(_) => (None, Some(rspack_base64::encode_to_string(&buffer))),
+    };
+    let map = match source.map(&MapOptions::default()) {
+      Some(map) => Some(map.to_json().ok()?),
+      None => None,
+    };
+    Some(Self { code, buffer, map })
+  }
+
+  fn into_source(self) -> Option<BoxSource> {
+    if let Some(buffer) = self.buffer {
+      return Some(RawSource::from(rspack_base64::decode_to_vec(buffer).ok()?).boxed());
+    }
+    let code = self.code?;
+    match self.map {
+      Some(map) => {
+        let source_map = SourceMap::from_json(&map).ok()?;
+        let name = source_map.file().unwrap_or_default().to_string();
+        Some(
+          SourceMapSource::new(WithoutOriginalOptions {
+            value: code,
+            name,
+            source_map,
+          })
+          .boxed(),
+        )
+      }
+      None => Some(RawSource::from(code).boxed()),
+    }
+  }
+}
+
+impl Persistable for RuntimeGlobals {
+  fn persist(&self) -> Option<Value> {
+    Some(Value::String(self.bits().to_string()))
+  }
+
+  fn restore(value: Value) -> Option<Self> {
+    let bits = value.as_str()?.parse().ok()?;
+    Some(RuntimeGlobals::from_bits_retain(bits))
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedCodeGenerationResult {
+  sources: Vec<(String, PersistedSource)>,
+  runtime_requirements: Value,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  hash: Option<String>,
+}
+
+impl Persistable for CodeGenerationResult {
+  fn persist(&self) -> Option<Value> {
+    // code generation data and init fragments are trait objects provided by plugins,
+    // results using them can't be restored faithfully
+    if !self.data.is_empty()
+      || !self.chunk_init_fragments.is_empty()
+      || self.concatenation_scope.is_some()
+    {
+      return None;
+    }
+    let sources = self
+      .inner
+      .iter()
+      .map(|(source_type, source)| Some((source_type.to_string(), PersistedSource::new(source)?)))
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_value(PersistedCodeGenerationResult {
+      sources,
+      runtime_requirements: self.runtime_requirements.persist()?,
+      hash: self.hash.as_ref().map(|hash| hash.encoded().to_string()),
+    })
+    .ok()
+  }
+
+  fn restore(value: Value) -> Option<Self> {
+    let persisted: PersistedCodeGenerationResult = serde_json::from_value(value).ok()?;
+    let mut result = CodeGenerationResult {
+      runtime_requirements: RuntimeGlobals::restore(persisted.runtime_requirements)?,
+      hash: persisted
+        .hash
+        .map(|hash| RspackHashDigest::from(hash.as_str())),
+      ..Default::default()
+    };
+    for (source_type, source) in persisted.sources {
+      result.add(
+        SourceType::from(source_type.as_str()),
+        source.into_source()?,
+      );
+    }
+    Some(result)
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedAssetInfo {
+  immutable: bool,
+  minimized: bool,
+  full_hash: HashSet<String>,
+  chunk_hash: HashSet<String>,
+  content_hash: HashSet<String>,
+  source_filename: Option<String>,
+  development: bool,
+  hot_module_replacement: bool,
+  javascript_module: Option<bool>,
+  related_source_map: Option<String>,
+  version: String,
+  css_unused_idents: Option<HashSet<String>>,
+  extras: serde_json::Map<String, Value>,
+  is_over_size_limit: Option<bool>,
+}
+
+impl From<&AssetInfo> for PersistedAssetInfo {
+  fn from(info: &AssetInfo) -> Self {
+    Self {
+      immutable: info.immutable,
+      minimized: info.minimized,
+      full_hash: info.full_hash.clone(),
+      chunk_hash: info.chunk_hash.clone(),
+      content_hash: info.content_hash.clone(),
+      source_filename: info.source_filename.clone(),
+      development: info.development,
+      hot_module_replacement: info.hot_module_replacement,
+      javascript_module: info.javascript_module,
+      related_source_map: info.related.source_map.clone(),
+      version: info.version.clone(),
+      css_unused_idents: info.css_unused_idents.clone(),
+      extras: info.extras.clone(),
+      is_over_size_limit: info.is_over_size_limit,
+    }
+  }
+}
+
+impl From<PersistedAssetInfo> for AssetInfo {
+  fn from(info: PersistedAssetInfo) -> Self {
+    Self {
+      immutable: info.immutable,
+      minimized: info.minimized,
+      full_hash: info.full_hash,
+      chunk_hash: info.chunk_hash,
+      content_hash: info.content_hash,
+      source_filename: info.source_filename,
+      development: info.development,
+      hot_module_replacement: info.hot_module_replacement,
+      javascript_module: info.javascript_module,
+      related: AssetInfoRelated {
+        source_map: info.related_source_map,
+      },
+      version: info.version,
+      css_unused_idents: info.css_unused_idents,
+      extras: info.extras,
+      is_over_size_limit: info.is_over_size_limit,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedRenderManifestEntry {
+  source: PersistedSource,
+  filename: String,
+  info: PersistedAssetInfo,
+  auxiliary: bool,
+  has_filename: bool,
+}
+
+impl Persistable for Vec<RenderManifestEntry> {
+  fn persist(&self) -> Option<Value> {
+    let entries = self
+      .iter()
+      .map(|entry| {
+        Some(PersistedRenderManifestEntry {
+          source: PersistedSource::new(entry.source())?,
+          filename: entry.filename().to_string(),
+          info: PersistedAssetInfo::from(&entry.info),
+          auxiliary: entry.auxiliary,
+          has_filename: entry.has_filename(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_value(entries).ok()
+  }
+
+  fn restore(value: Value) -> Option<Self> {
+    let entries: Vec<PersistedRenderManifestEntry> = serde_json::from_value(value).ok()?;
+    entries
+      .into_iter()
+      .map(|entry| {
+        Some(RenderManifestEntry::new(
+          entry.source.into_source()?,
+          entry.filename,
+          entry.info.into(),
+          entry.auxiliary,
+          entry.has_filename,
+        ))
+      })
+      .collect()
+  }
+}
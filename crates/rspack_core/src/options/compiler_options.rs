@@ -20,6 +20,8 @@ pub struct CompilerOptions {
   pub cache: CacheOptions,
   pub experiments: Experiments,
   pub node: Option<NodeOption>,
+  /// JSON of the options exposed as `define.amd` and `require.amd`, AMD is disabled when `None`
+  pub amd: Option<String>,
   pub optimization: Optimization,
   pub profile: bool,
   pub bail: bool,
@@ -429,6 +429,7 @@ pub struct CssAutoGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -437,6 +438,7 @@ pub struct CssModuleGeneratorOptions {
   pub exports_only: Option<bool>,
   pub local_ident_name: Option<LocalIdentName>,
   pub es_module: Option<bool>,
+  pub remove_unused_rules: Option<bool>,
 }
 
 #[derive(Debug, Clone, MergeFrom)]
@@ -1,6 +1,79 @@
-#[derive(Debug, Default)]
+use rspack_error::{error, Result};
+
+/// How `__dirname` and `__filename` are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeDirnameOption {
+  /// Kept as they are
+  False,
+  /// The path relative to the context
+  True,
+  /// `/` and `/index.js`
+  Mock,
+  /// Like [NodeDirnameOption::Mock] with a warning, as they aren't available in browsers
+  WarnMock,
+  /// Computed from `import.meta.url` at runtime
+  NodeModule,
+  /// Kept as they are, but evaluated to the real path at build time
+  EvalOnly,
+}
+
+impl TryFrom<&str> for NodeDirnameOption {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "false" => Ok(Self::False),
+      "true" => Ok(Self::True),
+      "mock" => Ok(Self::Mock),
+      "warn-mock" => Ok(Self::WarnMock),
+      "node-module" => Ok(Self::NodeModule),
+      "eval-only" => Ok(Self::EvalOnly),
+      _ => Err(error!(
+        "Unknown node option value \"{value}\", expected one of false, true, \"mock\", \"warn-mock\", \"node-module\" or \"eval-only\""
+      )),
+    }
+  }
+}
+
+/// How `global` is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeGlobalOption {
+  /// Kept as it is
+  False,
+  /// Replaced with the global object of the runtime
+  True,
+  /// Like [NodeGlobalOption::True] with a warning, as it isn't available in browsers
+  Warn,
+}
+
+impl TryFrom<&str> for NodeGlobalOption {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
+    match value {
+      "false" => Ok(Self::False),
+      "true" => Ok(Self::True),
+      "warn" => Ok(Self::Warn),
+      _ => Err(error!(
+        "Unknown node.global value \"{value}\", expected one of false, true or \"warn\""
+      )),
+    }
+  }
+}
+
+#[derive(Debug)]
 pub struct NodeOption {
-  pub dirname: String,
-  pub global: String,
-  pub filename: String,
+  pub dirname: NodeDirnameOption,
+  pub global: NodeGlobalOption,
+  pub filename: NodeDirnameOption,
+}
+
+impl Default for NodeOption {
+  fn default() -> Self {
+    Self {
+      dirname: NodeDirnameOption::WarnMock,
+      global: NodeGlobalOption::Warn,
+      filename: NodeDirnameOption::WarnMock,
+    }
+  }
 }
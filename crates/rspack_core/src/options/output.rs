@@ -3,6 +3,7 @@ use std::{borrow::Cow, fmt::Debug, hash::Hash, str::FromStr, string::ParseError}
 
 use derivative::Derivative;
 use regex::Regex;
+use rspack_error::{error, Result};
 use rspack_hash::RspackHash;
 pub use rspack_hash::{HashDigest, HashFunction, HashSalt};
 use rspack_macros::MergeFrom;
@@ -31,6 +32,8 @@ pub struct OutputOptions {
   pub webassembly_module_filename: FilenameTemplate,
   pub unique_name: String,
   pub chunk_loading: ChunkLoading,
+  /// Names of the [ChunkLoadingType::Custom] chunk loadings that plugins provide a runtime for
+  pub custom_chunk_loading_types: Vec<String>,
   pub chunk_loading_global: String,
   pub chunk_load_timeout: u32,
   pub charset: bool,
@@ -100,8 +103,8 @@ impl From<ChunkLoading> for String {
   }
 }
 
-impl From<&ChunkLoading> for &str {
-  fn from(value: &ChunkLoading) -> Self {
+impl<'a> From<&'a ChunkLoading> for &'a str {
+  fn from(value: &'a ChunkLoading) -> Self {
     match value {
       ChunkLoading::Enable(ty) => ty.into(),
       ChunkLoading::Disable => "false",
@@ -116,7 +119,8 @@ pub enum ChunkLoadingType {
   Require,
   AsyncNode,
   Import,
-  // TODO: Custom
+  /// A chunk loading whose runtime is provided by a plugin
+  Custom(String),
 }
 
 impl From<&str> for ChunkLoadingType {
@@ -127,7 +131,7 @@ impl From<&str> for ChunkLoadingType {
       "require" => Self::Require,
       "async-node" => Self::AsyncNode,
       "import" => Self::Import,
-      _ => unimplemented!("custom chunkLoading in not supported yet"),
+      _ => Self::Custom(value.to_string()),
     }
   }
 }
@@ -138,47 +142,60 @@ impl From<ChunkLoadingType> for String {
   }
 }
 
-impl From<&ChunkLoadingType> for &str {
-  fn from(value: &ChunkLoadingType) -> Self {
+impl<'a> From<&'a ChunkLoadingType> for &'a str {
+  fn from(value: &'a ChunkLoadingType) -> Self {
     match value {
       ChunkLoadingType::Jsonp => "jsonp",
       ChunkLoadingType::ImportScripts => "import-scripts",
       ChunkLoadingType::Require => "require",
       ChunkLoadingType::AsyncNode => "async-node",
       ChunkLoadingType::Import => "import",
+      ChunkLoadingType::Custom(value) => value,
     }
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum WasmLoading {
   Enable(WasmLoadingType),
   Disable,
 }
 
-impl From<&str> for WasmLoading {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for WasmLoading {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
     match value {
-      "false" => Self::Disable,
-      v => Self::Enable(v.into()),
+      "false" => Ok(Self::Disable),
+      v => Ok(Self::Enable(v.try_into()?)),
     }
   }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum WasmLoadingType {
   Fetch,
   AsyncNode,
   AsyncNodeModule,
+  /// Loads with `fetch` in browsers and with `fs` in Node.js
+  Universal,
+  /// Embeds small wasm binaries into the JavaScript as base64
+  Inline,
 }
 
-impl From<&str> for WasmLoadingType {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for WasmLoadingType {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: &str) -> Result<Self> {
     match value {
-      "fetch" => Self::Fetch,
-      "async-node" => Self::AsyncNode,
-      "async-node-module" => Self::AsyncNodeModule,
-      _ => todo!(),
+      "fetch" => Ok(Self::Fetch),
+      "async-node" => Ok(Self::AsyncNode),
+      "async-node-module" => Ok(Self::AsyncNodeModule),
+      "universal" => Ok(Self::Universal),
+      "inline" => Ok(Self::Inline),
+      _ => Err(error!(
+        "Unknown wasm loading type \"{value}\", expected one of \"fetch\", \"async-node\", \"async-node-module\", \"universal\" or \"inline\""
+      )),
     }
   }
 }
@@ -445,13 +462,41 @@ pub struct LibraryCustomUmdObject {
   pub root: Option<Vec<String>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct Environment {
   pub r#const: Option<bool>,
   pub arrow_function: Option<bool>,
+  pub destructuring: Option<bool>,
+  pub for_of: Option<bool>,
+  pub optional_chaining: Option<bool>,
+  pub template_literal: Option<bool>,
+  pub big_int_literal: Option<bool>,
+  pub dynamic_import: Option<bool>,
+  pub dynamic_import_in_worker: Option<bool>,
+  pub module: Option<bool>,
+  pub global_this: Option<bool>,
+  pub async_function: Option<bool>,
 }
 
 impl Environment {
+  /// Fill the unset features with the ones of `other`, e.g. resolved from the browserslist target.
+  pub fn fill_unset(&mut self, other: &Environment) {
+    self.r#const = self.r#const.or(other.r#const);
+    self.arrow_function = self.arrow_function.or(other.arrow_function);
+    self.destructuring = self.destructuring.or(other.destructuring);
+    self.for_of = self.for_of.or(other.for_of);
+    self.optional_chaining = self.optional_chaining.or(other.optional_chaining);
+    self.template_literal = self.template_literal.or(other.template_literal);
+    self.big_int_literal = self.big_int_literal.or(other.big_int_literal);
+    self.dynamic_import = self.dynamic_import.or(other.dynamic_import);
+    self.dynamic_import_in_worker = self
+      .dynamic_import_in_worker
+      .or(other.dynamic_import_in_worker);
+    self.module = self.module.or(other.module);
+    self.global_this = self.global_this.or(other.global_this);
+    self.async_function = self.async_function.or(other.async_function);
+  }
+
   pub fn supports_const(&self) -> bool {
     self.r#const.unwrap_or_default()
   }
@@ -459,4 +504,44 @@ impl Environment {
   pub fn supports_arrow_function(&self) -> bool {
     self.arrow_function.unwrap_or_default()
   }
+
+  pub fn supports_destructuring(&self) -> bool {
+    self.destructuring.unwrap_or_default()
+  }
+
+  pub fn supports_for_of(&self) -> bool {
+    self.for_of.unwrap_or_default()
+  }
+
+  pub fn supports_optional_chaining(&self) -> bool {
+    self.optional_chaining.unwrap_or_default()
+  }
+
+  pub fn supports_template_literal(&self) -> bool {
+    self.template_literal.unwrap_or_default()
+  }
+
+  pub fn supports_big_int_literal(&self) -> bool {
+    self.big_int_literal.unwrap_or_default()
+  }
+
+  pub fn supports_dynamic_import(&self) -> bool {
+    self.dynamic_import.unwrap_or_default()
+  }
+
+  pub fn supports_dynamic_import_in_worker(&self) -> bool {
+    self.dynamic_import_in_worker.unwrap_or_default()
+  }
+
+  pub fn supports_module(&self) -> bool {
+    self.module.unwrap_or_default()
+  }
+
+  pub fn supports_global_this(&self) -> bool {
+    self.global_this.unwrap_or_default()
+  }
+
+  pub fn supports_async_function(&self) -> bool {
+    self.async_function.unwrap_or_default()
+  }
 }
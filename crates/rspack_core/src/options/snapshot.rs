@@ -1,2 +1,116 @@
-#[derive(Debug, Clone, Default)]
-pub struct SnapshotOptions;
+use std::path::Path;
+
+use rspack_regex::RspackRegex;
+
+/// How a file is checked for changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SnapshotStrategy {
+  /// Compare the modification time
+  #[default]
+  Timestamp,
+  /// Compare the hash of the content
+  Hash,
+  /// Compare the modification time first, and fall back to the hash of the content when it
+  /// changed, so touched but unchanged files stay valid
+  TimestampAndHash,
+}
+
+impl SnapshotStrategy {
+  pub fn new(timestamp: bool, hash: bool) -> Self {
+    match (timestamp, hash) {
+      (true, true) => Self::TimestampAndHash,
+      (false, true) => Self::Hash,
+      _ => Self::Timestamp,
+    }
+  }
+
+  pub fn timestamp(&self) -> bool {
+    matches!(self, Self::Timestamp | Self::TimestampAndHash)
+  }
+
+  pub fn hash(&self) -> bool {
+    matches!(self, Self::Hash | Self::TimestampAndHash)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub enum SnapshotPath {
+  /// Matches paths starting with the string
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl SnapshotPath {
+  pub fn test(&self, path: &str) -> bool {
+    match self {
+      Self::String(s) => path.starts_with(s.as_str()),
+      Self::Regexp(r) => r.test(path),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct SnapshotOptions {
+  /// Strategy of the files and directories involved in resolving
+  pub resolve: SnapshotStrategy,
+  /// Strategy of the files a module is built from
+  pub module: SnapshotStrategy,
+  /// Strategy of the build dependencies of the persistent cache
+  pub build_dependencies: SnapshotStrategy,
+  /// Paths managed by a package manager, files in them are checked through the
+  /// `package.json` of their package instead of one by one
+  pub managed_paths: Vec<SnapshotPath>,
+  /// Paths whose content never changes, files in them are never checked
+  pub immutable_paths: Vec<SnapshotPath>,
+}
+
+impl Default for SnapshotOptions {
+  fn default() -> Self {
+    Self {
+      resolve: SnapshotStrategy::Timestamp,
+      module: SnapshotStrategy::Timestamp,
+      build_dependencies: SnapshotStrategy::TimestampAndHash,
+      managed_paths: vec![SnapshotPath::Regexp(
+        RspackRegex::new(r"^(.+?[\\/]node_modules[\\/])").expect("should be a valid regex"),
+      )],
+      // packages installed to a path containing their version, e.g. by pnpm
+      immutable_paths: vec![SnapshotPath::Regexp(
+        RspackRegex::new(r"[\\/]node_modules[\\/].*@\d+\.\d+\.\d+")
+          .expect("should be a valid regex"),
+      )],
+    }
+  }
+}
+
+impl SnapshotOptions {
+  pub fn is_immutable_path(&self, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    self.immutable_paths.iter().any(|p| p.test(&path))
+  }
+
+  pub fn is_managed_path(&self, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    self.managed_paths.iter().any(|p| p.test(&path))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_default_paths() {
+    let options = SnapshotOptions::default();
+    let pnpm = Path::new("/app/node_modules/.pnpm/react@18.2.0/node_modules/react/index.js");
+    assert!(options.is_immutable_path(pnpm));
+    assert!(options.is_managed_path(pnpm));
+
+    let npm = Path::new("/app/node_modules/react/index.js");
+    assert!(!options.is_immutable_path(npm));
+    assert!(options.is_managed_path(npm));
+
+    let source = Path::new("/app/src/index.js");
+    assert!(!options.is_immutable_path(source));
+    assert!(!options.is_managed_path(source));
+  }
+}
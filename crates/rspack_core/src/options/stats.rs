@@ -1,4 +1,46 @@
+use rspack_regex::RspackRegex;
+
+/// Condition of `excludeModules` and `excludeAssets`.
+#[derive(Debug, Clone)]
+pub enum StatsFilter {
+  /// Matches names containing the string as whole path segments,
+  /// e.g. `node_modules` matches `./node_modules/lib/index.js` but not `./my_node_modules/a.js`
+  String(String),
+  Regexp(RspackRegex),
+}
+
+impl StatsFilter {
+  pub fn test(&self, name: &str) -> bool {
+    match self {
+      Self::String(s) => name.match_indices(s.as_str()).any(|(start, matched)| {
+        let before = name[..start].chars().next_back();
+        let after = name[start + matched.len()..].chars().next();
+        matches!(before, Some('/' | '\\')) && matches!(after, None | Some('/' | '\\' | '!' | '?'))
+      }),
+      Self::Regexp(r) => r.test(name),
+    }
+  }
+}
+
 #[derive(Debug, Default)]
 pub struct StatsOptions {
   pub colors: bool,
+  /// Modules with a readable identifier matching any of the filters are excluded
+  pub exclude_modules: Vec<StatsFilter>,
+  /// Assets with a name matching any of the filters are excluded
+  pub exclude_assets: Vec<StatsFilter>,
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_string_filter() {
+    let filter = StatsFilter::String("node_modules".into());
+    assert!(filter.test("./node_modules/lib/index.js"));
+    assert!(filter.test("./loader.js!./node_modules/lib/index.js"));
+    assert!(!filter.test("./my_node_modules/a.js"));
+    assert!(!filter.test("./node_modules.js"));
+  }
 }
@@ -1,11 +1,20 @@
+use std::path::Path;
+
+use browserslist::Distrib;
 use rspack_error::{error, Result};
 pub use swc_core::ecma::ast::EsVersion;
 
+use crate::{
+  ChunkLoadingType, Context, Environment, NodeDirnameOption, NodeGlobalOption, NodeOption,
+  WasmLoadingType,
+};
+
 // TODO(swc-loader): Target still coupled with javascript downgrade, it should only affect runtime
 #[derive(Debug, Clone)]
 pub enum TargetEsVersion {
   Esx(EsVersion),
-  BrowsersList,
+  /// The highest es version supported by all the browsers of a browserslist query
+  BrowsersList(EsVersion),
   None,
 }
 
@@ -14,28 +23,154 @@ impl TargetEsVersion {
     matches!(self, TargetEsVersion::None)
   }
   pub fn is_browsers_list(&self) -> bool {
-    matches!(self, TargetEsVersion::BrowsersList)
+    matches!(self, TargetEsVersion::BrowsersList(_))
+  }
+}
+
+/// The version of a platform target, e.g. `14.17` of `node14.17`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TargetVersion {
+  pub major: u32,
+  pub minor: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetPlatformKind {
+  Web,
+  WebWorker,
+  Node,
+  /// Node.js loading the chunks with `fs` and `vm` instead of `require`
+  AsyncNode,
+  ElectronMain,
+  ElectronPreload,
+  ElectronRenderer,
+  NwJs,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetPlatform {
+  pub kind: TargetPlatformKind,
+  pub version: Option<TargetVersion>,
+}
+
+impl TargetPlatform {
+  /// Whether the platform provides the Node.js builtins
+  pub fn is_node(&self) -> bool {
+    !matches!(
+      self.kind,
+      TargetPlatformKind::Web | TargetPlatformKind::WebWorker
+    )
+  }
+
+  pub fn default_chunk_loading(&self, output_module: bool) -> ChunkLoadingType {
+    if output_module {
+      return ChunkLoadingType::Import;
+    }
+    match self.kind {
+      TargetPlatformKind::Web | TargetPlatformKind::ElectronRenderer | TargetPlatformKind::NwJs => {
+        ChunkLoadingType::Jsonp
+      }
+      TargetPlatformKind::WebWorker => ChunkLoadingType::ImportScripts,
+      TargetPlatformKind::Node
+      | TargetPlatformKind::ElectronMain
+      | TargetPlatformKind::ElectronPreload => ChunkLoadingType::Require,
+      TargetPlatformKind::AsyncNode => ChunkLoadingType::AsyncNode,
+    }
+  }
+
+  /// The chunk loading of the chunks of workers, which can't use `document` to load them.
+  pub fn default_worker_chunk_loading(&self, output_module: bool) -> ChunkLoadingType {
+    if output_module {
+      return ChunkLoadingType::Import;
+    }
+    match self.kind {
+      TargetPlatformKind::Web
+      | TargetPlatformKind::WebWorker
+      | TargetPlatformKind::ElectronRenderer
+      | TargetPlatformKind::NwJs => ChunkLoadingType::ImportScripts,
+      TargetPlatformKind::Node
+      | TargetPlatformKind::ElectronMain
+      | TargetPlatformKind::ElectronPreload => ChunkLoadingType::Require,
+      TargetPlatformKind::AsyncNode => ChunkLoadingType::AsyncNode,
+    }
+  }
+
+  pub fn default_wasm_loading(&self, output_module: bool) -> WasmLoadingType {
+    match self.kind {
+      TargetPlatformKind::Web
+      | TargetPlatformKind::WebWorker
+      | TargetPlatformKind::ElectronRenderer => WasmLoadingType::Fetch,
+      _ if output_module => WasmLoadingType::AsyncNodeModule,
+      _ => WasmLoadingType::AsyncNode,
+    }
+  }
+
+  pub fn default_global_object(&self) -> &'static str {
+    if self.is_node() {
+      "global"
+    } else {
+      "self"
+    }
+  }
+
+  /// How `__dirname`, `__filename` and `global` are handled, they are kept on the platforms
+  /// providing them.
+  pub fn default_node_option(&self, output_module: bool) -> NodeOption {
+    if self.is_node() {
+      let dirname = if output_module {
+        NodeDirnameOption::NodeModule
+      } else {
+        NodeDirnameOption::EvalOnly
+      };
+      NodeOption {
+        dirname,
+        filename: dirname,
+        global: NodeGlobalOption::False,
+      }
+    } else {
+      NodeOption::default()
+    }
   }
 }
 
 #[derive(Debug, Clone)]
 pub struct Target {
   pub es_version: TargetEsVersion,
+  /// The features supported by all the browsers of a browserslist target, unset otherwise
+  pub environment: Environment,
+  /// The platform of `web`, `webworker`, `node[version]`, `async-node[version]`,
+  /// `electron[version]-main|preload|renderer` or `nwjs[version]`, or `node` of the lowest
+  /// version when a browserslist only matches Node.js
+  pub platform: Option<TargetPlatform>,
 }
 
 impl Target {
-  pub fn new(args: &Vec<String>) -> Result<Target> {
+  /// Resolve the targets, the browserslist config is looked up from the `context`.
+  pub fn new(args: &Vec<String>, context: &Context) -> Result<Target> {
     let mut es_version = TargetEsVersion::None;
+    let mut environment = Environment::default();
+    let mut platform: Option<(&str, TargetPlatform)> = None;
+    let mut browsers_platform: Option<TargetPlatform> = None;
 
     for item in args {
       let item = item.as_str();
-      if item.starts_with("es") || item == "browserslist" {
+      if let Some(current) = parse_platform(item)? {
+        if let Some((existing, _)) = platform {
+          return Err(error!(
+            "Target platform conflict, \"{existing}\" and \"{item}\" can't be used together"
+          ));
+        }
+        platform = Some((item, current));
+        continue;
+      }
+      if item.starts_with("es") || item.starts_with("browserslist") {
         // es version
         if !es_version.is_none() {
-          return Err(error!("Target es version conflict"));
+          return Err(error!(
+            "Target es version conflict, \"{item}\" can't be used with another es version or browserslist"
+          ));
         }
         let version = match item {
-          "browserslist" => TargetEsVersion::BrowsersList,
           "es3" => TargetEsVersion::Esx(EsVersion::Es3),
           "es5" => TargetEsVersion::Esx(EsVersion::Es5),
           "es6" => TargetEsVersion::Esx(EsVersion::Es2015),
@@ -47,6 +182,12 @@ impl Target {
           "es2020" => TargetEsVersion::Esx(EsVersion::Es2020),
           "es2021" => TargetEsVersion::Esx(EsVersion::Es2021),
           "es2022" => TargetEsVersion::Esx(EsVersion::Es2022),
+          _ if item.starts_with("browserslist") => {
+            let browsers = resolve_browsers(item, context)?;
+            environment = browsers_environment(&browsers);
+            browsers_platform = infer_browsers_platform(&browsers);
+            TargetEsVersion::BrowsersList(environment_es_version(&environment))
+          }
           _ => {
             return Err(error!("Unknown target es version {}", item));
           }
@@ -54,8 +195,577 @@ impl Target {
         es_version = version;
         continue;
       }
+      return Err(error!("Unknown target \"{item}\""));
+    }
+
+    Ok(Target {
+      es_version,
+      environment,
+      platform: platform.map(|(_, platform)| platform).or(browsers_platform),
+    })
+  }
+
+  /// The platform the options left unset fall back to, it's `web` when the targets only have an
+  /// es version or a browserslist matching any browser.
+  pub fn platform_or_web(&self) -> TargetPlatform {
+    self.platform.clone().unwrap_or(TargetPlatform {
+      kind: TargetPlatformKind::Web,
+      version: None,
+    })
+  }
+}
+
+/// Parse a platform target, returns `None` when it's not a platform.
+fn parse_platform(item: &str) -> Result<Option<TargetPlatform>> {
+  let (kind, version) = if item == "web" {
+    (TargetPlatformKind::Web, "")
+  } else if item == "webworker" {
+    (TargetPlatformKind::WebWorker, "")
+  } else if let Some(version) = item.strip_prefix("async-node") {
+    (TargetPlatformKind::AsyncNode, version)
+  } else if let Some(version) = item
+    .strip_prefix("nwjs")
+    .or_else(|| item.strip_prefix("node-webkit"))
+  {
+    (TargetPlatformKind::NwJs, version)
+  } else if let Some(version) = item.strip_prefix("node") {
+    (TargetPlatformKind::Node, version)
+  } else if let Some(rest) = item.strip_prefix("electron") {
+    let (version, context) = rest.split_once('-').ok_or_else(|| {
+      error!("Unknown target \"{item}\", expected electron[version]-main|preload|renderer")
+    })?;
+    let kind = match context {
+      "main" => TargetPlatformKind::ElectronMain,
+      "preload" => TargetPlatformKind::ElectronPreload,
+      "renderer" => TargetPlatformKind::ElectronRenderer,
+      _ => {
+        return Err(error!(
+          "Unknown target \"{item}\", expected electron[version]-main|preload|renderer"
+        ))
+      }
+    };
+    (kind, version)
+  } else {
+    return Ok(None);
+  };
+
+  let version = if version.is_empty() {
+    None
+  } else {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    match (parts.next(), parts.next(), parts.next()) {
+      (Some(Some(major)), minor, None) => Some(TargetVersion {
+        major,
+        minor: match minor {
+          Some(Some(minor)) => minor,
+          None => 0,
+          Some(None) => return Err(error!("Unknown target \"{item}\", invalid version")),
+        },
+      }),
+      _ => return Err(error!("Unknown target \"{item}\", invalid version")),
     }
+  };
+  Ok(Some(TargetPlatform { kind, version }))
+}
+
+/// Resolve the browsers of `browserslist`, `browserslist:<query>`, `browserslist:<env>` or
+/// `browserslist:<path to config>[:<env>]` like webpack.
+fn resolve_browsers(item: &str, context: &Context) -> Result<Vec<Distrib>> {
+  let opts = browserslist::Opts {
+    path: Some(context.as_str().to_string()),
+    ..Default::default()
+  };
+  let browsers = match item.strip_prefix("browserslist:") {
+    Some(input) if Path::new(input).is_absolute() => {
+      // the `:` of a windows drive is part of the path
+      let (config, env) = match input.rsplit_once(':') {
+        Some((config, env)) if !config.is_empty() && !env.contains(['/', '\\']) => {
+          (config, Some(env))
+        }
+        _ => (input, None),
+      };
+      browserslist::execute(&browserslist::Opts {
+        config: Some(config.to_string()),
+        env: env.map(|env| env.to_string()),
+        throw_on_missing: true,
+        ..opts
+      })
+    }
+    // an environment of the config when it isn't a query
+    Some(input) => browserslist::resolve([input], &opts).or_else(|e| {
+      browserslist::execute(&browserslist::Opts {
+        env: Some(input.to_string()),
+        throw_on_missing: true,
+        ..opts.clone()
+      })
+      .map_err(|_| e)
+    }),
+    None if item == "browserslist" => browserslist::execute(&opts),
+    None => return Err(error!("Unknown target es version {}", item)),
+  }
+  .map_err(|e| error!("Failed to resolve the browserslist target \"{item}\": {e}"))?;
+  if browsers.is_empty() {
+    return Err(error!(
+      "No browsers are matched by the browserslist target \"{item}\""
+    ));
+  }
+  Ok(browsers)
+}
+
+/// The first versions of the browsers supporting a feature, borrowed from
+/// `browserslistTargetHandler` of webpack.
+type BrowserVersions = &'static [(&'static str, u32, u32)];
+
+const CONST: BrowserVersions = &[
+  ("chrome", 49, 0),
+  ("and_chr", 49, 0),
+  ("edge", 12, 0),
+  ("firefox", 36, 0),
+  ("and_ff", 36, 0),
+  ("opera", 36, 0),
+  ("op_mob", 36, 0),
+  ("safari", 10, 0),
+  ("ios_saf", 10, 0),
+  ("samsung", 5, 0),
+  ("android", 37, 0),
+  ("and_qq", 10, 4),
+  ("baidu", 13, 18),
+  ("and_uc", 12, 12),
+  ("kaios", 2, 5),
+  ("node", 6, 0),
+];
+const ARROW_FUNCTION: BrowserVersions = &[
+  ("chrome", 45, 0),
+  ("and_chr", 45, 0),
+  ("edge", 12, 0),
+  ("firefox", 39, 0),
+  ("and_ff", 39, 0),
+  ("opera", 32, 0),
+  ("op_mob", 32, 0),
+  ("safari", 10, 0),
+  ("ios_saf", 10, 0),
+  ("samsung", 5, 0),
+  ("android", 45, 0),
+  ("and_qq", 10, 4),
+  ("baidu", 7, 12),
+  ("and_uc", 12, 12),
+  ("kaios", 2, 5),
+  ("node", 6, 0),
+];
+const FOR_OF: BrowserVersions = &[
+  ("chrome", 38, 0),
+  ("and_chr", 38, 0),
+  ("edge", 12, 0),
+  ("firefox", 51, 0),
+  ("and_ff", 51, 0),
+  ("opera", 25, 0),
+  ("op_mob", 25, 0),
+  ("safari", 7, 0),
+  ("ios_saf", 7, 0),
+  ("samsung", 3, 0),
+  ("android", 38, 0),
+  ("node", 0, 12),
+];
+const DESTRUCTURING: BrowserVersions = &[
+  ("chrome", 49, 0),
+  ("and_chr", 49, 0),
+  ("edge", 14, 0),
+  ("firefox", 41, 0),
+  ("and_ff", 41, 0),
+  ("opera", 36, 0),
+  ("op_mob", 36, 0),
+  ("safari", 8, 0),
+  ("ios_saf", 8, 0),
+  ("samsung", 5, 0),
+  ("android", 49, 0),
+  ("node", 6, 0),
+];
+const BIG_INT_LITERAL: BrowserVersions = &[
+  ("chrome", 67, 0),
+  ("and_chr", 67, 0),
+  ("edge", 79, 0),
+  ("firefox", 68, 0),
+  ("and_ff", 68, 0),
+  ("opera", 54, 0),
+  ("op_mob", 48, 0),
+  ("safari", 14, 0),
+  ("ios_saf", 14, 0),
+  ("samsung", 9, 2),
+  ("android", 67, 0),
+  ("node", 10, 4),
+];
+const MODULE: BrowserVersions = &[
+  ("chrome", 61, 0),
+  ("and_chr", 61, 0),
+  ("edge", 16, 0),
+  ("firefox", 60, 0),
+  ("and_ff", 60, 0),
+  ("opera", 48, 0),
+  ("op_mob", 45, 0),
+  ("safari", 10, 1),
+  ("ios_saf", 10, 3),
+  ("samsung", 8, 0),
+  ("android", 61, 0),
+  ("and_qq", 10, 4),
+  ("baidu", 13, 18),
+  ("and_uc", 15, 5),
+  ("kaios", 3, 0),
+  ("node", 12, 17),
+];
+const DYNAMIC_IMPORT: BrowserVersions = &[
+  ("chrome", 63, 0),
+  ("and_chr", 63, 0),
+  ("edge", 79, 0),
+  ("firefox", 67, 0),
+  ("and_ff", 67, 0),
+  ("opera", 50, 0),
+  ("op_mob", 46, 0),
+  ("safari", 11, 1),
+  ("ios_saf", 11, 3),
+  ("samsung", 8, 2),
+  ("android", 63, 0),
+  ("and_qq", 10, 4),
+  ("baidu", 13, 18),
+  ("and_uc", 15, 5),
+  ("kaios", 3, 0),
+  ("node", 12, 17),
+];
+const DYNAMIC_IMPORT_IN_WORKER: BrowserVersions = &[
+  ("chrome", 80, 0),
+  ("and_chr", 80, 0),
+  ("edge", 80, 0),
+  ("firefox", 114, 0),
+  ("and_ff", 114, 0),
+  ("opera", 67, 0),
+  ("op_mob", 57, 0),
+  ("safari", 15, 0),
+  ("ios_saf", 15, 0),
+  ("samsung", 13, 0),
+  ("android", 80, 0),
+  ("node", 12, 17),
+];
+const GLOBAL_THIS: BrowserVersions = &[
+  ("chrome", 71, 0),
+  ("and_chr", 71, 0),
+  ("edge", 79, 0),
+  ("firefox", 65, 0),
+  ("and_ff", 65, 0),
+  ("opera", 58, 0),
+  ("op_mob", 50, 0),
+  ("safari", 12, 1),
+  ("ios_saf", 12, 2),
+  ("samsung", 10, 1),
+  ("android", 71, 0),
+  ("node", 12, 0),
+];
+const OPTIONAL_CHAINING: BrowserVersions = &[
+  ("chrome", 80, 0),
+  ("and_chr", 80, 0),
+  ("edge", 80, 0),
+  ("firefox", 74, 0),
+  ("and_ff", 79, 0),
+  ("opera", 67, 0),
+  ("op_mob", 64, 0),
+  ("safari", 13, 1),
+  ("ios_saf", 13, 4),
+  ("samsung", 13, 0),
+  ("android", 80, 0),
+  ("node", 14, 0),
+];
+const TEMPLATE_LITERAL: BrowserVersions = &[
+  ("chrome", 41, 0),
+  ("and_chr", 41, 0),
+  ("edge", 13, 0),
+  ("firefox", 34, 0),
+  ("and_ff", 34, 0),
+  ("opera", 29, 0),
+  ("op_mob", 64, 0),
+  ("safari", 9, 1),
+  ("ios_saf", 9, 0),
+  ("samsung", 4, 0),
+  ("android", 41, 0),
+  ("and_qq", 10, 4),
+  ("baidu", 7, 12),
+  ("and_uc", 12, 12),
+  ("kaios", 2, 5),
+  ("node", 4, 0),
+];
+const ASYNC_FUNCTION: BrowserVersions = &[
+  ("chrome", 55, 0),
+  ("and_chr", 55, 0),
+  ("edge", 15, 0),
+  ("firefox", 52, 0),
+  ("and_ff", 52, 0),
+  ("opera", 42, 0),
+  ("op_mob", 42, 0),
+  ("safari", 11, 0),
+  ("ios_saf", 11, 0),
+  ("samsung", 6, 2),
+  ("android", 55, 0),
+  ("node", 7, 6),
+];
+
+/// Whether all the browsers support a feature, the browsers without data don't.
+fn supported_by_all(browsers: &[Distrib], versions: BrowserVersions) -> bool {
+  browsers.iter().all(|browser| {
+    let Some((_, required_major, required_minor)) =
+      versions.iter().find(|(name, _, _)| *name == browser.name())
+    else {
+      return false;
+    };
+    // `TP` is the technology preview of safari, a range like `15.2-15.3` starts from its first version
+    let (major, minor) = match browser.version() {
+      "TP" => (u32::MAX, u32::MAX),
+      version => {
+        let version = version.split('-').next().unwrap_or(version);
+        let mut parts = version.split('.').map(|part| part.parse::<u32>());
+        match (parts.next(), parts.next()) {
+          (Some(Ok(major)), Some(Ok(minor))) => (major, minor),
+          (Some(Ok(major)), None) => (major, 0),
+          _ => return false,
+        }
+      }
+    };
+    (major, minor) >= (*required_major, *required_minor)
+  })
+}
+
+/// A browserslist matching only Node.js targets the `node` platform like webpack, `None` when it
+/// matches any browser.
+fn infer_browsers_platform(browsers: &[Distrib]) -> Option<TargetPlatform> {
+  if !browsers.iter().all(|browser| browser.name() == "node") {
+    return None;
+  }
+  let version = browsers
+    .iter()
+    .filter_map(|browser| {
+      let mut parts = browser.version().split('.').map(|part| part.parse::<u32>());
+      match (parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor))) => Some(TargetVersion { major, minor }),
+        (Some(Ok(major)), None) => Some(TargetVersion { major, minor: 0 }),
+        _ => None,
+      }
+    })
+    .min();
+  Some(TargetPlatform {
+    kind: TargetPlatformKind::Node,
+    version,
+  })
+}
+
+fn browsers_environment(browsers: &[Distrib]) -> Environment {
+  let supports = |versions| Some(supported_by_all(browsers, versions));
+  Environment {
+    r#const: supports(CONST),
+    arrow_function: supports(ARROW_FUNCTION),
+    destructuring: supports(DESTRUCTURING),
+    for_of: supports(FOR_OF),
+    optional_chaining: supports(OPTIONAL_CHAINING),
+    template_literal: supports(TEMPLATE_LITERAL),
+    big_int_literal: supports(BIG_INT_LITERAL),
+    dynamic_import: supports(DYNAMIC_IMPORT),
+    dynamic_import_in_worker: supports(DYNAMIC_IMPORT_IN_WORKER),
+    module: supports(MODULE),
+    global_this: supports(GLOBAL_THIS),
+    async_function: supports(ASYNC_FUNCTION),
+  }
+}
+
+fn environment_es_version(environment: &Environment) -> EsVersion {
+  let es2015 = environment.supports_const()
+    && environment.supports_arrow_function()
+    && environment.supports_destructuring()
+    && environment.supports_for_of()
+    && environment.supports_template_literal();
+  let es2017 = es2015 && environment.supports_async_function();
+  let es2020 = es2017
+    && environment.supports_optional_chaining()
+    && environment.supports_big_int_literal()
+    && environment.supports_global_this()
+    && environment.supports_dynamic_import();
+  if es2020 {
+    EsVersion::Es2020
+  } else if es2017 {
+    EsVersion::Es2017
+  } else if es2015 {
+    EsVersion::Es2015
+  } else {
+    EsVersion::Es5
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn target(query: &str) -> Target {
+    Target::new(&vec![query.to_string()], &Context::new("/".into())).expect("should resolve")
+  }
+
+  #[test]
+  fn test_browserslist_target() {
+    let legacy = target("browserslist:ie 11");
+    assert!(matches!(
+      legacy.es_version,
+      TargetEsVersion::BrowsersList(EsVersion::Es5)
+    ));
+    assert!(!legacy.environment.supports_arrow_function());
+    assert!(!legacy.environment.supports_const());
+
+    let modern = target("browserslist:chrome 100, firefox 100, safari 15.4");
+    assert!(matches!(
+      modern.es_version,
+      TargetEsVersion::BrowsersList(EsVersion::Es2020)
+    ));
+    assert!(modern.environment.supports_optional_chaining());
+    assert!(modern.environment.supports_dynamic_import());
+
+    let mixed = target("browserslist:chrome 60, safari 11");
+    assert!(matches!(
+      mixed.es_version,
+      TargetEsVersion::BrowsersList(EsVersion::Es2017)
+    ));
+    assert!(!mixed.environment.supports_module());
+  }
+
+  #[test]
+  fn test_platform_target() {
+    let context = Context::new("/".into());
+    let target = Target::new(&vec!["web".to_string(), "es2020".to_string()], &context)
+      .expect("should resolve");
+    assert!(matches!(
+      target.es_version,
+      TargetEsVersion::Esx(EsVersion::Es2020)
+    ));
+    let platform = target.platform.expect("should have platform");
+    assert_eq!(platform.kind, TargetPlatformKind::Web);
+    assert_eq!(
+      platform.default_chunk_loading(false),
+      ChunkLoadingType::Jsonp
+    );
+    assert_eq!(platform.default_wasm_loading(false), WasmLoadingType::Fetch);
+    assert_eq!(platform.default_global_object(), "self");
+
+    let platform = Target::new(&vec!["node14.17".to_string()], &context)
+      .expect("should resolve")
+      .platform
+      .expect("should have platform");
+    assert_eq!(platform.kind, TargetPlatformKind::Node);
+    assert_eq!(
+      platform.version,
+      Some(TargetVersion {
+        major: 14,
+        minor: 17
+      })
+    );
+    assert_eq!(
+      platform.default_chunk_loading(false),
+      ChunkLoadingType::Require
+    );
+    assert_eq!(
+      platform.default_chunk_loading(true),
+      ChunkLoadingType::Import
+    );
+    assert_eq!(
+      platform.default_wasm_loading(true),
+      WasmLoadingType::AsyncNodeModule
+    );
+    assert_eq!(platform.default_global_object(), "global");
+    assert_eq!(
+      platform.default_node_option(false).dirname,
+      NodeDirnameOption::EvalOnly
+    );
+
+    let platform = Target::new(&vec!["electron13-renderer".to_string()], &context)
+      .expect("should resolve")
+      .platform
+      .expect("should have platform");
+    assert_eq!(platform.kind, TargetPlatformKind::ElectronRenderer);
+    assert_eq!(
+      platform.default_chunk_loading(false),
+      ChunkLoadingType::Jsonp
+    );
+    assert_eq!(platform.default_global_object(), "global");
+  }
+
+  #[test]
+  fn test_platform_or_web() {
+    let platform = target("es2020").platform_or_web();
+    assert_eq!(platform.kind, TargetPlatformKind::Web);
+    assert_eq!(
+      platform.default_node_option(false).dirname,
+      NodeDirnameOption::WarnMock
+    );
+
+    let platform = target("async-node").platform_or_web();
+    assert_eq!(platform.kind, TargetPlatformKind::AsyncNode);
+    assert_eq!(
+      platform.default_chunk_loading(false),
+      ChunkLoadingType::AsyncNode
+    );
+    assert_eq!(
+      platform.default_worker_chunk_loading(false),
+      ChunkLoadingType::AsyncNode
+    );
+  }
+
+  #[test]
+  fn test_browserslist_platform() {
+    let platform = target("browserslist:node 16.14, node 18").platform_or_web();
+    assert_eq!(platform.kind, TargetPlatformKind::Node);
+    assert_eq!(
+      platform.version,
+      Some(TargetVersion {
+        major: 16,
+        minor: 14
+      })
+    );
+    assert_eq!(
+      platform.default_chunk_loading(false),
+      ChunkLoadingType::Require
+    );
+
+    // any browser makes it a web target
+    let platform = target("browserslist:node 18, chrome 100").platform_or_web();
+    assert_eq!(platform.kind, TargetPlatformKind::Web);
+    assert_eq!(
+      platform.default_worker_chunk_loading(false),
+      ChunkLoadingType::ImportScripts
+    );
+
+    // the platform of the targets takes precedence
+    let platform = Target::new(
+      &vec!["browserslist:node 18".to_string(), "async-node".to_string()],
+      &Context::new("/".into()),
+    )
+    .expect("should resolve")
+    .platform_or_web();
+    assert_eq!(platform.kind, TargetPlatformKind::AsyncNode);
+  }
+
+  #[test]
+  fn test_platform_target_error() {
+    let context = Context::new("/".into());
+    let err =
+      Target::new(&vec!["web".to_string(), "node".to_string()], &context).expect_err("should fail");
+    assert!(err
+      .to_string()
+      .contains("Target platform conflict, \"web\" and \"node\" can't be used together"));
+    let err = Target::new(&vec!["electron-gpu".to_string()], &context).expect_err("should fail");
+    assert!(err.to_string().contains("Unknown target \"electron-gpu\""));
+    let err = Target::new(&vec!["node1x".to_string()], &context).expect_err("should fail");
+    assert!(err.to_string().contains("invalid version"));
+    let err = Target::new(&vec!["deno".to_string()], &context).expect_err("should fail");
+    assert!(err.to_string().contains("Unknown target \"deno\""));
+  }
 
-    Ok(Target { es_version })
+  #[test]
+  fn test_browserslist_target_error() {
+    let err = Target::new(
+      &vec!["browserslist:unknown 1".to_string()],
+      &Context::new("/".into()),
+    )
+    .expect_err("should fail");
+    assert!(err.to_string().contains("unknown browser"));
   }
 }
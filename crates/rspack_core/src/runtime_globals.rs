@@ -248,6 +248,21 @@ bitflags! {
     const RSPACK_UNIQUE_ID = 1 << 64;
 
     const HAS_FETCH_PRIORITY = 1 << 65;
+
+    /**
+     * the AMD define function
+     */
+    const AMD_DEFINE = 1 << 66;
+
+    /**
+     * the AMD options
+     */
+    const AMD_OPTIONS = 1 << 67;
+
+    /**
+     * the uncaught error handler for the webpack runtime
+     */
+    const UNCAUGHT_ERROR_HANDLER = 1 << 68;
   }
 }
 
@@ -334,6 +349,9 @@ impl RuntimeGlobals {
       R::HAS_CSS_MODULES => "has css modules",
 
       R::HAS_FETCH_PRIORITY => "has fetch priority",
+      R::AMD_DEFINE => "__webpack_require__.amdD",
+      R::AMD_OPTIONS => "__webpack_require__.amdO",
+      R::UNCAUGHT_ERROR_HANDLER => "__webpack_require__.oe",
       _ => unreachable!(),
     }
   }
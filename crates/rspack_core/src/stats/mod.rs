@@ -47,7 +47,11 @@ impl<'compilation> Stats<'compilation> {
 }
 
 impl Stats<'_> {
-  pub fn get_assets(&self) -> (Vec<StatsAsset>, Vec<StatsAssetsByChunkName>) {
+  /// Returns the assets, the assets of each named chunk and the number of excluded assets.
+  pub fn get_assets(
+    &self,
+    options: &ExtendedStatsOptions,
+  ) -> (Vec<StatsAsset>, Vec<StatsAssetsByChunkName>, usize) {
     let mut compilation_file_to_chunks: HashMap<&String, Vec<&Chunk>> = HashMap::default();
     let mut compilation_file_to_auxiliary_chunks: HashMap<&String, Vec<&Chunk>> =
       HashMap::default();
@@ -114,6 +118,9 @@ impl Stats<'_> {
         assets.remove(source_map);
       }
     }
+    let assets_len = assets.len();
+    assets.retain(|name, _| !options.is_asset_excluded(name));
+    let filtered_assets = assets_len - assets.len();
     assets.par_iter_mut().for_each(|(name, asset)| {
       if let Some(chunks) = compilation_file_to_chunks.get(name) {
         asset.chunks = chunks.par_iter().map(|chunk| chunk.id.clone()).collect();
@@ -179,42 +186,51 @@ impl Stats<'_> {
       })
       .collect();
 
-    (assets, assets_by_chunk_name)
+    (assets, assets_by_chunk_name, filtered_assets)
   }
 
+  /// Returns the result of `f` for the modules and the number of excluded modules.
   #[allow(clippy::too_many_arguments)]
   pub fn get_modules<T>(
     &self,
     options: &ExtendedStatsOptions,
     f: impl Fn(Vec<StatsModule>) -> T,
-  ) -> Result<T> {
+  ) -> Result<(T, usize)> {
     let module_graph = self.compilation.get_module_graph();
+    let context = &self.compilation.options.context;
     let executor_module_graph = &self
       .compilation
       .module_executor
       .as_ref()
       .map(|executor| executor.make_artifact.get_module_graph());
+    let mut total = 0;
 
-    let mut modules: Vec<StatsModule> = module_graph
-      .modules()
+    let graph_modules = module_graph.modules();
+    total += graph_modules.len();
+    let mut modules: Vec<StatsModule> = graph_modules
       .values()
       .par_bridge()
+      .filter(|module| !options.is_module_excluded(&module.readable_identifier(context)))
       .map(|module| self.get_module(&module_graph, module, false, None, options))
       .collect::<Result<_>>()?;
 
+    total += self.compilation.runtime_modules.len();
     let runtime_modules = self
       .compilation
       .runtime_modules
       .par_iter()
+      .filter(|(_, module)| !options.is_module_excluded(&module.readable_identifier(context)))
       .map(|(identifier, module)| self.get_runtime_module(identifier, module, options))
       .collect::<Result<Vec<_>>>()?;
     modules.extend(runtime_modules);
 
     if let Some(executor_module_graph) = &executor_module_graph {
-      let executed_modules: Vec<StatsModule> = executor_module_graph
-        .modules()
+      let executor_modules = executor_module_graph.modules();
+      total += executor_modules.len();
+      let executed_modules: Vec<StatsModule> = executor_modules
         .values()
         .par_bridge()
+        .filter(|module| !options.is_module_excluded(&module.readable_identifier(context)))
         .map(|module| self.get_module(executor_module_graph, module, true, None, options))
         .collect::<Result<_>>()?;
 
@@ -227,9 +243,11 @@ impl Stats<'_> {
       .as_ref()
       .map(|me| &me.executed_runtime_modules)
     {
+      total += executed_runtime_modules.len();
       let runtime_modules: Vec<StatsModule> = executed_runtime_modules
         .iter()
         .par_bridge()
+        .filter(|item| !options.is_module_excluded(&item.value().name))
         .map(|item| {
           let (id, module) = item.pair();
           self.get_executed_runtime_module(id, module, options)
@@ -240,7 +258,8 @@ impl Stats<'_> {
 
     sort_modules(&mut modules);
 
-    Ok(f(modules))
+    let filtered_modules = total - modules.len();
+    Ok((f(modules), filtered_modules))
   }
 
   #[allow(clippy::too_many_arguments)]
@@ -6,7 +6,9 @@ use rspack_sources::Source;
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
 
-use crate::{ChunkGroupOrderKey, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType};
+use crate::{
+  ChunkGroupOrderKey, ModuleIdentifier, ModuleType, RuntimeSpec, SourceType, StatsFilter,
+};
 
 pub enum EntrypointsStatsOption {
   Bool(bool),
@@ -36,6 +38,18 @@ pub struct ExtendedStatsOptions {
   pub source: bool,
   pub used_exports: bool,
   pub warnings: bool,
+  pub exclude_modules: Vec<StatsFilter>,
+  pub exclude_assets: Vec<StatsFilter>,
+}
+
+impl ExtendedStatsOptions {
+  pub fn is_module_excluded(&self, name: &str) -> bool {
+    self.exclude_modules.iter().any(|filter| filter.test(name))
+  }
+
+  pub fn is_asset_excluded(&self, name: &str) -> bool {
+    self.exclude_assets.iter().any(|filter| filter.test(name))
+  }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,115 @@
+//! Helpers for the tests building with a [Compiler](crate::Compiler).
+
+use rspack_hash::{HashDigest, HashFunction, HashSalt};
+use rspack_paths::Utf8Path;
+
+use crate::{
+  ChunkLoading, ChunkLoadingType, CompilerOptions, Context, CrossOriginLoading, DynamicImportMode,
+  Environment, JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, Mode,
+  ModuleOptions, ModuleType, Optimization, OutputOptions, ParserOptions, ParserOptionsByModuleType,
+  PathInfo, PublicPath, Resolve, Target, WasmLoading,
+};
+
+/// Options of a development build for the `web` target, which resolves and parses the `.js`
+/// files of `context` and emits to `context/dist`.
+pub fn compiler_options(context: &Utf8Path) -> CompilerOptions {
+  let context = Context::from(context);
+  CompilerOptions {
+    target: Target::new(&vec!["web".to_string()], &context).expect("should resolve target"),
+    output: OutputOptions {
+      path: context.as_path().join("dist"),
+      pathinfo: PathInfo::Bool(false),
+      clean: false,
+      public_path: PublicPath::Auto,
+      asset_module_filename: "[hash][ext][query]".to_string().into(),
+      wasm_loading: WasmLoading::Disable,
+      webassembly_module_filename: "[hash].module.wasm".to_string().into(),
+      unique_name: "test".to_string(),
+      chunk_loading: ChunkLoading::Enable(ChunkLoadingType::Jsonp),
+      custom_chunk_loading_types: vec![],
+      chunk_loading_global: "webpackChunktest".to_string(),
+      chunk_load_timeout: 120_000,
+      charset: false,
+      filename: "[name].js".to_string().into(),
+      chunk_filename: "[id].js".to_string().into(),
+      cross_origin_loading: CrossOriginLoading::Disable,
+      css_filename: "[name].css".to_string().into(),
+      css_chunk_filename: "[id].css".to_string().into(),
+      css_head_data_compression: false,
+      hot_update_main_filename: "[runtime].[fullhash].hot-update.json".to_string().into(),
+      hot_update_chunk_filename: "[id].[fullhash].hot-update.js".to_string().into(),
+      hot_update_global: "webpackHotUpdatetest".to_string(),
+      library: None,
+      enabled_library_types: None,
+      strict_module_error_handling: false,
+      global_object: "self".to_string(),
+      import_function_name: "import".to_string(),
+      import_meta_name: "import.meta".to_string(),
+      iife: true,
+      module: false,
+      trusted_types: None,
+      source_map_filename: "[file].map[query]".to_string().into(),
+      hash_function: HashFunction::Xxhash64,
+      hash_digest: HashDigest::Hex,
+      hash_digest_length: 16,
+      hash_salt: HashSalt::None,
+      async_chunks: true,
+      worker_chunk_loading: ChunkLoading::Enable(ChunkLoadingType::ImportScripts),
+      worker_wasm_loading: WasmLoading::Disable,
+      worker_public_path: String::new(),
+      script_type: "false".to_string(),
+      environment: Environment::default(),
+    },
+    context,
+    dev_server: Default::default(),
+    mode: Mode::Development,
+    resolve: Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    },
+    resolve_loader: Resolve {
+      extensions: Some(vec![".js".to_string()]),
+      ..Default::default()
+    },
+    module: ModuleOptions {
+      parser: Some(ParserOptionsByModuleType::from_iter([(
+        ModuleType::JsAuto,
+        ParserOptions::Javascript(JavascriptParserOptions {
+          dynamic_import_mode: DynamicImportMode::Lazy,
+          dynamic_import_preload: JavascriptParserOrder::Disable,
+          dynamic_import_prefetch: JavascriptParserOrder::Disable,
+          dynamic_import_fetch_priority: None,
+          url: JavascriptParserUrl::Enable,
+          expr_context_critical: true,
+          wrapped_context_critical: false,
+          exports_presence: None,
+          import_exports_presence: None,
+          reexport_exports_presence: None,
+          strict_export_presence: false,
+          worker: vec![],
+          override_strict: None,
+          import_meta: true,
+        }),
+      )])),
+      ..Default::default()
+    },
+    stats: Default::default(),
+    snapshot: Default::default(),
+    cache: Default::default(),
+    experiments: Default::default(),
+    node: None,
+    amd: None,
+    optimization: Optimization {
+      remove_available_modules: false,
+      side_effects: Default::default(),
+      provided_exports: false,
+      used_exports: Default::default(),
+      inner_graph: false,
+      mangle_exports: Default::default(),
+      concatenate_modules: false,
+    },
+    profile: false,
+    bail: false,
+    __references: Default::default(),
+  }
+}
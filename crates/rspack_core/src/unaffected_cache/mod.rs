@@ -9,7 +9,8 @@ use rspack_util::fx_hash::FxIndexSet;
 use rustc_hash::FxHasher;
 
 use crate::{
-  AffectType, ChunkGraph, Compilation, Module, ModuleGraph, ModuleGraphConnection, ModuleIdentifier,
+  AffectType, ChunkGraph, Compilation, DependenciesBlock, Module, ModuleGraph,
+  ModuleGraphConnection, ModuleIdentifier,
 };
 
 #[derive(Debug, Default)]
@@ -158,9 +159,13 @@ impl UnaffectedModuleWithChunkGraphCache {
     for module_id in module_ids {
       module_id.hash(&mut hasher);
     }
-    for block_id in module.get_blocks() {
+    let mut block_ids = module.get_blocks().to_vec();
+    while let Some(block_id) = block_ids.pop() {
+      if let Some(block) = module_graph.block_by_id(&block_id) {
+        block_ids.extend_from_slice(block.get_blocks());
+      }
       let Some(chunk_group) =
-        chunk_graph.get_block_chunk_group(block_id, &compilation.chunk_group_by_ukey)
+        chunk_graph.get_block_chunk_group(&block_id, &compilation.chunk_group_by_ukey)
       else {
         continue;
       };
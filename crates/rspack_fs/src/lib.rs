@@ -10,6 +10,12 @@ pub use sync::{FileSystem, ReadableFileSystem, WritableFileSystem};
 mod error;
 pub use error::{Error, Result};
 
+mod memory;
+pub use memory::MemoryFileSystem;
+
+mod overlay;
+pub use overlay::OverlayFileSystem;
+
 cfg_native! {
   mod native;
   pub use native::{NativeFileSystem};
@@ -17,3 +23,10 @@ cfg_native! {
   #[cfg(feature = "async")]
   pub use native::AsyncNativeFileSystem;
 }
+
+cfg_watch! {
+  mod watcher;
+  pub use watcher::{
+    FileSystemChanges, NativeWatchFileSystem, WatchCallback, WatchFileSystem, WatchOptions, Watcher,
+  };
+}
@@ -11,3 +11,10 @@ macro_rules! cfg_native {
       $( #[cfg(feature = "native")] $item )*
   }
 }
+
+#[macro_export]
+macro_rules! cfg_watch {
+  ($($item:item)*) => {
+      $( #[cfg(feature = "watch")] $item )*
+  }
+}
@@ -0,0 +1,212 @@
+use std::{
+  collections::HashMap,
+  io::{Error as IoError, ErrorKind},
+  sync::RwLock,
+};
+
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use super::{
+  cfg_async,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, Result,
+};
+
+#[derive(Debug)]
+enum Entry {
+  File(Vec<u8>),
+  Directory,
+}
+
+/// A file system kept in memory, it behaves like [`crate::NativeFileSystem`] but
+/// never touches the disk. Only the root directory exists implicitly.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+  entries: RwLock<HashMap<Utf8PathBuf, Entry>>,
+}
+
+fn error(kind: ErrorKind, message: &str, path: &Utf8Path) -> Error {
+  Error::Io(IoError::new(kind, format!("{message}: {path}")))
+}
+
+impl MemoryFileSystem {
+  fn is_dir(entries: &HashMap<Utf8PathBuf, Entry>, path: &Utf8Path) -> bool {
+    // only the root, e.g. `/` or the empty path of the relative paths, exists implicitly
+    path.parent().is_none() || matches!(entries.get(path), Some(Entry::Directory))
+  }
+
+  fn check_parent(entries: &HashMap<Utf8PathBuf, Entry>, path: &Utf8Path) -> Result<()> {
+    match path.parent() {
+      Some(parent) if !Self::is_dir(entries, parent) => {
+        Err(error(ErrorKind::NotFound, "No such directory", parent))
+      }
+      _ => Ok(()),
+    }
+  }
+
+  /// Removes a file, or a directory after removing all its contents.
+  pub fn remove(&self, path: &Utf8Path) -> Result<()> {
+    let mut entries = self.entries.write().expect("should get lock");
+    match entries.remove(path) {
+      Some(Entry::File(_)) => Ok(()),
+      Some(Entry::Directory) => {
+        entries.retain(|entry, _| !entry.starts_with(path));
+        Ok(())
+      }
+      None => Err(error(
+        ErrorKind::NotFound,
+        "No such file or directory",
+        path,
+      )),
+    }
+  }
+}
+
+impl WritableFileSystem for MemoryFileSystem {
+  fn create_dir(&self, dir: &Utf8Path) -> Result<()> {
+    let mut entries = self.entries.write().expect("should get lock");
+    if entries.contains_key(dir) || Self::is_dir(&entries, dir) {
+      return Err(error(ErrorKind::AlreadyExists, "Already exists", dir));
+    }
+    Self::check_parent(&entries, dir)?;
+    entries.insert(dir.to_path_buf(), Entry::Directory);
+    Ok(())
+  }
+
+  fn create_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    let mut entries = self.entries.write().expect("should get lock");
+    for ancestor in dir.ancestors() {
+      if Self::is_dir(&entries, ancestor) {
+        break;
+      }
+      if entries.contains_key(ancestor) {
+        return Err(error(ErrorKind::AlreadyExists, "Not a directory", ancestor));
+      }
+    }
+    for ancestor in dir.ancestors() {
+      if Self::is_dir(&entries, ancestor) {
+        break;
+      }
+      entries.insert(ancestor.to_path_buf(), Entry::Directory);
+    }
+    Ok(())
+  }
+
+  fn write(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    let mut entries = self.entries.write().expect("should get lock");
+    if Self::is_dir(&entries, file) {
+      return Err(error(ErrorKind::Other, "Is a directory", file));
+    }
+    Self::check_parent(&entries, file)?;
+    entries.insert(file.to_path_buf(), Entry::File(data.to_vec()));
+    Ok(())
+  }
+}
+
+impl ReadableFileSystem for MemoryFileSystem {
+  fn read(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    let entries = self.entries.read().expect("should get lock");
+    match entries.get(file) {
+      Some(Entry::File(data)) => Ok(data.clone()),
+      Some(Entry::Directory) => Err(error(ErrorKind::Other, "Is a directory", file)),
+      None => Err(error(ErrorKind::NotFound, "No such file", file)),
+    }
+  }
+}
+
+cfg_async! {
+  use futures::future::{self, BoxFuture};
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl MemoryFileSystem {
+    fn remove_entry(&self, path: &Utf8Path, is_dir: bool) -> Result<()> {
+      let matched = match self.entries.read().expect("should get lock").get(path) {
+        Some(Entry::File(_)) => !is_dir,
+        Some(Entry::Directory) => is_dir,
+        None => {
+          return Err(error(
+            ErrorKind::NotFound,
+            "No such file or directory",
+            path,
+          ))
+        }
+      };
+      if !matched {
+        let message = if is_dir {
+          "Not a directory"
+        } else {
+          "Is a directory"
+        };
+        return Err(error(ErrorKind::Other, message, path));
+      }
+      self.remove(path)
+    }
+  }
+
+  impl AsyncWritableFileSystem for MemoryFileSystem {
+    fn create_dir<'a>(&'a self, dir: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(WritableFileSystem::create_dir(self, dir)))
+    }
+
+    fn create_dir_all<'a>(&'a self, dir: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(WritableFileSystem::create_dir_all(self, dir)))
+    }
+
+    fn write<'a>(&'a self, file: &'a Utf8Path, data: &'a [u8]) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(WritableFileSystem::write(self, file, data)))
+    }
+
+    fn remove_file<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(self.remove_entry(file, false)))
+    }
+
+    fn remove_dir_all<'a>(&'a self, dir: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(self.remove_entry(dir, true)))
+    }
+  }
+
+  impl AsyncReadableFileSystem for MemoryFileSystem {
+    fn read<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<Vec<u8>>> {
+      Box::pin(future::ready(ReadableFileSystem::read(self, file)))
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_memory_file_system() {
+    let fs = MemoryFileSystem::default();
+    let dir = Utf8Path::new("/dist/static");
+    let file = dir.join("main.js");
+
+    assert!(WritableFileSystem::write(&fs, &file, b"a").is_err());
+    assert!(WritableFileSystem::create_dir(&fs, dir).is_err());
+    WritableFileSystem::create_dir_all(&fs, dir).expect("should create dir");
+    WritableFileSystem::write(&fs, &file, b"a").expect("should write file");
+    assert_eq!(
+      ReadableFileSystem::read(&fs, &file).expect("should read file"),
+      b"a"
+    );
+    assert!(ReadableFileSystem::read(&fs, dir).is_err());
+    assert!(WritableFileSystem::create_dir_all(&fs, &file.join("nested")).is_err());
+
+    fs.remove(Utf8Path::new("/dist"))
+      .expect("should remove dir");
+    assert!(ReadableFileSystem::read(&fs, &file).is_err());
+  }
+
+  #[test]
+  fn test_memory_file_system_relative_paths() {
+    let fs = MemoryFileSystem::default();
+
+    assert!(WritableFileSystem::write(&fs, Utf8Path::new("dist/main.js"), b"a").is_err());
+    WritableFileSystem::write(&fs, Utf8Path::new("main.js"), b"a").expect("should write file");
+    WritableFileSystem::create_dir(&fs, Utf8Path::new("dist")).expect("should create dir");
+    WritableFileSystem::write(&fs, Utf8Path::new("dist/main.js"), b"b").expect("should write file");
+    assert!(WritableFileSystem::create_dir(&fs, Utf8Path::new("main.js")).is_err());
+  }
+}
@@ -0,0 +1,156 @@
+use std::io::ErrorKind;
+
+use rspack_paths::Utf8Path;
+
+use super::{
+  cfg_async,
+  sync::{ReadableFileSystem, WritableFileSystem},
+  Error, MemoryFileSystem, Result,
+};
+
+/// Layers virtual files kept in memory over another file system, e.g. [`crate::NativeFileSystem`].
+///
+/// Reads look up the virtual files first and fall back to the underlying file system,
+/// writes only ever go to memory. As the directories of the underlying file system
+/// can't be checked, missing parent directories are created in memory on write.
+#[derive(Debug, Default)]
+pub struct OverlayFileSystem<T> {
+  memory: MemoryFileSystem,
+  fs: T,
+}
+
+impl<T> OverlayFileSystem<T> {
+  pub fn new(fs: T) -> Self {
+    Self {
+      memory: MemoryFileSystem::default(),
+      fs,
+    }
+  }
+
+  /// The virtual files written on top of the underlying file system.
+  pub fn memory(&self) -> &MemoryFileSystem {
+    &self.memory
+  }
+
+  fn write_virtual(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    if let Some(parent) = file.parent() {
+      WritableFileSystem::create_dir_all(&self.memory, parent)?;
+    }
+    WritableFileSystem::write(&self.memory, file, data)
+  }
+}
+
+fn is_not_found(result: &Result<Vec<u8>>) -> bool {
+  matches!(result, Err(Error::Io(err)) if err.kind() == ErrorKind::NotFound)
+}
+
+impl<T> WritableFileSystem for OverlayFileSystem<T> {
+  fn create_dir(&self, dir: &Utf8Path) -> Result<()> {
+    WritableFileSystem::create_dir_all(&self.memory, dir)
+  }
+
+  fn create_dir_all(&self, dir: &Utf8Path) -> Result<()> {
+    WritableFileSystem::create_dir_all(&self.memory, dir)
+  }
+
+  fn write(&self, file: &Utf8Path, data: &[u8]) -> Result<()> {
+    self.write_virtual(file, data)
+  }
+}
+
+impl<T: ReadableFileSystem> ReadableFileSystem for OverlayFileSystem<T> {
+  fn read(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+    let result = ReadableFileSystem::read(&self.memory, file);
+    if is_not_found(&result) {
+      return self.fs.read(file);
+    }
+    result
+  }
+}
+
+cfg_async! {
+  use futures::future::{self, BoxFuture};
+
+  use crate::{AsyncReadableFileSystem, AsyncWritableFileSystem};
+
+  impl<T: Send + Sync> AsyncWritableFileSystem for OverlayFileSystem<T> {
+    fn create_dir<'a>(&'a self, dir: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(WritableFileSystem::create_dir(self, dir)))
+    }
+
+    fn create_dir_all<'a>(&'a self, dir: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(WritableFileSystem::create_dir_all(self, dir)))
+    }
+
+    fn write<'a>(&'a self, file: &'a Utf8Path, data: &'a [u8]) -> BoxFuture<'a, Result<()>> {
+      Box::pin(future::ready(self.write_virtual(file, data)))
+    }
+
+    fn remove_file<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      AsyncWritableFileSystem::remove_file(&self.memory, file)
+    }
+
+    fn remove_dir_all<'a>(&'a self, dir: &'a Utf8Path) -> BoxFuture<'a, Result<()>> {
+      AsyncWritableFileSystem::remove_dir_all(&self.memory, dir)
+    }
+  }
+
+  impl<T: AsyncReadableFileSystem + Send + Sync> AsyncReadableFileSystem for OverlayFileSystem<T> {
+    fn read<'a>(&'a self, file: &'a Utf8Path) -> BoxFuture<'a, Result<Vec<u8>>> {
+      let fut = async move {
+        let result = ReadableFileSystem::read(&self.memory, file);
+        if is_not_found(&result) {
+          return self.fs.read(file).await;
+        }
+        result
+      };
+      Box::pin(fut)
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  struct DiskFileSystem;
+
+  impl ReadableFileSystem for DiskFileSystem {
+    fn read(&self, file: &Utf8Path) -> Result<Vec<u8>> {
+      match file.as_str() {
+        "/app/src/index.js" => Ok(b"disk".to_vec()),
+        _ => Err(Error::Io(std::io::Error::from(ErrorKind::NotFound))),
+      }
+    }
+  }
+
+  #[test]
+  fn test_overlay_file_system() {
+    let fs = OverlayFileSystem::new(DiskFileSystem);
+    let index = Utf8Path::new("/app/src/index.js");
+    let virtual_module = Utf8Path::new("/app/src/virtual.js");
+
+    assert_eq!(
+      ReadableFileSystem::read(&fs, index).expect("should read file"),
+      b"disk"
+    );
+    assert!(ReadableFileSystem::read(&fs, virtual_module).is_err());
+
+    WritableFileSystem::write(&fs, virtual_module, b"virtual").expect("should write file");
+    WritableFileSystem::write(&fs, index, b"memory").expect("should write file");
+    assert_eq!(
+      ReadableFileSystem::read(&fs, virtual_module).expect("should read file"),
+      b"virtual"
+    );
+    assert_eq!(
+      ReadableFileSystem::read(&fs, index).expect("should read file"),
+      b"memory"
+    );
+
+    fs.memory().remove(index).expect("should remove file");
+    assert_eq!(
+      ReadableFileSystem::read(&fs, index).expect("should read file"),
+      b"disk"
+    );
+  }
+}
@@ -0,0 +1,407 @@
+use std::{
+  collections::HashSet,
+  path::Path,
+  sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+  thread::{self, JoinHandle},
+  time::Duration,
+};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{
+  Config, ErrorKind, Event, EventKind, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher as _,
+};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use crate::{Error, Result};
+
+/// Interval of the polling watcher used when native events are not available.
+const FALLBACK_POLL_INTERVAL: Duration = Duration::from_millis(1000);
+
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+  /// Changes are reported once no other change happened during this time.
+  pub aggregate_timeout: Duration,
+  /// Poll the file system with this interval instead of listening to native events.
+  pub poll: Option<Duration>,
+  /// Glob patterns of the paths whose changes are ignored, e.g. `**/node_modules/**`.
+  pub ignored: Vec<String>,
+}
+
+impl Default for WatchOptions {
+  fn default() -> Self {
+    Self {
+      aggregate_timeout: Duration::from_millis(20),
+      poll: None,
+      ignored: vec![],
+    }
+  }
+}
+
+/// Changes aggregated during [WatchOptions::aggregate_timeout], in the shape expected by
+/// `Compiler::rebuild`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileSystemChanges {
+  /// Files created or modified
+  pub changed_files: HashSet<String>,
+  pub removed_files: HashSet<String>,
+}
+
+impl FileSystemChanges {
+  pub fn is_empty(&self) -> bool {
+    self.changed_files.is_empty() && self.removed_files.is_empty()
+  }
+
+  fn add(&mut self, path: String, exists: bool) {
+    if exists {
+      self.removed_files.remove(&path);
+      self.changed_files.insert(path);
+    } else {
+      self.changed_files.remove(&path);
+      self.removed_files.insert(path);
+    }
+  }
+}
+
+pub type WatchCallback = Box<dyn FnMut(FileSystemChanges) + Send>;
+
+pub trait Watcher: Send {
+  /// Stop watching, the changes still being aggregated are reported before this returns,
+  /// no change is reported after.
+  fn close(self: Box<Self>);
+}
+
+pub trait WatchFileSystem: Send + Sync {
+  /// Watch `files` and everything inside `directories`.
+  ///
+  /// `callback` is called on a background thread with the changes aggregated
+  /// during [WatchOptions::aggregate_timeout].
+  fn watch(
+    &self,
+    files: &[Utf8PathBuf],
+    directories: &[Utf8PathBuf],
+    options: &WatchOptions,
+    callback: WatchCallback,
+  ) -> Result<Box<dyn Watcher>>;
+}
+
+/// Watch with the native events of the platform (inotify on Linux),
+/// and fall back to polling when they are unavailable.
+#[derive(Debug, Default)]
+pub struct NativeWatchFileSystem;
+
+impl WatchFileSystem for NativeWatchFileSystem {
+  fn watch(
+    &self,
+    files: &[Utf8PathBuf],
+    directories: &[Utf8PathBuf],
+    options: &WatchOptions,
+    callback: WatchCallback,
+  ) -> Result<Box<dyn Watcher>> {
+    let filter = WatchFilter::new(files, directories, &options.ignored)?;
+
+    // watch the parent directories of the files, as editors often replace a file
+    // instead of writing to it, which would drop a watcher on the file itself
+    let mut targets = files
+      .iter()
+      .filter_map(|file| file.parent())
+      .collect::<HashSet<_>>()
+      .into_iter()
+      .map(|dir| (dir, RecursiveMode::NonRecursive))
+      .collect::<Vec<_>>();
+    targets.extend(
+      directories
+        .iter()
+        .map(|dir| (dir.as_path(), RecursiveMode::Recursive)),
+    );
+
+    let (sender, receiver) = mpsc::channel();
+    let watcher = match options.poll {
+      Some(interval) => create_poll_watcher(sender, interval, &targets)?,
+      None => match create_native_watcher(sender.clone(), &targets) {
+        Ok(watcher) => watcher,
+        Err(_) => create_poll_watcher(sender, FALLBACK_POLL_INTERVAL, &targets)?,
+      },
+    };
+
+    let aggregate_timeout = options.aggregate_timeout;
+    let handle = thread::spawn(move || aggregate(receiver, filter, aggregate_timeout, callback));
+
+    Ok(Box::new(NativeWatcher {
+      watcher: Some(watcher),
+      handle: Some(handle),
+    }))
+  }
+}
+
+type NotifyWatcher = Box<dyn notify::Watcher + Send>;
+type NotifySender = Sender<notify::Result<Event>>;
+
+fn create_native_watcher(
+  sender: NotifySender,
+  targets: &[(&Utf8Path, RecursiveMode)],
+) -> notify::Result<NotifyWatcher> {
+  let mut watcher = RecommendedWatcher::new(sender, Config::default())?;
+  watch_targets(&mut watcher, targets)?;
+  Ok(Box::new(watcher))
+}
+
+fn create_poll_watcher(
+  sender: NotifySender,
+  interval: Duration,
+  targets: &[(&Utf8Path, RecursiveMode)],
+) -> Result<NotifyWatcher> {
+  // modification times are compared in seconds, compare the contents to catch quick edits as well
+  let config = Config::default()
+    .with_poll_interval(interval)
+    .with_compare_contents(true);
+  let mut watcher = PollWatcher::new(sender, config).map_err(into_error)?;
+  watch_targets(&mut watcher, targets).map_err(into_error)?;
+  Ok(Box::new(watcher))
+}
+
+fn watch_targets(
+  watcher: &mut dyn notify::Watcher,
+  targets: &[(&Utf8Path, RecursiveMode)],
+) -> notify::Result<()> {
+  for (path, mode) in targets {
+    match watcher.watch(path.as_std_path(), *mode) {
+      // missing paths are still reported through their watched parent directory
+      Err(err) if is_not_found(&err) => {}
+      result => result?,
+    }
+  }
+  Ok(())
+}
+
+fn is_not_found(err: &notify::Error) -> bool {
+  match &err.kind {
+    ErrorKind::PathNotFound => true,
+    ErrorKind::Io(io) => io.kind() == std::io::ErrorKind::NotFound,
+    _ => false,
+  }
+}
+
+fn into_error(err: notify::Error) -> Error {
+  match err.kind {
+    ErrorKind::Io(err) => Error::Io(err),
+    _ => Error::Io(std::io::Error::other(err)),
+  }
+}
+
+struct WatchFilter {
+  files: HashSet<String>,
+  directories: Vec<Utf8PathBuf>,
+  ignored: GlobSet,
+}
+
+impl WatchFilter {
+  fn new(files: &[Utf8PathBuf], directories: &[Utf8PathBuf], ignored: &[String]) -> Result<Self> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in ignored {
+      let glob = Glob::new(pattern)
+        .map_err(|err| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, err)))?;
+      builder.add(glob);
+    }
+    let ignored = builder
+      .build()
+      .map_err(|err| Error::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, err)))?;
+    Ok(Self {
+      files: files.iter().map(|file| file.to_string()).collect(),
+      directories: directories.to_vec(),
+      ignored,
+    })
+  }
+
+  fn is_watched(&self, path: &Path) -> bool {
+    if self.ignored.is_match(path) {
+      return false;
+    }
+    path.to_str().is_some_and(|path| self.files.contains(path))
+      || self
+        .directories
+        .iter()
+        .any(|dir| path.starts_with(dir.as_std_path()))
+  }
+
+  fn collect(&self, event: &Event, changes: &mut FileSystemChanges) {
+    if matches!(event.kind, EventKind::Access(_)) {
+      return;
+    }
+    for path in &event.paths {
+      if self.is_watched(path) {
+        changes.add(path.to_string_lossy().to_string(), path.exists());
+      }
+    }
+  }
+}
+
+fn aggregate(
+  receiver: Receiver<notify::Result<Event>>,
+  filter: WatchFilter,
+  aggregate_timeout: Duration,
+  mut callback: WatchCallback,
+) {
+  let mut changes = FileSystemChanges::default();
+  loop {
+    let received = if changes.is_empty() {
+      receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+    } else {
+      receiver.recv_timeout(aggregate_timeout)
+    };
+    match received {
+      Ok(Ok(event)) => filter.collect(&event, &mut changes),
+      // errors of single events don't stop watching
+      Ok(Err(_)) => {}
+      Err(RecvTimeoutError::Timeout) => callback(std::mem::take(&mut changes)),
+      Err(RecvTimeoutError::Disconnected) => {
+        // the watcher is closed, report what happened before instead of losing it
+        if !changes.is_empty() {
+          callback(std::mem::take(&mut changes));
+        }
+        break;
+      }
+    }
+  }
+}
+
+struct NativeWatcher {
+  watcher: Option<NotifyWatcher>,
+  handle: Option<JoinHandle<()>>,
+}
+
+impl NativeWatcher {
+  fn stop(&mut self) {
+    // dropping the watcher disconnects the channel, which ends the aggregating thread
+    drop(self.watcher.take());
+    if let Some(handle) = self.handle.take() {
+      // the watcher may be closed by the callback, which runs on the aggregating thread
+      if handle.thread().id() != thread::current().id() {
+        let _ = handle.join();
+      }
+    }
+  }
+}
+
+impl Watcher for NativeWatcher {
+  fn close(mut self: Box<Self>) {
+    self.stop();
+  }
+}
+
+impl Drop for NativeWatcher {
+  fn drop(&mut self) {
+    self.stop();
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::{
+    fs,
+    sync::{mpsc::RecvTimeoutError, Arc, Mutex},
+  };
+
+  use super::*;
+
+  /// Upper bound to wait for the events of the watcher, only reached when a test fails.
+  const RECV_TIMEOUT: Duration = Duration::from_secs(10);
+
+  #[test]
+  fn test_filter() {
+    let filter = WatchFilter::new(
+      &[Utf8PathBuf::from("/app/rspack.config.js")],
+      &[Utf8PathBuf::from("/app/src")],
+      &["**/node_modules/**".to_string()],
+    )
+    .expect("should create filter");
+    assert!(filter.is_watched(Path::new("/app/rspack.config.js")));
+    assert!(filter.is_watched(Path::new("/app/src/index.js")));
+    assert!(!filter.is_watched(Path::new("/app/package.json")));
+    assert!(!filter.is_watched(Path::new("/app/src/node_modules/lib/index.js")));
+  }
+
+  #[test]
+  fn test_changes() {
+    let mut changes = FileSystemChanges::default();
+    changes.add("a.js".to_string(), true);
+    changes.add("b.js".to_string(), false);
+    changes.add("a.js".to_string(), false);
+    assert!(changes.changed_files.is_empty());
+    assert_eq!(
+      changes.removed_files,
+      HashSet::from(["a.js".to_string(), "b.js".to_string()])
+    );
+  }
+
+  #[test]
+  fn test_poll_watch() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let file = Utf8PathBuf::from_path_buf(dir.path().join("index.js")).expect("should be utf8");
+    fs::write(&file, "a").expect("should write file");
+
+    let (sender, receiver) = mpsc::channel();
+    let watcher = NativeWatchFileSystem
+      .watch(
+        &[file.clone()],
+        &[],
+        &WatchOptions {
+          poll: Some(Duration::from_millis(10)),
+          ..Default::default()
+        },
+        Box::new(move |changes| {
+          let _ = sender.send(changes);
+        }),
+      )
+      .expect("should watch");
+
+    // the poll watcher scans the initial state before `watch` returns
+    fs::write(&file, "bb").expect("should write file");
+    let changes = receiver
+      .recv_timeout(RECV_TIMEOUT)
+      .expect("should receive changes");
+    assert!(changes.changed_files.contains(file.as_str()));
+
+    watcher.close();
+    fs::write(&file, "ccc").expect("should write file");
+    // closing ends the aggregating thread, which drops the callback and its sender
+    assert!(matches!(
+      receiver.recv_timeout(RECV_TIMEOUT),
+      Err(RecvTimeoutError::Disconnected)
+    ));
+  }
+
+  #[test]
+  fn test_close_in_callback() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let file = Utf8PathBuf::from_path_buf(dir.path().join("index.js")).expect("should be utf8");
+    fs::write(&file, "a").expect("should write file");
+
+    let watcher: Arc<Mutex<Option<Box<dyn Watcher>>>> = Default::default();
+    let (sender, receiver) = mpsc::channel();
+    let handle = NativeWatchFileSystem
+      .watch(
+        &[file.clone()],
+        &[],
+        &WatchOptions {
+          poll: Some(Duration::from_millis(10)),
+          ..Default::default()
+        },
+        Box::new({
+          let watcher = watcher.clone();
+          move |_| {
+            if let Some(watcher) = watcher.lock().expect("should lock").take() {
+              watcher.close();
+            }
+            let _ = sender.send(());
+          }
+        }),
+      )
+      .expect("should watch");
+    *watcher.lock().expect("should lock") = Some(handle);
+
+    fs::write(&file, "bb").expect("should write file");
+    receiver
+      .recv_timeout(RECV_TIMEOUT)
+      .expect("should close the watcher in the callback");
+  }
+}
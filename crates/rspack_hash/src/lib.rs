@@ -5,21 +5,33 @@ use std::{
 
 use data_encoding::HEXLOWER_PERMISSIVE;
 use md4::Digest;
+use rspack_error::{error, Error};
 use smol_str::SmolStr;
-use xxhash_rust::xxh64::Xxh64;
+use xxhash_rust::{xxh3::Xxh3, xxh64::Xxh64};
 
 #[derive(Debug, Clone, Copy)]
 pub enum HashFunction {
   Xxhash64,
   MD4,
+  /// 128 bits variant of xxh3
+  Xxhash3,
+  SHA256,
+  Blake3,
 }
 
-impl From<&str> for HashFunction {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashFunction {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "xxhash64" => HashFunction::Xxhash64,
-      "md4" => HashFunction::MD4,
-      _ => unimplemented!("{}", value),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "md4" => Ok(HashFunction::MD4),
+      "xxhash3" => Ok(HashFunction::Xxhash3),
+      "sha256" => Ok(HashFunction::SHA256),
+      "blake3" => Ok(HashFunction::Blake3),
+      _ => Err(error!(
+        "Unsupported hash function \"{value}\", expected one of \"xxhash64\", \"md4\", \"xxhash3\", \"sha256\" or \"blake3\""
+      )),
     }
   }
 }
@@ -29,11 +41,15 @@ pub enum HashDigest {
   Hex,
 }
 
-impl From<&str> for HashDigest {
-  fn from(value: &str) -> Self {
+impl TryFrom<&str> for HashDigest {
+  type Error = Error;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
     match value {
-      "hex" => HashDigest::Hex,
-      _ => unimplemented!(),
+      "hex" => Ok(HashDigest::Hex),
+      _ => Err(error!(
+        "Unsupported hash digest \"{value}\", expected \"hex\""
+      )),
     }
   }
 }
@@ -57,6 +73,9 @@ impl From<Option<String>> for HashSalt {
 pub enum RspackHash {
   Xxhash64(Box<Xxh64>),
   MD4(Box<md4::Md4>),
+  Xxhash3(Box<Xxh3>),
+  SHA256(Box<sha2::Sha256>),
+  Blake3(Box<blake3::Hasher>),
 }
 
 impl fmt::Debug for RspackHash {
@@ -64,6 +83,9 @@ impl fmt::Debug for RspackHash {
     match self {
       Self::Xxhash64(_) => write!(f, "RspackHash(Xxhash64)"),
       Self::MD4(_) => write!(f, "RspackHash(MD4)"),
+      Self::Xxhash3(_) => write!(f, "RspackHash(Xxhash3)"),
+      Self::SHA256(_) => write!(f, "RspackHash(SHA256)"),
+      Self::Blake3(_) => write!(f, "RspackHash(Blake3)"),
     }
   }
 }
@@ -73,6 +95,9 @@ impl RspackHash {
     match function {
       HashFunction::Xxhash64 => Self::Xxhash64(Box::new(Xxh64::new(0))),
       HashFunction::MD4 => Self::MD4(Box::new(md4::Md4::new())),
+      HashFunction::Xxhash3 => Self::Xxhash3(Box::new(Xxh3::new())),
+      HashFunction::SHA256 => Self::SHA256(Box::new(sha2::Sha256::new())),
+      HashFunction::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
     }
   }
 
@@ -88,6 +113,9 @@ impl RspackHash {
     let inner = match self {
       RspackHash::Xxhash64(hasher) => hasher.finish().to_be_bytes().to_vec(),
       RspackHash::MD4(hash) => hash.finalize().to_vec(),
+      RspackHash::Xxhash3(hasher) => hasher.digest128().to_be_bytes().to_vec(),
+      RspackHash::SHA256(hash) => hash.finalize().to_vec(),
+      RspackHash::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
     };
     RspackHashDigest::new(inner, digest)
   }
@@ -97,19 +125,11 @@ impl Hasher for RspackHash {
   fn finish(&self) -> u64 {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.finish(),
-      RspackHash::MD4(hasher) => {
-        // finalize take ownership, so we need to clone it
-        let hash = hasher.clone().finalize();
-        let msb_u64: u64 = ((hash[0] as u64) << 56)
-          | ((hash[1] as u64) << 48)
-          | ((hash[2] as u64) << 40)
-          | ((hash[3] as u64) << 32)
-          | ((hash[4] as u64) << 24)
-          | ((hash[5] as u64) << 16)
-          | ((hash[6] as u64) << 8)
-          | (hash[7] as u64);
-        msb_u64
-      }
+      // finalize take ownership, so we need to clone it
+      RspackHash::MD4(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Xxhash3(hasher) => msb_u64(&hasher.digest128().to_be_bytes()),
+      RspackHash::SHA256(hasher) => msb_u64(&hasher.clone().finalize()),
+      RspackHash::Blake3(hasher) => msb_u64(hasher.finalize().as_bytes()),
     }
   }
 
@@ -117,10 +137,22 @@ impl Hasher for RspackHash {
     match self {
       RspackHash::Xxhash64(hasher) => hasher.write(bytes),
       RspackHash::MD4(hasher) => hasher.update(bytes),
+      RspackHash::Xxhash3(hasher) => hasher.update(bytes),
+      RspackHash::SHA256(hasher) => hasher.update(bytes),
+      RspackHash::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
     }
   }
 }
 
+/// The most significant 64 bits of a digest.
+fn msb_u64(digest: &[u8]) -> u64 {
+  let mut msb = [0; 8];
+  msb.copy_from_slice(&digest[..8]);
+  u64::from_be_bytes(msb)
+}
+
 #[derive(Debug, Clone, Eq)]
 pub struct RspackHashDigest {
   encoded: SmolStr,
@@ -144,6 +176,14 @@ impl RspackHashDigest {
   }
 }
 
+impl From<&str> for RspackHashDigest {
+  fn from(value: &str) -> Self {
+    Self {
+      encoded: value.into(),
+    }
+  }
+}
+
 impl Hash for RspackHashDigest {
   fn hash<H: Hasher>(&self, state: &mut H) {
     self.encoded.hash(state);
@@ -155,3 +195,45 @@ impl PartialEq for RspackHashDigest {
     self.encoded == other.encoded
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn digest(function: HashFunction) -> RspackHashDigest {
+    let mut hasher = RspackHash::new(&function);
+    hasher.write(b"rspack");
+    hasher.digest(&HashDigest::Hex)
+  }
+
+  #[test]
+  fn test_digest_length() {
+    assert_eq!(digest(HashFunction::Xxhash64).encoded().len(), 16);
+    assert_eq!(digest(HashFunction::MD4).encoded().len(), 32);
+    assert_eq!(digest(HashFunction::Xxhash3).encoded().len(), 32);
+    assert_eq!(digest(HashFunction::SHA256).encoded().len(), 64);
+    assert_eq!(digest(HashFunction::Blake3).encoded().len(), 64);
+  }
+
+  #[test]
+  fn test_finish_matches_digest() {
+    for function in [
+      HashFunction::MD4,
+      HashFunction::Xxhash3,
+      HashFunction::SHA256,
+      HashFunction::Blake3,
+    ] {
+      let mut hasher = RspackHash::new(&function);
+      hasher.write(b"rspack");
+      let finish = format!("{:016x}", hasher.finish());
+      assert_eq!(finish, hasher.digest(&HashDigest::Hex).rendered(16));
+    }
+  }
+
+  #[test]
+  fn test_unsupported_hash_function() {
+    assert!(HashFunction::try_from("sha256").is_ok());
+    assert!(HashFunction::try_from("sha1").is_err());
+    assert!(HashDigest::try_from("base64").is_err());
+  }
+}
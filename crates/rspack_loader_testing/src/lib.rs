@@ -1,15 +1,20 @@
 #![feature(let_chains)]
 
+use std::{marker::PhantomData, sync::Arc};
+
 use async_trait::async_trait;
 use rspack_core::{Loader, LoaderContext, RunnerContext};
 use rspack_error::Result;
-use rspack_loader_runner::{DisplayWithSuffix, Identifiable, Identifier};
+use rspack_fs::{MemoryFileSystem, ReadableFileSystem};
+use rspack_loader_runner::{
+  Content, DisplayWithSuffix, Identifiable, Identifier, LoaderRunnerPlugin, ResourceData,
+};
 use serde_json::json;
 
 pub struct SimpleLoader;
 #[async_trait]
-impl Loader<RunnerContext> for SimpleLoader {
-  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+impl<Context: Send> Loader<Context> for SimpleLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<Context>) -> Result<()> {
     let Some(content) = loader_context.content.take() else {
       return Ok(());
     };
@@ -27,8 +32,8 @@ pub const SIMPLE_LOADER_IDENTIFIER: &str = "builtin:test-simple-loader";
 
 pub struct SimpleAsyncLoader;
 #[async_trait]
-impl Loader<RunnerContext> for SimpleAsyncLoader {
-  async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+impl<Context: Send> Loader<Context> for SimpleAsyncLoader {
+  async fn run(&self, loader_context: &mut LoaderContext<Context>) -> Result<()> {
     let Some(content) = loader_context.content.take() else {
       return Ok(());
     };
@@ -66,3 +71,74 @@ impl Identifiable for PitchingLoader {
   }
 }
 pub const PITCHING_LOADER_IDENTIFIER: &str = "builtin:test-pitching-loader";
+
+/// Reads the resources from a [MemoryFileSystem], so the loaders run without touching the disk.
+pub struct MemoryResourcePlugin<Context> {
+  fs: Arc<MemoryFileSystem>,
+  _context: PhantomData<fn() -> Context>,
+}
+
+impl<Context> MemoryResourcePlugin<Context> {
+  pub fn new(fs: Arc<MemoryFileSystem>) -> Self {
+    Self {
+      fs,
+      _context: PhantomData,
+    }
+  }
+}
+
+#[async_trait]
+impl<Context> LoaderRunnerPlugin for MemoryResourcePlugin<Context> {
+  type Context = Context;
+
+  fn name(&self) -> &'static str {
+    "memory-resource"
+  }
+
+  async fn process_resource(&self, resource_data: &ResourceData) -> Result<Option<Content>> {
+    let Some(resource_path) = &resource_data.resource_path else {
+      return Ok(None);
+    };
+    let content = ReadableFileSystem::read(self.fs.as_ref(), resource_path)?;
+    Ok(Some(Content::from(content)))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_loader_runner::{run_loaders, Loader, ResourceData};
+  use rspack_paths::Utf8Path;
+
+  use super::{MemoryResourcePlugin, SimpleLoader};
+
+  #[tokio::test]
+  async fn test_run_loaders_on_memory_file_system() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    let file = Utf8Path::new("/virtual/index.js");
+    WritableFileSystem::create_dir_all(fs.as_ref(), Utf8Path::new("/virtual"))
+      .expect("should create dir");
+    WritableFileSystem::write(fs.as_ref(), file, b"content").expect("should write file");
+
+    let resource_data = Arc::new(ResourceData::new(file.to_string()).path(file));
+    let result = run_loaders(
+      vec![Arc::new(SimpleLoader) as Arc<dyn Loader<()>>],
+      resource_data,
+      Some(Arc::new(MemoryResourcePlugin::new(fs))),
+      (),
+      Default::default(),
+    )
+    .await
+    .expect("should run loaders");
+    assert_eq!(
+      result
+        .inner
+        .content
+        .try_into_string()
+        .expect("should be a string"),
+      "module.exports = \"content-simple\""
+    );
+  }
+}
@@ -0,0 +1,143 @@
+#![feature(let_chains)]
+
+use std::sync::Arc;
+
+use rspack_core::{
+  AfterResolveResult, ApplyContext, BeforeResolveResult, CompilerOptions, ContextElementDependency,
+  ContextModuleFactoryAfterResolve, ContextModuleFactoryBeforeResolve, ContextModuleOptions,
+  DependencyId, DependencyType, Plugin, PluginContext, ResolveContextModuleDependencies,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8Path;
+use rspack_regex::RspackRegex;
+use rustc_hash::FxHashMap as HashMap;
+
+#[derive(Debug)]
+pub struct ContextReplacementPluginOptions {
+  pub resource_reg_exp: RspackRegex,
+  /// Replaces the request of the context before it is resolved, and the resolved directory
+  /// when it matches, a relative path is resolved against the resolved directory
+  pub new_content_resource: Option<String>,
+  pub new_content_recursive: Option<bool>,
+  pub new_content_reg_exp: Option<RspackRegex>,
+  /// Replaces the files of the context with a static map, the keys are the requests
+  /// available in the context, the values are the requests they are resolved to, relative
+  /// to the directory of the context
+  pub new_content_create_context_map: Option<HashMap<String, String>>,
+}
+
+/// Changes the directory, the recursive flag and the regexp of the contexts whose request or
+/// resolved directory matches `resource_reg_exp`, e.g. to reduce the locales of `moment` to
+/// the ones used by the application.
+#[plugin]
+#[derive(Debug)]
+pub struct ContextReplacementPlugin {
+  options: ContextReplacementPluginOptions,
+}
+
+impl ContextReplacementPlugin {
+  pub fn new(options: ContextReplacementPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(ContextModuleFactoryBeforeResolve for ContextReplacementPlugin)]
+async fn cmf_before_resolve(&self, result: BeforeResolveResult) -> Result<BeforeResolveResult> {
+  let mut result = result;
+  if let BeforeResolveResult::Data(data) = &mut result
+    && let Some(request) = &data.request
+    && self.options.resource_reg_exp.test(request)
+  {
+    if let Some(new_content_resource) = &self.options.new_content_resource {
+      data.request = Some(new_content_resource.clone());
+    }
+    if let Some(new_content_recursive) = self.options.new_content_recursive {
+      data.recursive = new_content_recursive;
+    }
+    if let Some(new_content_reg_exp) = &self.options.new_content_reg_exp {
+      data.reg_exp = Some(new_content_reg_exp.clone());
+    }
+  }
+  Ok(result)
+}
+
+#[plugin_hook(ContextModuleFactoryAfterResolve for ContextReplacementPlugin)]
+async fn cmf_after_resolve(&self, result: AfterResolveResult) -> Result<AfterResolveResult> {
+  let mut result = result;
+  if let AfterResolveResult::Data(data) = &mut result
+    && self.options.resource_reg_exp.test(data.resource.as_str())
+  {
+    if let Some(new_content_resource) = &self.options.new_content_resource {
+      // an absolute path replaces the resource when joined
+      data.resource = data.resource.join(new_content_resource);
+    }
+    if let Some(new_content_recursive) = self.options.new_content_recursive {
+      data.recursive = new_content_recursive;
+    }
+    if let Some(new_content_reg_exp) = &self.options.new_content_reg_exp {
+      data.reg_exp = Some(new_content_reg_exp.clone());
+    }
+    if let Some(context_map) = &self.options.new_content_create_context_map {
+      data.resolve_dependencies = Some(create_resolve_dependencies_from_context_map(
+        context_map.clone(),
+      ));
+    }
+  }
+  Ok(result)
+}
+
+fn create_resolve_dependencies_from_context_map(
+  context_map: HashMap<String, String>,
+) -> ResolveContextModuleDependencies {
+  Arc::new(move |options: &ContextModuleOptions| {
+    let dependencies = context_map
+      .iter()
+      .map(|(key, value)| ContextElementDependency {
+        id: DependencyId::new(),
+        request: format!(
+          "{value}{}{}",
+          options.resource_query, options.resource_fragment
+        ),
+        user_request: key.clone(),
+        category: options.context_options.category,
+        context: options.resource.clone().into(),
+        layer: options.layer.clone(),
+        options: options.context_options.clone(),
+        resource_identifier: ContextElementDependency::create_resource_identifier(
+          options.resource.as_str(),
+          &options.resource.join(Utf8Path::new(value)),
+          options.context_options.attributes.as_ref(),
+        ),
+        attributes: options.context_options.attributes.clone(),
+        referenced_exports: options.context_options.referenced_exports.clone(),
+        dependency_type: DependencyType::ContextElement(options.type_prefix),
+      })
+      .collect();
+    Ok(dependencies)
+  })
+}
+
+impl Plugin for ContextReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "ContextReplacementPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .context_module_factory_hooks
+      .before_resolve
+      .tap(cmf_before_resolve::new(self));
+    ctx
+      .context
+      .context_module_factory_hooks
+      .after_resolve
+      .tap(cmf_after_resolve::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,159 @@
+use rspack_core::{
+  AsContextDependency, AsDependency, AsDependencyTemplate, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, ErrorSpan,
+  ModuleDependency, ModuleGraph, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+
+use crate::{parser_and_generator::CssParserAndGenerator, utils::unescape};
+
+/// The request of an ICSS `:import("./x.css") { ... }` block, the values of the block are
+/// taken from the exports of the imported module.
+#[derive(Debug, Clone)]
+pub struct CssIcssImportDependency {
+  id: DependencyId,
+  request: String,
+}
+
+impl CssIcssImportDependency {
+  pub fn new(request: String) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+    }
+  }
+}
+
+impl Dependency for CssIcssImportDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CssImport
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::CssIcssImport
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+}
+
+impl ModuleDependency for CssIcssImportDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl AsDependencyTemplate for CssIcssImportDependency {}
+impl AsContextDependency for CssIcssImportDependency {}
+
+/// An occurrence of a name declared in an ICSS `:import` block, replaced with the value
+/// the imported module exports under `export_name`.
+#[derive(Debug, Clone)]
+pub struct CssIcssSymbolDependency {
+  import_dependency: DependencyId,
+  export_name: String,
+  start: u32,
+  end: u32,
+}
+
+impl CssIcssSymbolDependency {
+  pub fn new(import_dependency: DependencyId, export_name: String, start: u32, end: u32) -> Self {
+    Self {
+      import_dependency,
+      export_name,
+      start,
+      end,
+    }
+  }
+
+  pub fn span(&self) -> ErrorSpan {
+    ErrorSpan::new(self.start, self.end)
+  }
+}
+
+impl DependencyTemplate for CssIcssSymbolDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let module_graph = code_generatable_context.compilation.get_module_graph();
+    if let Some(value) =
+      get_icss_export_value(&module_graph, &self.import_dependency, &self.export_name)
+    {
+      source.replace(self.start, self.end, &value, None);
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    None
+  }
+
+  fn update_hash(
+    &self,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+    let module_graph = compilation.get_module_graph();
+    if let Some(value) =
+      get_icss_export_value(&module_graph, &self.import_dependency, &self.export_name)
+    {
+      hasher.write(value.as_bytes());
+    }
+  }
+}
+
+impl AsDependency for CssIcssSymbolDependency {}
+
+// Bounds the chain of modules re-exporting a value, the chain may be circular
+const MAX_ICSS_IMPORT_DEPTH: usize = 32;
+
+/// Resolves the value a css module exports under `name`, following the values it imports or
+/// composes from other modules.
+pub fn get_icss_export_value(
+  module_graph: &ModuleGraph,
+  dependency_id: &DependencyId,
+  name: &str,
+) -> Option<String> {
+  get_icss_export_value_impl(module_graph, dependency_id, name, 0)
+}
+
+fn get_icss_export_value_impl(
+  module_graph: &ModuleGraph,
+  dependency_id: &DependencyId,
+  name: &str,
+  depth: usize,
+) -> Option<String> {
+  if depth > MAX_ICSS_IMPORT_DEPTH {
+    return None;
+  }
+  let module = module_graph
+    .get_module_by_dependency_id(dependency_id)?
+    .as_normal_module()?;
+  let parser_and_generator = module
+    .parser_and_generator()
+    .as_any()
+    .downcast_ref::<CssParserAndGenerator>()?;
+  let exports = parser_and_generator.exports.as_ref()?.get(name)?;
+  let values = exports
+    .iter()
+    .map(|export| match (&export.from, &export.id) {
+      (Some(_), Some(id)) => get_icss_export_value_impl(module_graph, id, &export.ident, depth + 1),
+      _ => Some(unescape(&export.ident).into_owned()),
+    })
+    .collect::<Option<Vec<_>>>()?;
+  Some(values.join(" "))
+}
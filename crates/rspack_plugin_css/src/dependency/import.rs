@@ -4,21 +4,59 @@ use rspack_core::{
   RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CssLayer {
+  /// `layer` without a name
+  Anonymous,
+  Named(String),
+}
+
+/// The `layer()`, `supports()` and media query list of an `@import`, the imported CSS is
+/// wrapped in the matching `@layer`, `@supports` and `@media` blocks when rendered.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CssImportConditions {
+  pub layer: Option<CssLayer>,
+  pub supports: Option<String>,
+  pub media: Option<String>,
+}
+
+impl CssImportConditions {
+  pub fn is_empty(&self) -> bool {
+    self.layer.is_none() && self.supports.is_none() && self.media.is_none()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CssImportDependency {
   id: DependencyId,
   request: String,
   range: RealDependencyLocation,
+  /// The conditions of the import followed by the ones of the `@import` chain its issuer is
+  /// created for
+  import_chain: Vec<CssImportConditions>,
 }
 
 impl CssImportDependency {
-  pub fn new(request: String, range: RealDependencyLocation) -> Self {
+  pub fn new(
+    request: String,
+    range: RealDependencyLocation,
+    import_chain: Vec<CssImportConditions>,
+  ) -> Self {
     Self {
       id: DependencyId::new(),
       request,
       range,
+      import_chain,
     }
   }
+
+  pub fn import_chain(&self) -> &[CssImportConditions] {
+    &self.import_chain
+  }
+
+  pub fn range(&self) -> &RealDependencyLocation {
+    &self.range
+  }
 }
 
 impl Dependency for CssImportDependency {
@@ -1,6 +1,6 @@
 use rspack_core::{
   AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
-  DependencyId, DependencyTemplate, DependencyType, ExportNameOrSpec, ExportSpec,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ExportNameOrSpec, ExportSpec,
   ExportsOfExportsSpec, ExportsSpec, RuntimeSpec, TemplateContext, TemplateReplaceSource,
 };
 use rspack_util::ext::DynHash;
@@ -24,6 +24,10 @@ impl CssLocalIdentDependency {
       end,
     }
   }
+
+  pub fn local_ident(&self) -> &str {
+    &self.local_ident
+  }
 }
 
 impl Dependency for CssLocalIdentDependency {
@@ -39,6 +43,10 @@ impl Dependency for CssLocalIdentDependency {
     &DependencyType::CssLocalIdent
   }
 
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.start, self.end))
+  }
+
   fn get_exports(&self, _mg: &rspack_core::ModuleGraph) -> Option<ExportsSpec> {
     Some(ExportsSpec {
       exports: ExportsOfExportsSpec::Array(
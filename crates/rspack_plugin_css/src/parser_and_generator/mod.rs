@@ -10,7 +10,7 @@ use rspack_core::{
   diagnostics::map_box_diagnostics_to_module_parse_diagnostics,
   rspack_sources::{BoxSource, ConcatSource, RawSource, ReplaceSource, Source, SourceExt},
   BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, Compilation, ConstDependency,
-  CssExportsConvention, Dependency, DependencyId, DependencyTemplate, GenerateContext,
+  CssExportsConvention, Dependency, DependencyId, DependencyTemplate, ErrorSpan, GenerateContext,
   LocalIdentName, Module, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType,
   NormalModule, ParseContext, ParseResult, ParserAndGenerator, RealDependencyLocation, RuntimeSpec,
   SourceType, TemplateContext, UsageState,
@@ -20,14 +20,17 @@ use rspack_error::{
   miette::Diagnostic, IntoTWithDiagnosticArray, Result, RspackSeverity, TWithDiagnosticArray,
 };
 use rspack_util::ext::DynHash;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet};
 
 use crate::utils::{css_modules_exports_to_string, escape_css, LocalIdentOptions};
-use crate::utils::{export_locals_convention, unescape};
+use crate::utils::{
+  export_locals_convention, find_css_rules, find_css_values, find_icss_symbols, find_unused_rules,
+  unescape, CssRule, CssValue,
+};
 use crate::{
   dependency::{
-    CssComposeDependency, CssExportDependency, CssImportDependency, CssLocalIdentDependency,
-    CssUrlDependency,
+    CssComposeDependency, CssExportDependency, CssIcssImportDependency, CssIcssSymbolDependency,
+    CssImportConditions, CssImportDependency, CssLayer, CssLocalIdentDependency, CssUrlDependency,
   },
   utils::{
     css_modules_exports_to_concatenate_module_string, css_parsing_traceable_error, normalize_url,
@@ -69,7 +72,32 @@ pub struct CssParserAndGenerator {
   pub exports_only: bool,
   pub named_exports: bool,
   pub es_module: bool,
+  pub remove_unused_rules: bool,
   pub exports: Option<CssExports>,
+  /// The conditions of the `@import` chain the module is created for, the innermost import
+  /// first. They are part of the module identifier, like webpack's `CssModule`, so a module
+  /// imported under different conditions is a different module.
+  pub import_chain: Vec<CssImportConditions>,
+  /// The rules of the module, found when `remove_unused_rules` is enabled so that the ones
+  /// which only reference unused local idents are removed from the generated css.
+  pub rules: Vec<CssRule>,
+}
+
+impl CssParserAndGenerator {
+  /// Exports a value of an ICSS `:export` block or a `@value` rule under the names of the
+  /// exports convention
+  fn add_value_export(&mut self, name: &str, css_export: CssExport) -> CssExportDependency {
+    let exports = self.exports.get_or_insert_default();
+    let convention = self
+      .convention
+      .as_ref()
+      .expect("should have local_ident_name for module_type css/auto or css/module");
+    let convention_names = export_locals_convention(name, convention);
+    for name in convention_names.iter() {
+      update_css_exports(exports, name.to_owned(), css_export.clone());
+    }
+    CssExportDependency::new(convention_names)
+  }
 }
 
 impl ParserAndGenerator for CssParserAndGenerator {
@@ -137,7 +165,84 @@ impl ParserAndGenerator for CssParserAndGenerator {
     let mut presentational_dependencies: Vec<Box<dyn DependencyTemplate>> = vec![];
     let mut code_generation_dependencies: Vec<Box<dyn ModuleDependency>> = vec![];
 
-    let (deps, warnings) = css_module_lexer::collect_dependencies(&source_code, mode);
+    let css_values = if matches!(mode, css_module_lexer::Mode::Local) {
+      find_css_values(&source_code)
+    } else {
+      vec![]
+    };
+    // the `@value` rules are removed from the output, their content is blanked out so that the
+    // lexer doesn't report dependencies in them
+    let lexed_source = if css_values.is_empty() {
+      Cow::Borrowed(&*source_code)
+    } else {
+      let mut lexed_source = source_code.to_string();
+      for (_, range) in &css_values {
+        let range = range.start as usize..range.end as usize;
+        lexed_source.replace_range(range.clone(), &" ".repeat(range.len()));
+      }
+      Cow::Owned(lexed_source)
+    };
+    let (deps, warnings) = css_module_lexer::collect_dependencies(&lexed_source, mode);
+    self.rules = if self.remove_unused_rules && !matches!(mode, css_module_lexer::Mode::Css) {
+      find_css_rules(&lexed_source)
+    } else {
+      vec![]
+    };
+    let mut icss_import: Option<(String, DependencyId)> = None;
+    let mut icss_symbols: HashMap<&str, (String, DependencyId, &str)> = HashMap::default();
+    let mut values: HashMap<&str, String> = HashMap::default();
+    for (css_value, range) in &css_values {
+      presentational_dependencies.push(Box::new(ConstDependency::new(
+        range.start,
+        range.end,
+        "".into(),
+        None,
+      )));
+      match css_value {
+        CssValue::Declaration { name, value } => {
+          let css_export = if let Some(symbol) = icss_symbols.get(value).cloned() {
+            let css_export = CssExport {
+              ident: symbol.2.to_string(),
+              from: Some(symbol.0.clone()),
+              id: Some(symbol.1),
+            };
+            icss_symbols.insert(name, symbol);
+            css_export
+          } else {
+            let value = values
+              .get(value)
+              .cloned()
+              .unwrap_or_else(|| value.to_string());
+            values.insert(name, value.clone());
+            CssExport {
+              ident: value,
+              from: None,
+              id: None,
+            }
+          };
+          dependencies.push(Box::new(self.add_value_export(name, css_export)));
+        }
+        CssValue::Import { names, from } => {
+          let request = values
+            .get(from)
+            .map_or(*from, |from| from.as_str())
+            .trim_matches(|c| c == '\'' || c == '"')
+            .to_string();
+          let dep = CssIcssImportDependency::new(request.clone());
+          let dep_id = *dep.id();
+          dependencies.push(Box::new(dep));
+          for (imported, local) in names {
+            icss_symbols.insert(local, (request.clone(), dep_id, imported));
+            let css_export = CssExport {
+              ident: imported.to_string(),
+              from: Some(request.clone()),
+              id: Some(dep_id),
+            };
+            dependencies.push(Box::new(self.add_value_export(local, css_export)));
+          }
+        }
+      }
+    }
     for dependency in deps {
       match dependency {
         css_module_lexer::Dependency::Url {
@@ -164,7 +269,13 @@ impl ParserAndGenerator for CssParserAndGenerator {
           dependencies.push(dep.clone());
           code_generation_dependencies.push(dep);
         }
-        css_module_lexer::Dependency::Import { request, range, .. } => {
+        css_module_lexer::Dependency::Import {
+          request,
+          range,
+          layer,
+          supports,
+          media,
+        } => {
           if request.is_empty() {
             presentational_dependencies.push(Box::new(ConstDependency::new(
               range.start,
@@ -181,9 +292,30 @@ impl ParserAndGenerator for CssParserAndGenerator {
             range.start,
             range.end,
           );
+          let conditions = CssImportConditions {
+            layer: layer.map(|layer| {
+              let layer = layer.trim();
+              if layer.is_empty() {
+                CssLayer::Anonymous
+              } else {
+                CssLayer::Named(layer.to_string())
+              }
+            }),
+            supports: supports.map(|supports| supports.trim().to_string()),
+            media: media
+              .map(str::trim)
+              .filter(|media| !media.is_empty())
+              .map(ToString::to_string),
+          };
+          let import_chain = (!conditions.is_empty())
+            .then_some(conditions)
+            .into_iter()
+            .chain(self.import_chain.iter().cloned())
+            .collect();
           dependencies.push(Box::new(CssImportDependency::new(
             request.to_string(),
             RealDependencyLocation::new(range.start, range.end),
+            import_chain,
           )));
         }
         css_module_lexer::Dependency::Replace { content, range } => presentational_dependencies
@@ -308,29 +440,62 @@ impl ParserAndGenerator for CssParserAndGenerator {
             }
           }
         }
-        css_module_lexer::Dependency::ICSSExportValue { prop, value } => {
-          let exports = self.exports.get_or_insert_default();
-          let convention = self
-            .convention
-            .as_ref()
-            .expect("should have local_ident_name for module_type css/auto or css/module");
-          let convention_names = export_locals_convention(prop, convention);
-          for name in convention_names.iter() {
-            update_css_exports(
-              exports,
-              name.to_owned(),
-              CssExport {
-                ident: value.to_string(),
-                from: None,
-                id: None,
-              },
-            );
+        css_module_lexer::Dependency::ICSSImportFrom { path } => {
+          let request = path.trim_matches(|c| c == '\'' || c == '"').to_string();
+          let dep = CssIcssImportDependency::new(request.clone());
+          icss_import = Some((request, *dep.id()));
+          dependencies.push(Box::new(dep));
+        }
+        css_module_lexer::Dependency::ICSSImportValue { prop, value } => {
+          if let Some((request, dep_id)) = &icss_import {
+            icss_symbols.insert(prop, (request.clone(), *dep_id, value));
           }
-          dependencies.push(Box::new(CssExportDependency::new(convention_names)));
+        }
+        css_module_lexer::Dependency::ICSSExportValue { prop, value } => {
+          // an exported value may be a name imported from another module or a `@value`
+          let css_export = if let Some((request, dep_id, export_name)) = icss_symbols.get(value) {
+            CssExport {
+              ident: export_name.to_string(),
+              from: Some(request.clone()),
+              id: Some(*dep_id),
+            }
+          } else {
+            CssExport {
+              ident: values
+                .get(value)
+                .cloned()
+                .unwrap_or_else(|| value.to_string()),
+              from: None,
+              id: None,
+            }
+          };
+          dependencies.push(Box::new(self.add_value_export(prop, css_export)));
         }
         _ => {}
       }
     }
+    if !icss_symbols.is_empty() || !values.is_empty() {
+      for (name, range) in find_icss_symbols(&source_code, |word| {
+        icss_symbols.contains_key(word) || values.contains_key(word)
+      }) {
+        if let Some(value) = values.get(name) {
+          presentational_dependencies.push(Box::new(ConstDependency::new(
+            range.start,
+            range.end,
+            value.as_str().into(),
+            None,
+          )));
+        } else {
+          let (_, dep_id, export_name) = &icss_symbols[name];
+          presentational_dependencies.push(Box::new(CssIcssSymbolDependency::new(
+            *dep_id,
+            export_name.to_string(),
+            range.start,
+            range.end,
+          )));
+        }
+      }
+    }
     for warning in warnings {
       let range = warning.range();
       let error = css_parsing_traceable_error(
@@ -457,23 +622,49 @@ impl ParserAndGenerator for CssParserAndGenerator {
           )));
         }
 
+        let unused_rules = match &self.exports {
+          Some(exports) if self.remove_unused_rules => get_unused_rules(
+            &self.rules,
+            module,
+            exports,
+            generate_context.runtime,
+            &compilation.get_module_graph(),
+          ),
+          _ => vec![],
+        };
+        // the dependencies inside of the removed rules are not applied, their replacements
+        // would be kept
+        let is_removed = |span: Option<ErrorSpan>| {
+          span.is_some_and(|span| {
+            unused_rules
+              .iter()
+              .any(|rule| rule.start <= span.start && span.end <= rule.end)
+          })
+        };
+
         module.get_dependencies().iter().for_each(|id| {
-          if let Some(dependency) = compilation
-            .get_module_graph()
+          let module_graph = compilation.get_module_graph();
+          let dependency = module_graph
             .dependency_by_id(id)
-            .expect("should have dependency")
-            .as_dependency_template()
+            .expect("should have dependency");
+          if let Some(template) = dependency.as_dependency_template()
+            && !is_removed(dependency.span())
           {
-            dependency.apply(&mut source, &mut context)
+            template.apply(&mut source, &mut context)
           }
         });
 
         if let Some(dependencies) = module.get_presentational_dependencies() {
           dependencies
             .iter()
+            .filter(|dependency| !is_removed(presentational_dependency_span(dependency.as_ref())))
             .for_each(|dependency| dependency.apply(&mut source, &mut context));
         };
 
+        for rule in &unused_rules {
+          source.replace(rule.start, rule.end, "", None);
+        }
+
         generate_context.concatenation_scope = context.concatenation_scope.take();
 
         Ok(source.boxed())
@@ -566,6 +757,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
     _runtime: Option<&RuntimeSpec>,
   ) -> Result<()> {
     self.es_module.dyn_hash(hasher);
+    self.remove_unused_rules.dyn_hash(hasher);
     Ok(())
   }
 }
@@ -623,5 +815,54 @@ fn get_unused_local_ident(
   }
 }
 
+/// Finds the rules whose selectors only reference the local idents of unused exports, see
+/// [find_unused_rules]
+fn get_unused_rules(
+  rules: &[CssRule],
+  module: &dyn Module,
+  exports: &CssExports,
+  runtime: Option<&RuntimeSpec>,
+  mg: &ModuleGraph,
+) -> Vec<css_module_lexer::Range> {
+  if rules.is_empty() {
+    return vec![];
+  }
+  let used_local_idents: FxHashSet<&str> =
+    get_used_exports(exports, module.identifier(), runtime, mg)
+      .values()
+      .flat_map(|css_exports| css_exports.iter())
+      .filter(|css_export| css_export.from.is_none())
+      .map(|css_export| css_export.ident.as_str())
+      .collect();
+  // the local idents of the class and id selectors by the start of the selector
+  let local_idents: HashMap<u32, bool> = module
+    .get_dependencies()
+    .iter()
+    .filter_map(|id| {
+      let dependency = mg
+        .dependency_by_id(id)?
+        .downcast_ref::<CssLocalIdentDependency>()?;
+      let local_ident = dependency.local_ident().strip_prefix(['.', '#'])?;
+      Some((
+        dependency.span()?.start,
+        used_local_idents.contains(local_ident),
+      ))
+    })
+    .collect();
+  find_unused_rules(rules, |range| local_idents.get(&range.start).copied())
+}
+
+/// The span of the source a presentational dependency of a css module replaces
+fn presentational_dependency_span(dependency: &dyn DependencyTemplate) -> Option<ErrorSpan> {
+  let dependency = dependency.as_any();
+  if let Some(dependency) = dependency.downcast_ref::<ConstDependency>() {
+    Some(ErrorSpan::new(dependency.start, dependency.end))
+  } else {
+    dependency
+      .downcast_ref::<CssIcssSymbolDependency>()
+      .map(CssIcssSymbolDependency::span)
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct CssUsedExports(pub String);
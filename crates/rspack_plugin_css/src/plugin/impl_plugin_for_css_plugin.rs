@@ -7,14 +7,15 @@ use rayon::prelude::*;
 use rspack_core::rspack_sources::ReplaceSource;
 use rspack_core::{
   get_css_chunk_filename_template,
-  rspack_sources::{ConcatSource, RawSource, Source, SourceExt},
-  Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin, RenderManifestEntry,
-  SourceType,
+  rspack_sources::{BoxSource, ConcatSource, RawSource, Source, SourceExt},
+  BoxModule, Chunk, ChunkKind, Module, ModuleType, ParserAndGenerator, PathData, Plugin,
+  RenderManifestEntry, SourceType,
 };
 use rspack_core::{
   ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationContentHash,
   CompilationParams, CompilationRenderManifest, CompilationRuntimeRequirementInTree,
-  CompilerCompilation, CompilerOptions, DependencyType, LibIdentOptions, PublicPath,
+  CompilerCompilation, CompilerOptions, DependencyType, LibIdentOptions, ModuleFactoryCreateData,
+  NormalModuleCreateData, NormalModuleFactoryAfterResolve, NormalModuleFactoryModule, PublicPath,
   RuntimeGlobals,
 };
 use rspack_error::{Diagnostic, Result};
@@ -23,6 +24,7 @@ use rspack_hook::plugin_hook;
 use rspack_plugin_runtime::is_enabled_for_chunk;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+use crate::dependency::{CssImportConditions, CssImportDependency, CssLayer};
 use crate::parser_and_generator::{
   CodeGenerationDataUnusedLocalIdent, CssParserAndGenerator, CssUsedExports,
 };
@@ -74,11 +76,11 @@ impl CssPlugin {
           meta_data.push(meta_data_str.0.as_str());
         }
 
-        Ok(
-          code_gen_result
-            .get(&SourceType::Css)
-            .map(|source| (CssModuleDebugInfo { module: *module }, source)),
-        )
+        Ok(code_gen_result.get(&SourceType::Css).map(|source| {
+          let source =
+            Self::wrap_in_import_conditions(source.clone(), Self::get_module_import_chain(*module));
+          (CssModuleDebugInfo { module: *module }, source)
+        }))
       })
       .collect::<Result<Vec<_>>>()?;
 
@@ -93,7 +95,7 @@ impl CssPlugin {
         |mut acc, (debug_info, cur_source)| {
           let (start, end) = Self::render_module_debug_info(compilation, &debug_info);
           acc.add(start);
-          acc.add(cur_source.clone());
+          acc.add(cur_source);
           acc.add(RawSource::from("\n"));
           acc.add(end);
           acc
@@ -124,6 +126,84 @@ impl CssPlugin {
     Ok(source)
   }
 
+  /// Returns the conditions of the `@import` chain the module of a css import is created for,
+  /// the conditions of the import itself followed by the ones its issuer is created for
+  fn get_import_chain(data: &ModuleFactoryCreateData) -> Option<&[CssImportConditions]> {
+    let import_chain = data
+      .dependency
+      .downcast_ref::<CssImportDependency>()?
+      .import_chain();
+    (!import_chain.is_empty()).then_some(import_chain)
+  }
+
+  /// Returns the conditions of the `@import` chain a css module was created for
+  fn get_module_import_chain(module: &dyn Module) -> &[CssImportConditions] {
+    module
+      .as_normal_module()
+      .and_then(|module| {
+        module
+          .parser_and_generator()
+          .downcast_ref::<CssParserAndGenerator>()
+      })
+      .map(|parser_and_generator| parser_and_generator.import_chain.as_slice())
+      .unwrap_or_default()
+  }
+
+  /// Formats an import chain like the identifier of webpack's `CssModule`, the layer, supports
+  /// and media of each import
+  fn import_chain_identifier(import_chain: &[CssImportConditions]) -> String {
+    import_chain
+      .iter()
+      .map(|conditions| {
+        let layer = match &conditions.layer {
+          Some(CssLayer::Anonymous) => "layer".to_string(),
+          Some(CssLayer::Named(layer)) => format!("layer({layer})"),
+          None => String::new(),
+        };
+        format!(
+          "{layer}|{}|{}",
+          conditions.supports.as_deref().unwrap_or_default(),
+          conditions.media.as_deref().unwrap_or_default()
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("|")
+  }
+
+  /// Wraps the source in `@media`, then `@supports`, then `@layer` for each import of the
+  /// chain, like `renderModule` of webpack's `CssModulesPlugin`
+  fn wrap_in_import_conditions(
+    source: BoxSource,
+    import_chain: &[CssImportConditions],
+  ) -> BoxSource {
+    import_chain.iter().fold(source, |source, conditions| {
+      let mut source = source;
+      if let Some(media) = &conditions.media {
+        source = Self::wrap_in_block(format!("@media {media}"), source);
+      }
+      if let Some(supports) = &conditions.supports {
+        source = Self::wrap_in_block(format!("@supports ({supports})"), source);
+      }
+      match &conditions.layer {
+        Some(CssLayer::Anonymous) => source = Self::wrap_in_block("@layer".to_string(), source),
+        Some(CssLayer::Named(layer)) => {
+          source = Self::wrap_in_block(format!("@layer {layer}"), source)
+        }
+        None => {}
+      }
+      source
+    })
+  }
+
+  fn wrap_in_block(prelude: String, source: BoxSource) -> BoxSource {
+    ConcatSource::new([
+      RawSource::from(format!("{prelude} {{\n")).boxed(),
+      source,
+      RawSource::from("\n}").boxed(),
+    ])
+    .boxed()
+  }
+
   fn render_module_debug_info(
     compilation: &Compilation,
     debug_info: &CssModuleDebugInfo,
@@ -175,6 +255,40 @@ async fn compilation(
     DependencyType::CssCompose,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::CssIcssImport,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryAfterResolve for CssPlugin)]
+async fn after_resolve(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+) -> Result<Option<bool>> {
+  if let Some(import_chain) = Self::get_import_chain(data) {
+    create_data.identifier_suffix = Some(Self::import_chain_identifier(import_chain));
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryModule for CssPlugin)]
+async fn nmf_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  _create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  if let Some(import_chain) = Self::get_import_chain(data)
+    && let Some(module) = module.as_normal_module_mut()
+    && let Some(parser_and_generator) = module
+      .parser_and_generator_mut()
+      .downcast_mut::<CssParserAndGenerator>()
+  {
+    parser_and_generator.import_chain = import_chain.to_vec();
+  }
   Ok(())
 }
 
@@ -239,12 +353,14 @@ async fn content_hash(
           .code_generation_results
           .get_hash(&m.identifier(), Some(&chunk.runtime)),
         compilation.chunk_graph.get_module_id(m.identifier()),
+        Self::get_module_import_chain(*m),
       )
     })
-    .for_each(|(current, id)| {
+    .for_each(|(current, id, import_chain)| {
       if let Some(current) = current {
         current.hash(&mut hasher);
         id.hash(&mut hasher);
+        import_chain.hash(&mut hasher);
       }
     });
 
@@ -411,6 +527,16 @@ impl Plugin for CssPlugin {
       .compiler_hooks
       .compilation
       .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .after_resolve
+      .tap(after_resolve::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(nmf_module::new(self));
     ctx
       .context
       .compilation_hooks
@@ -443,6 +569,9 @@ impl Plugin for CssPlugin {
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
+          remove_unused_rules: false,
+          import_chain: vec![],
+          rules: vec![],
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -469,6 +598,9 @@ impl Plugin for CssPlugin {
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
+          remove_unused_rules: g.remove_unused_rules.unwrap_or(false),
+          import_chain: vec![],
+          rules: vec![],
         }) as Box<dyn ParserAndGenerator>
       }),
     );
@@ -495,6 +627,9 @@ impl Plugin for CssPlugin {
           exports_only: g.exports_only.expect("should have exports_only"),
           named_exports: p.named_exports.expect("should have named_exports"),
           es_module: g.es_module.expect("should have es_module"),
+          remove_unused_rules: g.remove_unused_rules.unwrap_or(false),
+          import_chain: vec![],
+          rules: vec![],
         }) as Box<dyn ParserAndGenerator>
       }),
     );
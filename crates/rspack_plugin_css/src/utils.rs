@@ -412,3 +412,772 @@ pub fn replace_module_request_prefix<'s>(
     specifier
   }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CssTokenKind {
+  Ident,
+  /// A function name and its opening parenthesis, e.g. `calc(`
+  Function,
+  /// A `url()` with an unquoted url
+  Url,
+  AtKeyword,
+  /// A `#` followed by a name, e.g. an id selector or a hex color
+  Hash,
+  String,
+  Number,
+  LeftParenthesis,
+  RightParenthesis,
+  LeftCurlyBracket,
+  RightCurlyBracket,
+  Semicolon,
+  Colon,
+  Comma,
+  /// Any other character, e.g. the `.` of a class selector
+  Delim(char),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CssToken {
+  kind: CssTokenKind,
+  start: css_module_lexer::Pos,
+  end: css_module_lexer::Pos,
+}
+
+/// The tokens of a stylesheet, without white spaces and comments, read with the
+/// [css_module_lexer::Lexer] so that escapes, numbers and comments are handled like the
+/// dependencies of the module are.
+#[derive(Debug, Clone)]
+struct CssTokens<'s> {
+  lexer: css_module_lexer::Lexer<'s>,
+}
+
+fn is_ident_start(c: char) -> bool {
+  c == '_' || c.is_ascii_alphabetic() || !c.is_ascii()
+}
+
+fn is_valid_escape(c1: Option<char>, c2: Option<char>) -> bool {
+  c1 == Some('\\') && c2.is_some_and(|c| !matches!(c, '\n' | '\r' | '\x0C'))
+}
+
+/// Whether the lexer is at the start of an identifier, see
+/// https://drafts.csswg.org/css-syntax/#would-start-an-identifier
+fn starts_ident(lexer: &css_module_lexer::Lexer) -> bool {
+  match lexer.cur() {
+    Some('-') => {
+      lexer.peek().is_some_and(|c| is_ident_start(c) || c == '-')
+        || is_valid_escape(lexer.peek(), lexer.peek2())
+    }
+    Some(c) => is_ident_start(c) || is_valid_escape(Some(c), lexer.peek()),
+    None => false,
+  }
+}
+
+/// Whether the lexer is at the start of a number, see
+/// https://drafts.csswg.org/css-syntax/#starts-with-a-number
+fn starts_number(lexer: &css_module_lexer::Lexer) -> bool {
+  let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+  match lexer.cur() {
+    Some('+' | '-') => {
+      is_digit(lexer.peek()) || (lexer.peek() == Some('.') && is_digit(lexer.peek2()))
+    }
+    Some('.') => is_digit(lexer.peek()),
+    c => is_digit(c),
+  }
+}
+
+impl<'s> CssTokens<'s> {
+  fn new(source: &'s str) -> Self {
+    let mut lexer = css_module_lexer::Lexer::new(source);
+    lexer.consume();
+    Self { lexer }
+  }
+
+  fn slice(&self, token: &CssToken) -> &'s str {
+    self.lexer.slice(token.start, token.end).unwrap_or_default()
+  }
+
+  fn peek(&self) -> Option<CssToken> {
+    self.clone().next()
+  }
+
+  /// Consumes the current character, the lexer loses its position when consuming past the end
+  fn consume(&mut self) {
+    if self.lexer.cur().is_some() {
+      self.lexer.consume();
+    }
+  }
+
+  fn consume_string(&mut self, quote: char) {
+    self.consume();
+    loop {
+      match self.lexer.cur() {
+        Some(c) if c == quote => return self.consume(),
+        None | Some('\n' | '\r' | '\x0C') => return,
+        Some('\\') => {
+          self.consume();
+          self.consume();
+        }
+        Some(_) => self.consume(),
+      }
+    }
+  }
+
+  fn consume_url(&mut self) {
+    loop {
+      match self.lexer.cur() {
+        Some(')') => return self.consume(),
+        None => return,
+        Some('\\') => {
+          self.consume();
+          self.consume();
+        }
+        Some(_) => self.consume(),
+      }
+    }
+  }
+
+  /// Skips the tokens of the current statement up to its `;`, `{` or `}`, which is not
+  /// skipped. Returns the end of the last skipped token, or `end` if none is skipped.
+  fn skip_statement(&mut self, mut end: css_module_lexer::Pos) -> css_module_lexer::Pos {
+    loop {
+      let mut tokens = self.clone();
+      match tokens.next() {
+        Some(CssToken {
+          kind:
+            CssTokenKind::Semicolon | CssTokenKind::LeftCurlyBracket | CssTokenKind::RightCurlyBracket,
+          ..
+        })
+        | None => return end,
+        Some(token) => {
+          end = token.end;
+          *self = tokens;
+        }
+      }
+    }
+  }
+
+  /// Skips the tokens up to the end of the next block
+  fn skip_block(&mut self) {
+    let mut depth = 0usize;
+    for token in self.by_ref() {
+      match token.kind {
+        CssTokenKind::LeftCurlyBracket => depth += 1,
+        CssTokenKind::RightCurlyBracket if depth <= 1 => return,
+        CssTokenKind::RightCurlyBracket => depth -= 1,
+        _ => {}
+      }
+    }
+  }
+}
+
+impl Iterator for CssTokens<'_> {
+  type Item = CssToken;
+
+  fn next(&mut self) -> Option<CssToken> {
+    // stops at the end of the source as well
+    let _ = self.lexer.consume_white_space_and_comments();
+    let c = self.lexer.cur()?;
+    let start = self.lexer.cur_pos()?;
+    let kind = if starts_number(&self.lexer) {
+      let _ = self.lexer.consume_number();
+      if starts_ident(&self.lexer) {
+        let _ = self.lexer.consume_ident_sequence();
+      } else if self.lexer.cur() == Some('%') {
+        self.consume();
+      }
+      CssTokenKind::Number
+    } else if starts_ident(&self.lexer) {
+      let _ = self.lexer.consume_ident_sequence();
+      if self.lexer.cur() != Some('(') {
+        CssTokenKind::Ident
+      } else if self
+        .lexer
+        .slice(start, self.lexer.cur_pos()?)?
+        .eq_ignore_ascii_case("url")
+        && {
+          let mut lexer = self.lexer.clone();
+          lexer.consume();
+          let _ = lexer.consume_white_space_and_comments();
+          !matches!(lexer.cur(), Some('"' | '\''))
+        }
+      {
+        self.consume_url();
+        CssTokenKind::Url
+      } else {
+        self.consume();
+        CssTokenKind::Function
+      }
+    } else if matches!(c, '"' | '\'') {
+      self.consume_string(c);
+      CssTokenKind::String
+    } else {
+      self.consume();
+      match c {
+        '#'
+          if self
+            .lexer
+            .cur()
+            .is_some_and(|c| is_ident_start(c) || c.is_ascii_digit() || c == '-')
+            || is_valid_escape(self.lexer.cur(), self.lexer.peek()) =>
+        {
+          let _ = self.lexer.consume_ident_sequence();
+          CssTokenKind::Hash
+        }
+        '@' if starts_ident(&self.lexer) => {
+          let _ = self.lexer.consume_ident_sequence();
+          CssTokenKind::AtKeyword
+        }
+        '(' => CssTokenKind::LeftParenthesis,
+        ')' => CssTokenKind::RightParenthesis,
+        '{' => CssTokenKind::LeftCurlyBracket,
+        '}' => CssTokenKind::RightCurlyBracket,
+        ';' => CssTokenKind::Semicolon,
+        ':' => CssTokenKind::Colon,
+        ',' => CssTokenKind::Comma,
+        c => CssTokenKind::Delim(c),
+      }
+    };
+    Some(CssToken {
+      kind,
+      start,
+      end: self.lexer.cur_pos()?,
+    })
+  }
+}
+
+/// Finds the identifiers of `source` for which `is_symbol` returns true, e.g. the names
+/// declared in an ICSS `:import` block. Function names, class and id selectors, at-rule names
+/// and property names are not searched, and neither are the statements whose dependencies
+/// replace them: `@import` and `@value` rules, `:import` and `:export` blocks and `composes`
+/// declarations.
+pub fn find_icss_symbols(
+  source: &str,
+  is_symbol: impl Fn(&str) -> bool,
+) -> Vec<(&str, css_module_lexer::Range)> {
+  let mut tokens = CssTokens::new(source);
+  let mut symbols = vec![];
+  let mut depth = 0usize;
+  let mut prev: Option<CssToken> = None;
+  while let Some(token) = tokens.next() {
+    match token.kind {
+      CssTokenKind::LeftCurlyBracket => depth += 1,
+      CssTokenKind::RightCurlyBracket => depth = depth.saturating_sub(1),
+      CssTokenKind::AtKeyword if depth == 0 => {
+        let name = tokens.slice(&token);
+        if name.eq_ignore_ascii_case("@import") || name.eq_ignore_ascii_case("@value") {
+          tokens.skip_statement(token.end);
+        }
+      }
+      CssTokenKind::Colon
+        if depth == 0
+          && let Some(next) = tokens.peek()
+          && next.start == token.end
+          && matches!(
+            (next.kind, tokens.slice(&next)),
+            (CssTokenKind::Function, "import(") | (CssTokenKind::Ident, "export")
+          ) =>
+      {
+        tokens.skip_block();
+      }
+      CssTokenKind::Ident => {
+        let name = tokens.slice(&token);
+        let is_class =
+          prev.is_some_and(|prev| prev.kind == CssTokenKind::Delim('.') && prev.end == token.start);
+        let is_property = depth > 0
+          && prev.is_some_and(|prev| {
+            matches!(
+              prev.kind,
+              CssTokenKind::LeftCurlyBracket | CssTokenKind::Semicolon
+            )
+          })
+          && tokens
+            .peek()
+            .is_some_and(|next| next.kind == CssTokenKind::Colon);
+        if is_property && (name == "composes" || name == "compose-with") {
+          tokens.skip_statement(token.end);
+        } else if !is_class && !is_property && is_symbol(name) {
+          symbols.push((name, css_module_lexer::Range::new(token.start, token.end)));
+        }
+      }
+      _ => {}
+    }
+    prev = Some(token);
+  }
+  symbols
+}
+
+static VALUE_IMPORT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r#"^([\s\S]+?)\s+from\s+("[^"]*"|'[^']*'|[\w-]+)$"#).expect("Invalid regexp")
+});
+static VALUE_IMPORT_NAME_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^([\w-]+)(?:\s+as\s+([\w-]+))?$").expect("Invalid regexp"));
+static VALUE_DECLARATION_REGEX: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"^([\w-]+)\s*:?\s*([\s\S]*)$").expect("Invalid regexp"));
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum CssValue<'s> {
+  /// `@value primary: #BF4040;`
+  Declaration { name: &'s str, value: &'s str },
+  /// `@value primary, secondary as accent from "./colors.css";`, the names are pairs of the
+  /// imported name and the local name
+  Import {
+    names: Vec<(&'s str, &'s str)>,
+    from: &'s str,
+  },
+}
+
+fn parse_css_value(params: &str) -> Option<CssValue> {
+  if let Some(captures) = VALUE_IMPORT_REGEX.captures(params) {
+    let names = captures
+      .get(1)?
+      .as_str()
+      .trim()
+      .trim_start_matches('(')
+      .trim_end_matches(')')
+      .split(',')
+      .map(|name| {
+        let captures = VALUE_IMPORT_NAME_REGEX.captures(name.trim())?;
+        let imported = captures.get(1)?.as_str();
+        Some((
+          imported,
+          captures.get(2).map_or(imported, |local| local.as_str()),
+        ))
+      })
+      .collect::<Option<Vec<_>>>();
+    if let Some(names) = names {
+      return Some(CssValue::Import {
+        names,
+        from: captures.get(2)?.as_str(),
+      });
+    }
+  }
+  let captures = VALUE_DECLARATION_REGEX.captures(params)?;
+  Some(CssValue::Declaration {
+    name: captures.get(1)?.as_str(),
+    value: captures.get(2)?.as_str().trim(),
+  })
+}
+
+/// Finds the top level `@value` rules of CSS Modules and the ranges they span, including the
+/// trailing semicolon.
+pub fn find_css_values(source: &str) -> Vec<(CssValue, css_module_lexer::Range)> {
+  let mut tokens = CssTokens::new(source);
+  let mut values = vec![];
+  let mut depth = 0usize;
+  while let Some(token) = tokens.next() {
+    match token.kind {
+      CssTokenKind::LeftCurlyBracket => depth += 1,
+      CssTokenKind::RightCurlyBracket => depth = depth.saturating_sub(1),
+      CssTokenKind::AtKeyword
+        if depth == 0 && tokens.slice(&token).eq_ignore_ascii_case("@value") =>
+      {
+        let params_end = tokens.skip_statement(token.end);
+        let end = match tokens.peek() {
+          Some(next) if next.kind == CssTokenKind::Semicolon => {
+            tokens.next();
+            next.end
+          }
+          _ => params_end,
+        };
+        if let Some(value) = source
+          .get(token.end as usize..params_end as usize)
+          .and_then(|params| parse_css_value(params.trim()))
+        {
+          values.push((value, css_module_lexer::Range::new(token.start, end)));
+        }
+      }
+      _ => {}
+    }
+  }
+  values
+}
+
+/// A rule of a stylesheet, see [find_css_rules]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssRule {
+  /// From the start of the prelude to the end of the block
+  pub range: css_module_lexer::Range,
+  pub kind: CssRuleKind,
+  /// Whether the block has declarations or statements other than nested rules
+  pub has_declarations: bool,
+  pub rules: Vec<CssRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssRuleKind {
+  /// A style rule with the class and id selectors of each selector of its selector list, and
+  /// the ones inside parentheses, e.g. of `:not(.a)`
+  Style {
+    selectors: Vec<Vec<css_module_lexer::Range>>,
+    nested: Vec<css_module_lexer::Range>,
+  },
+  /// A `@media`, `@supports`, `@container` or `@layer` block
+  Grouping,
+  /// Any other at-rule, e.g. `@font-face` or `@keyframes`
+  Other,
+}
+
+/// The statement [find_css_rules] is reading
+struct CssStatement<'s> {
+  start: Option<css_module_lexer::Pos>,
+  at_rule: Option<&'s str>,
+  selectors: Vec<Vec<css_module_lexer::Range>>,
+  nested: Vec<css_module_lexer::Range>,
+  parentheses: usize,
+}
+
+impl CssStatement<'_> {
+  fn new() -> Self {
+    Self {
+      start: None,
+      at_rule: None,
+      selectors: vec![vec![]],
+      nested: vec![],
+      parentheses: 0,
+    }
+  }
+
+  fn add_selector(&mut self, range: css_module_lexer::Range) {
+    if self.parentheses > 0 {
+      self.nested.push(range);
+    } else if let Some(selector) = self.selectors.last_mut() {
+      selector.push(range);
+    }
+  }
+}
+
+/// Finds the rules of `source`, with the class and id selectors of its style rules, see
+/// [find_unused_rules]
+pub fn find_css_rules(source: &str) -> Vec<CssRule> {
+  let mut tokens = CssTokens::new(source);
+  let mut rules = vec![];
+  // the rules whose block is not closed yet, the innermost last
+  let mut open_rules: Vec<CssRule> = vec![];
+  let mut statement = CssStatement::new();
+  let close_rule = |open_rules: &mut Vec<CssRule>, rules: &mut Vec<CssRule>, end| {
+    if let Some(mut rule) = open_rules.pop() {
+      rule.range.end = end;
+      open_rules
+        .last_mut()
+        .map_or(&mut *rules, |parent| &mut parent.rules)
+        .push(rule);
+    }
+  };
+  while let Some(token) = tokens.next() {
+    match token.kind {
+      CssTokenKind::Semicolon | CssTokenKind::RightCurlyBracket => {
+        if statement.start.is_some()
+          && let Some(rule) = open_rules.last_mut()
+        {
+          rule.has_declarations = true;
+        }
+        if token.kind == CssTokenKind::RightCurlyBracket {
+          close_rule(&mut open_rules, &mut rules, token.end);
+        }
+        statement = CssStatement::new();
+      }
+      CssTokenKind::LeftCurlyBracket => {
+        let kind = match statement.at_rule {
+          Some(name)
+            if ["@media", "@supports", "@container", "@layer"]
+              .iter()
+              .any(|grouping| name.eq_ignore_ascii_case(grouping)) =>
+          {
+            CssRuleKind::Grouping
+          }
+          Some(_) => CssRuleKind::Other,
+          None => CssRuleKind::Style {
+            selectors: statement.selectors,
+            nested: statement.nested,
+          },
+        };
+        open_rules.push(CssRule {
+          range: css_module_lexer::Range::new(statement.start.unwrap_or(token.start), token.end),
+          kind,
+          has_declarations: false,
+          rules: vec![],
+        });
+        statement = CssStatement::new();
+      }
+      _ => {
+        let start = *statement.start.get_or_insert(token.start);
+        match token.kind {
+          CssTokenKind::AtKeyword if start == token.start => {
+            statement.at_rule = Some(tokens.slice(&token));
+          }
+          CssTokenKind::Delim('.')
+            if let Some(next) = tokens.peek()
+              && next.kind == CssTokenKind::Ident
+              && next.start == token.end =>
+          {
+            tokens.next();
+            statement.add_selector(css_module_lexer::Range::new(token.start, next.end));
+          }
+          CssTokenKind::Hash => {
+            statement.add_selector(css_module_lexer::Range::new(token.start, token.end));
+          }
+          CssTokenKind::Function | CssTokenKind::LeftParenthesis => statement.parentheses += 1,
+          CssTokenKind::RightParenthesis => {
+            statement.parentheses = statement.parentheses.saturating_sub(1);
+          }
+          CssTokenKind::Comma if statement.parentheses == 0 => statement.selectors.push(vec![]),
+          _ => {}
+        }
+      }
+    }
+  }
+  // the blocks left open are closed at the end of the source
+  if statement.start.is_some()
+    && let Some(rule) = open_rules.last_mut()
+  {
+    rule.has_declarations = true;
+  }
+  while !open_rules.is_empty() {
+    close_rule(&mut open_rules, &mut rules, source.len() as u32);
+  }
+  rules
+}
+
+/// Whether the selector references a local ident and none of the ones it references is used
+fn is_unused_selector(
+  selector: &[css_module_lexer::Range],
+  is_used: &impl Fn(&css_module_lexer::Range) -> Option<bool>,
+) -> bool {
+  let mut references_unused = false;
+  for range in selector {
+    match is_used(range) {
+      Some(true) => return false,
+      Some(false) => references_unused = true,
+      None => {}
+    }
+  }
+  references_unused
+}
+
+/// Returns whether `rule` is removed, the nested rules of a kept rule may be removed on their own
+fn find_unused_rule(
+  rule: &CssRule,
+  is_used: &impl Fn(&css_module_lexer::Range) -> Option<bool>,
+  unused_rules: &mut Vec<css_module_lexer::Range>,
+) -> bool {
+  let unused_rules_len = unused_rules.len();
+  let mut all_nested_unused = !rule.rules.is_empty();
+  for nested_rule in &rule.rules {
+    all_nested_unused &= find_unused_rule(nested_rule, is_used, unused_rules);
+  }
+  let is_unused = match &rule.kind {
+    // `:not(.unused)` still matches
+    CssRuleKind::Style { selectors, nested } => {
+      nested.iter().all(|range| is_used(range).is_none())
+        && selectors
+          .iter()
+          .all(|selector| is_unused_selector(selector, is_used))
+    }
+    CssRuleKind::Grouping => all_nested_unused && !rule.has_declarations,
+    CssRuleKind::Other => false,
+  };
+  if is_unused {
+    unused_rules.truncate(unused_rules_len);
+    unused_rules.push(rule.range.clone());
+  }
+  is_unused
+}
+
+/// Finds the style rules whose selectors all reference unused local idents, together with the
+/// grouping rules left empty by removing them. `is_used` returns whether the local ident of a
+/// class or id selector is used, or `None` if the selector is not a local ident of the module.
+pub fn find_unused_rules(
+  rules: &[CssRule],
+  is_used: impl Fn(&css_module_lexer::Range) -> Option<bool>,
+) -> Vec<css_module_lexer::Range> {
+  let mut unused_rules = vec![];
+  for rule in rules {
+    find_unused_rule(rule, &is_used, &mut unused_rules);
+  }
+  unused_rules
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn symbol_starts(source: &str, symbols: &[(&str, css_module_lexer::Range)]) -> Vec<usize> {
+    symbols
+      .iter()
+      .map(|(name, range)| {
+        assert_eq!(&source[range.start as usize..range.end as usize], *name);
+        range.start as usize
+      })
+      .collect()
+  }
+
+  #[test]
+  fn find_icss_symbols_in_values_and_selectors() {
+    let source = ".a { color: primary; background: url(primary.png) } /* primary */ \
+                  .primary { content: \"primary\" } @media small { .b { margin: primary-x } }";
+    let symbols = find_icss_symbols(source, |word| word == "primary" || word == "small");
+    let words = symbols.iter().map(|(word, _)| *word).collect::<Vec<_>>();
+    assert_eq!(words, vec!["primary", "small"]);
+    assert_eq!(symbol_starts(source, &symbols)[0], 12);
+  }
+
+  #[test]
+  fn find_icss_symbols_not_in_property_names() {
+    let source = ".a { color: color; --color : color } color:hover { margin: 0 }";
+    let symbols = find_icss_symbols(source, |word| word == "color" || word == "--color");
+    assert_eq!(
+      symbol_starts(source, &symbols),
+      vec![
+        12,
+        29,
+        source.find("color:hover").expect("should have selector")
+      ]
+    );
+  }
+
+  #[test]
+  fn find_icss_symbols_not_in_replaced_statements() {
+    let source = ":import(\"./a.css\") { primary: primary } :export { brand: primary }\n\
+                  @import \"./b.css\" primary; @value secondary: primary;\n\
+                  .a { composes: primary from \"./c.css\"; color: primary }";
+    let symbols = find_icss_symbols(source, |word| word == "primary");
+    assert_eq!(
+      symbol_starts(source, &symbols),
+      vec![source.rfind("primary").expect("should have value")]
+    );
+  }
+
+  #[test]
+  fn find_icss_symbols_with_escaped_idents() {
+    let source = ".a { color: primary\\.x; margin: primary\\ 0 primary }";
+    let symbols = find_icss_symbols(source, |word| word == "primary");
+    assert_eq!(
+      symbol_starts(source, &symbols),
+      vec![source.rfind("primary").expect("should have value")]
+    );
+  }
+
+  #[test]
+  fn find_css_values_declarations_and_imports() {
+    let source = "@value primary: #BF4040;\n@value small (max-width: 599px);\n\
+                  @value secondary, accent as brand from \"./colors.css\";\n\
+                  .a { color: primary; }\n@media small { .b { @value nested: 1px; } }";
+    let values = find_css_values(source);
+    assert_eq!(
+      values.iter().map(|(value, _)| value).collect::<Vec<_>>(),
+      vec![
+        &CssValue::Declaration {
+          name: "primary",
+          value: "#BF4040",
+        },
+        &CssValue::Declaration {
+          name: "small",
+          value: "(max-width: 599px)",
+        },
+        &CssValue::Import {
+          names: vec![("secondary", "secondary"), ("accent", "brand")],
+          from: "\"./colors.css\"",
+        },
+      ]
+    );
+    let range = &values[0].1;
+    assert_eq!(
+      &source[range.start as usize..range.end as usize],
+      "@value primary: #BF4040;"
+    );
+  }
+
+  #[test]
+  fn find_css_values_with_unterminated_strings() {
+    let source = "@value primary: \"#BF4040";
+    let values = find_css_values(source);
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].1.end as usize, source.len());
+    assert_eq!(find_css_values("@value a: 1; \"\\").len(), 1);
+  }
+
+  #[test]
+  fn find_css_values_with_uppercase_at_rules() {
+    let values = find_css_values("@VALUE primary: #BF4040;\n@Value small: 1px;");
+    assert_eq!(
+      values.iter().map(|(value, _)| value).collect::<Vec<_>>(),
+      vec![
+        &CssValue::Declaration {
+          name: "primary",
+          value: "#BF4040",
+        },
+        &CssValue::Declaration {
+          name: "small",
+          value: "1px",
+        },
+      ]
+    );
+  }
+
+  fn removed_rules<'s>(source: &'s str, unused: &[&str], used: &[&str]) -> Vec<&'s str> {
+    let slice = |range: &css_module_lexer::Range| &source[range.start as usize..range.end as usize];
+    find_unused_rules(&find_css_rules(source), |range| {
+      let name = &slice(range)[1..];
+      if used.contains(&name) {
+        Some(true)
+      } else if unused.contains(&name) {
+        Some(false)
+      } else {
+        None
+      }
+    })
+    .iter()
+    .map(slice)
+    .collect()
+  }
+
+  #[test]
+  fn find_unused_rules_with_unused_local_idents() {
+    let source = ".used { color: red }\n.unused, div .unused-b { color: blue }\n\
+                  .unused, div { color: green }\n.unused .used { color: gray }\n\
+                  div:not(.unused) { margin: 0 }\n#unused-id { color: #fff }";
+    assert_eq!(
+      removed_rules(source, &["unused", "unused-b", "unused-id"], &["used"]),
+      vec![
+        ".unused, div .unused-b { color: blue }",
+        "#unused-id { color: #fff }"
+      ]
+    );
+  }
+
+  #[test]
+  fn find_unused_rules_with_escaped_idents() {
+    let source = ".\\31 23-a { color: red } .a\\.b { color: blue } .a { color: green }";
+    assert_eq!(
+      removed_rules(source, &["\\31 23-a", "a\\.b"], &["a"]),
+      vec![".\\31 23-a { color: red }", ".a\\.b { color: blue }"]
+    );
+  }
+
+  #[test]
+  fn find_unused_rules_in_media_and_supports() {
+    let source = "@media (min-width: 1px) { .unused { color: pink } }\n\
+                  @media print { .unused { color: black } .used { color: white } }\n\
+                  @supports (display: grid) { @media screen { .unused { display: grid } } }\n\
+                  @supports (display: flex) { .unused { display: flex } --x: 1; }\n\
+                  @font-face { font-family: a } @keyframes unused { to { color: red } }";
+    assert_eq!(
+      removed_rules(source, &["unused"], &["used"]),
+      vec![
+        "@media (min-width: 1px) { .unused { color: pink } }",
+        ".unused { color: black }",
+        "@supports (display: grid) { @media screen { .unused { display: grid } } }",
+        ".unused { display: flex }",
+      ]
+    );
+  }
+
+  #[test]
+  fn find_css_rules_of_unclosed_blocks() {
+    let source = "@media print { .a { color: red }";
+    let rules = find_css_rules(source);
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0].range.end as usize, source.len());
+    assert_eq!(rules[0].rules[0].range.end, 32);
+  }
+}
@@ -0,0 +1,6 @@
+mod plugin;
+mod server;
+mod websocket;
+
+pub use plugin::DevServerPlugin;
+pub use server::{DevServer, DevServerOptions, WEBSOCKET_PATH};
@@ -0,0 +1,69 @@
+use std::{fmt::Debug, sync::Arc};
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilerAfterEmit, CompilerOptions, Plugin, PluginContext,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::server::DevServer;
+
+/// Keeps a [DevServer] in sync with the compiler, the served assets and the notifications
+/// of the clients are updated after the assets of every [rspack_core::Compiler::build] and
+/// [rspack_core::Compiler::rebuild] got emitted.
+#[plugin]
+#[derive(Debug)]
+pub struct DevServerPlugin {
+  server: Arc<DevServer>,
+}
+
+impl DevServerPlugin {
+  pub fn new(server: Arc<DevServer>) -> Self {
+    Self::new_inner(server)
+  }
+}
+
+#[plugin_hook(CompilerAfterEmit for DevServerPlugin)]
+async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let assets = compilation
+    .assets()
+    .iter()
+    .filter_map(|(filename, asset)| {
+      let filename = filename
+        .split_once('?')
+        .map_or(filename.as_str(), |(filename, _query)| filename);
+      Some((filename.to_string(), asset.get_source()?.clone()))
+    })
+    .collect();
+  let errors = compilation
+    .get_errors_sorted()
+    .map(|error| error.message())
+    .collect();
+  let warnings = compilation
+    .get_warnings_sorted()
+    .map(|warning| warning.message())
+    .collect();
+  self
+    .server
+    .update(assets, compilation.get_hash(), errors, warnings);
+  Ok(())
+}
+
+impl Plugin for DevServerPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DevServerPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compiler_hooks
+      .after_emit
+      .tap(after_emit::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,555 @@
+use std::{
+  fmt::Debug,
+  net::{Ipv4Addr, SocketAddr},
+  sync::{Arc, Mutex},
+};
+
+use rspack_core::rspack_sources::BoxSource;
+use rspack_error::{error, Result};
+use rustc_hash::FxHashMap;
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  sync::{broadcast, mpsc, watch},
+};
+
+use crate::websocket::{
+  accept_key, encode_frame, read_frame, OPCODE_CLOSE, OPCODE_PING, OPCODE_PONG, OPCODE_TEXT,
+};
+
+/// Path of the websocket the clients receive the HMR notifications from
+pub const WEBSOCKET_PATH: &str = "/ws";
+const MAX_REQUEST_HEAD_SIZE: usize = 8 * 1024;
+
+const NOT_FOUND_RESPONSE: &[u8] =
+  b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+const METHOD_NOT_ALLOWED_RESPONSE: &[u8] =
+  b"HTTP/1.1 405 Method Not Allowed\r\nallow: GET, HEAD\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+const BAD_REQUEST_RESPONSE: &[u8] =
+  b"HTTP/1.1 400 Bad Request\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+pub struct DevServerOptions {
+  /// Address the server listens to, port `0` picks a free one
+  pub listen: SocketAddr,
+  /// Headers added to all the responses of the assets
+  pub headers: Vec<(String, String)>,
+  /// Asset served for the `text/html` requests which don't match any asset, e.g. `index.html`,
+  /// for the applications using the HTML5 History API
+  pub history_api_fallback: Option<String>,
+}
+
+impl Debug for DevServerOptions {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("DevServerOptions")
+      .field("listen", &self.listen)
+      .field("headers", &self.headers)
+      .field("history_api_fallback", &self.history_api_fallback)
+      .finish()
+  }
+}
+
+impl Default for DevServerOptions {
+  fn default() -> Self {
+    Self {
+      listen: SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+      headers: Default::default(),
+      history_api_fallback: None,
+    }
+  }
+}
+
+#[derive(Default)]
+struct State {
+  assets: FxHashMap<String, BoxSource>,
+  /// Notifications of the last compilation, sent to the clients once they connect
+  messages: Arc<[String]>,
+}
+
+struct Shared {
+  state: Mutex<State>,
+  headers: Vec<(String, String)>,
+  history_api_fallback: Option<String>,
+  updates: broadcast::Sender<Arc<[String]>>,
+}
+
+/// A development server serving the emitted assets from memory, and notifying its clients
+/// of every compilation over a websocket at [WEBSOCKET_PATH].
+///
+/// The notifications use the messages of webpack-dev-server, so its client runtime can
+/// be used: a `hash` message, followed by `errors`, `warnings` or `ok`.
+pub struct DevServer {
+  shared: Arc<Shared>,
+  url_base: String,
+  /// Dropping it stops the server and closes all connections
+  _closed: watch::Sender<()>,
+}
+
+impl Debug for DevServer {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("DevServer")
+      .field("url_base", &self.url_base)
+      .finish()
+  }
+}
+
+impl DevServer {
+  pub async fn listen(options: DevServerOptions) -> Result<Self> {
+    let listener = TcpListener::bind(options.listen)
+      .await
+      .map_err(|e| error!("Failed to start the dev server: {e}"))?;
+    let addr = listener
+      .local_addr()
+      .map_err(|e| error!("Failed to start the dev server: {e}"))?;
+    let url_base = if addr.ip().is_unspecified() {
+      format!("http://localhost:{}", addr.port())
+    } else {
+      format!("http://{addr}")
+    };
+
+    let (updates, _) = broadcast::channel(16);
+    let shared = Arc::new(Shared {
+      state: Default::default(),
+      headers: options.headers,
+      history_api_fallback: options
+        .history_api_fallback
+        .map(|fallback| fallback.trim_start_matches('/').to_string()),
+      updates,
+    });
+    let (closed_tx, closed) = watch::channel(());
+    tokio::spawn(accept(listener, shared.clone(), closed));
+
+    Ok(Self {
+      shared,
+      url_base,
+      _closed: closed_tx,
+    })
+  }
+
+  /// The origin of the server, e.g. `http://127.0.0.1:8080`.
+  pub fn url_base(&self) -> &str {
+    &self.url_base
+  }
+
+  /// Replace the served assets with the ones of a new compilation, and notify the clients.
+  pub fn update(
+    &self,
+    assets: FxHashMap<String, BoxSource>,
+    hash: Option<&str>,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+  ) {
+    let messages: Arc<[String]> = notifications(hash, errors, warnings).into();
+    {
+      let mut state = self.shared.state.lock().expect("should lock state");
+      state.assets = assets;
+      state.messages = messages.clone();
+    }
+    // no receivers when no client is connected
+    let _ = self.shared.updates.send(messages);
+  }
+}
+
+fn notifications(hash: Option<&str>, errors: Vec<String>, warnings: Vec<String>) -> Vec<String> {
+  let message = |ty: &str, data: Option<serde_json::Value>| match data {
+    Some(data) => format!(r#"{{"type":"{ty}","data":{data}}}"#),
+    None => format!(r#"{{"type":"{ty}"}}"#),
+  };
+  let mut messages = vec![];
+  if let Some(hash) = hash {
+    messages.push(message("hash", Some(hash.into())));
+  }
+  if !errors.is_empty() {
+    messages.push(message("errors", Some(errors.into())));
+  } else if !warnings.is_empty() {
+    messages.push(message("warnings", Some(warnings.into())));
+  } else {
+    messages.push(message("ok", None));
+  }
+  messages
+}
+
+async fn accept(listener: TcpListener, shared: Arc<Shared>, mut closed: watch::Receiver<()>) {
+  loop {
+    tokio::select! {
+      accepted = listener.accept() => {
+        if let Ok((stream, _)) = accepted {
+          tokio::spawn(serve(stream, shared.clone(), closed.clone()));
+        }
+      }
+      _ = closed.changed() => return,
+    }
+  }
+}
+
+struct Request {
+  method: String,
+  path: String,
+  /// Headers with lowercase names
+  headers: FxHashMap<String, String>,
+}
+
+impl Request {
+  fn header(&self, name: &str) -> Option<&str> {
+    self.headers.get(name).map(|value| value.as_str())
+  }
+}
+
+async fn serve(mut stream: TcpStream, shared: Arc<Shared>, closed: watch::Receiver<()>) {
+  let Some(request) = read_request(&mut stream).await else {
+    let _ = stream.write_all(BAD_REQUEST_RESPONSE).await;
+    return;
+  };
+  if request.method != "GET" && request.method != "HEAD" {
+    let _ = stream.write_all(METHOD_NOT_ALLOWED_RESPONSE).await;
+    return;
+  }
+  if request.path == WEBSOCKET_PATH {
+    serve_websocket(stream, &request, shared, closed).await;
+    return;
+  }
+
+  let Some((filename, source)) = find_asset(&shared, &request) else {
+    let _ = stream.write_all(NOT_FOUND_RESPONSE).await;
+    return;
+  };
+  let body = source.buffer();
+  let content_type = mime_guess::from_path(&filename).first_or_octet_stream();
+  let mut response = format!(
+    "HTTP/1.1 200 OK\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\nconnection: close\r\n",
+    body.len()
+  );
+  for (name, value) in &shared.headers {
+    response.push_str(&format!("{name}: {value}\r\n"));
+  }
+  response.push_str("\r\n");
+  let mut response = response.into_bytes();
+  if request.method == "GET" {
+    response.extend_from_slice(&body);
+  }
+  let _ = stream.write_all(&response).await;
+}
+
+fn find_asset(shared: &Shared, request: &Request) -> Option<(String, BoxSource)> {
+  let state = shared.state.lock().expect("should lock state");
+  let filename = request.path.trim_start_matches('/');
+  let filename = if filename.is_empty() || filename.ends_with('/') {
+    format!("{filename}index.html")
+  } else {
+    filename.to_string()
+  };
+  if let Some(source) = state.assets.get(&filename) {
+    return Some((filename, source.clone()));
+  }
+
+  let accepts_html = request
+    .header("accept")
+    .is_some_and(|accept| accept.contains("text/html"));
+  let fallback = shared
+    .history_api_fallback
+    .as_ref()
+    .filter(|_| accepts_html)?;
+  let source = state.assets.get(fallback)?;
+  Some((fallback.clone(), source.clone()))
+}
+
+async fn serve_websocket(
+  mut stream: TcpStream,
+  request: &Request,
+  shared: Arc<Shared>,
+  mut closed: watch::Receiver<()>,
+) {
+  let (Some(key), Some(true)) = (
+    request.header("sec-websocket-key"),
+    request
+      .header("upgrade")
+      .map(|upgrade| upgrade.eq_ignore_ascii_case("websocket")),
+  ) else {
+    let _ = stream.write_all(BAD_REQUEST_RESPONSE).await;
+    return;
+  };
+  let response = format!(
+    "HTTP/1.1 101 Switching Protocols\r\nupgrade: websocket\r\nconnection: Upgrade\r\nsec-websocket-accept: {}\r\n\r\n",
+    accept_key(key)
+  );
+  let _ = stream.set_nodelay(true);
+  if stream.write_all(response.as_bytes()).await.is_err() {
+    return;
+  }
+
+  // subscribe before reading the last notifications, so no compilation is missed
+  let mut updates = shared.updates.subscribe();
+  let messages = shared
+    .state
+    .lock()
+    .expect("should lock state")
+    .messages
+    .clone();
+  let (mut reader, mut writer) = stream.into_split();
+  if send_messages(&mut writer, &messages).await.is_err() {
+    return;
+  }
+
+  // `read_frame` isn't cancel safe, a frame partially read when another branch of the
+  // `select!` completes would be lost, so the frames are read by a dedicated task
+  let (frames_tx, mut frames) = mpsc::channel(1);
+  let read_frames = tokio::spawn(async move {
+    while let Some(frame) = read_frame(&mut reader).await {
+      if frames_tx.send(frame).await.is_err() {
+        return;
+      }
+    }
+  });
+
+  loop {
+    tokio::select! {
+      update = updates.recv() => {
+        let messages = match update {
+          Ok(messages) => messages,
+          // only the notifications of the latest compilation matter
+          Err(broadcast::error::RecvError::Lagged(_)) => shared
+            .state
+            .lock()
+            .expect("should lock state")
+            .messages
+            .clone(),
+          Err(broadcast::error::RecvError::Closed) => break,
+        };
+        if send_messages(&mut writer, &messages).await.is_err() {
+          break;
+        }
+      }
+      frame = frames.recv() => {
+        let Some(frame) = frame else {
+          break;
+        };
+        match frame.opcode {
+          OPCODE_PING => {
+            if writer.write_all(&encode_frame(OPCODE_PONG, &frame.payload)).await.is_err() {
+              break;
+            }
+          }
+          OPCODE_CLOSE => {
+            let _ = writer.write_all(&encode_frame(OPCODE_CLOSE, &frame.payload)).await;
+            break;
+          }
+          _ => {}
+        }
+      }
+      _ = closed.changed() => {
+        let _ = writer.write_all(&encode_frame(OPCODE_CLOSE, &[])).await;
+        break;
+      }
+    }
+  }
+  read_frames.abort();
+}
+
+async fn send_messages(
+  writer: &mut tokio::net::tcp::OwnedWriteHalf,
+  messages: &[String],
+) -> std::io::Result<()> {
+  for message in messages {
+    writer
+      .write_all(&encode_frame(OPCODE_TEXT, message.as_bytes()))
+      .await?;
+  }
+  Ok(())
+}
+
+/// Read the head of a request, the query of the path is dropped.
+async fn read_request(stream: &mut TcpStream) -> Option<Request> {
+  let mut head = Vec::new();
+  let mut buf = [0; 1024];
+  while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+    let n = stream.read(&mut buf).await.ok()?;
+    if n == 0 || head.len() + n > MAX_REQUEST_HEAD_SIZE {
+      return None;
+    }
+    head.extend_from_slice(&buf[..n]);
+  }
+  let head = std::str::from_utf8(&head).ok()?;
+  let mut lines = head.lines();
+  let mut request_line = lines.next()?.split_whitespace();
+  let method = request_line.next()?.to_string();
+  let target = request_line.next()?;
+  let path = target.split(['?', '#']).next()?;
+  let path = urlencoding::decode(path).ok()?.into_owned();
+  let headers = lines
+    .take_while(|line| !line.is_empty())
+    .filter_map(|line| {
+      let (name, value) = line.split_once(':')?;
+      Some((name.trim().to_ascii_lowercase(), value.trim().to_string()))
+    })
+    .collect();
+  Some(Request {
+    method,
+    path,
+    headers,
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_core::rspack_sources::{RawSource, SourceExt};
+
+  use super::*;
+
+  async fn request(server: &DevServer, head: &str) -> String {
+    let addr = server.url_base().trim_start_matches("http://");
+    let mut stream = TcpStream::connect(addr).await.expect("should connect");
+    stream
+      .write_all(format!("{head}\r\nHost: {addr}\r\n\r\n").as_bytes())
+      .await
+      .expect("should send request");
+    let mut response = String::new();
+    stream
+      .read_to_string(&mut response)
+      .await
+      .expect("should read response");
+    response
+  }
+
+  #[tokio::test]
+  async fn test_serve_assets() {
+    let server = DevServer::listen(DevServerOptions {
+      headers: vec![("x-served-by".to_string(), "rspack".to_string())],
+      history_api_fallback: Some("/index.html".to_string()),
+      ..Default::default()
+    })
+    .await
+    .expect("should listen");
+    server.update(
+      FxHashMap::from_iter([
+        (
+          "index.html".to_string(),
+          RawSource::from("<html></html>").boxed(),
+        ),
+        ("main.js".to_string(), RawSource::from("main()").boxed()),
+      ]),
+      Some("abc"),
+      vec![],
+      vec![],
+    );
+
+    let response = request(&server, "GET /main.js?v=1 HTTP/1.1").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("content-type: application/javascript\r\n"));
+    assert!(response.contains("x-served-by: rspack\r\n"));
+    assert!(response.ends_with("\r\n\r\nmain()"));
+
+    let response = request(&server, "HEAD /main.js HTTP/1.1").await;
+    assert!(response.contains("content-length: 6\r\n"));
+    assert!(response.ends_with("\r\n\r\n"));
+
+    let response = request(&server, "GET / HTTP/1.1").await;
+    assert!(response.ends_with("<html></html>"));
+
+    let response = request(&server, "GET /about HTTP/1.1\r\nAccept: text/html").await;
+    assert!(response.ends_with("<html></html>"));
+
+    let response = request(&server, "GET /about HTTP/1.1").await;
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    let response = request(&server, "POST /main.js HTTP/1.1").await;
+    assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+  }
+
+  async fn read_message(stream: &mut TcpStream) -> String {
+    let mut head = [0; 2];
+    stream
+      .read_exact(&mut head)
+      .await
+      .expect("should read frame");
+    assert_eq!(head[0], 0x80 | OPCODE_TEXT);
+    let mut payload = vec![0; head[1] as usize];
+    stream
+      .read_exact(&mut payload)
+      .await
+      .expect("should read frame");
+    String::from_utf8(payload).expect("should be utf8")
+  }
+
+  #[tokio::test]
+  async fn test_websocket_notifications() {
+    let server = DevServer::listen(Default::default())
+      .await
+      .expect("should listen");
+    server.update(Default::default(), Some("abc"), vec![], vec![]);
+
+    let addr = server.url_base().trim_start_matches("http://").to_string();
+    let mut stream = TcpStream::connect(&addr).await.expect("should connect");
+    stream
+      .write_all(
+        format!(
+          "GET {WEBSOCKET_PATH} HTTP/1.1\r\nHost: {addr}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n"
+        )
+        .as_bytes(),
+      )
+      .await
+      .expect("should send request");
+    let expected = "HTTP/1.1 101 Switching Protocols\r\nupgrade: websocket\r\nconnection: Upgrade\r\nsec-websocket-accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n\r\n";
+    let mut response = vec![0; expected.len()];
+    stream
+      .read_exact(&mut response)
+      .await
+      .expect("should read response");
+    assert_eq!(response, expected.as_bytes());
+
+    // the notifications of the last compilation
+    assert_eq!(
+      read_message(&mut stream).await,
+      r#"{"type":"hash","data":"abc"}"#
+    );
+    assert_eq!(read_message(&mut stream).await, r#"{"type":"ok"}"#);
+
+    server.update(
+      Default::default(),
+      Some("def"),
+      vec!["Module not found".to_string()],
+      vec![],
+    );
+    assert_eq!(
+      read_message(&mut stream).await,
+      r#"{"type":"hash","data":"def"}"#
+    );
+    assert_eq!(
+      read_message(&mut stream).await,
+      r#"{"type":"errors","data":["Module not found"]}"#
+    );
+
+    server.update(
+      Default::default(),
+      Some("ghi"),
+      vec![],
+      vec!["Critical dependency".to_string()],
+    );
+    assert_eq!(
+      read_message(&mut stream).await,
+      r#"{"type":"hash","data":"ghi"}"#
+    );
+    assert_eq!(
+      read_message(&mut stream).await,
+      r#"{"type":"warnings","data":["Critical dependency"]}"#
+    );
+
+    // a notification sent while a frame of the client is partially received
+    let ping = b"\x89\x85\x37\xfa\x21\x3d\x7f\x9f\x4d\x51\x58";
+    stream
+      .write_all(&ping[..4])
+      .await
+      .expect("should send frame");
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    server.update(Default::default(), None, vec![], vec![]);
+    assert_eq!(read_message(&mut stream).await, r#"{"type":"ok"}"#);
+    stream
+      .write_all(&ping[4..])
+      .await
+      .expect("should send frame");
+    let mut pong = [0; 7];
+    stream
+      .read_exact(&mut pong)
+      .await
+      .expect("should read pong");
+    assert_eq!(&pong, b"\x8a\x05Hello");
+  }
+}
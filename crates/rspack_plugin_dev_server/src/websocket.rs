@@ -0,0 +1,113 @@
+//! The subset of the WebSocket protocol (RFC 6455) the dev server needs: the opening
+//! handshake, sending text frames and answering the control frames of the clients.
+
+use sha1::{Digest, Sha1};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const ACCEPT_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+/// Clients only send control frames to the dev server, which can't be larger than this
+const MAX_CONTROL_PAYLOAD_SIZE: u64 = 125;
+
+pub const OPCODE_TEXT: u8 = 0x1;
+pub const OPCODE_CLOSE: u8 = 0x8;
+pub const OPCODE_PING: u8 = 0x9;
+pub const OPCODE_PONG: u8 = 0xA;
+
+/// The value of the `Sec-WebSocket-Accept` header answering a `Sec-WebSocket-Key`.
+pub fn accept_key(key: &str) -> String {
+  let mut hasher = Sha1::new();
+  hasher.update(key.trim().as_bytes());
+  hasher.update(ACCEPT_GUID.as_bytes());
+  rspack_base64::base64::encode_to_string(hasher.finalize())
+}
+
+/// Encode an unmasked frame, frames sent by a server must not be masked.
+pub fn encode_frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+  let mut frame = Vec::with_capacity(payload.len() + 10);
+  frame.push(0x80 | opcode);
+  let len = payload.len();
+  if len < 126 {
+    frame.push(len as u8);
+  } else if len <= u16::MAX as usize {
+    frame.push(126);
+    frame.extend_from_slice(&(len as u16).to_be_bytes());
+  } else {
+    frame.push(127);
+    frame.extend_from_slice(&(len as u64).to_be_bytes());
+  }
+  frame.extend_from_slice(payload);
+  frame
+}
+
+pub struct Frame {
+  pub opcode: u8,
+  pub payload: Vec<u8>,
+}
+
+/// Read a frame sent by a client, returns `None` when the connection is closed or the
+/// client sent something the dev server doesn't expect.
+pub async fn read_frame<R: AsyncRead + Unpin>(reader: &mut R) -> Option<Frame> {
+  let mut head = [0; 2];
+  reader.read_exact(&mut head).await.ok()?;
+  let opcode = head[0] & 0x0F;
+  let masked = head[1] & 0x80 != 0;
+  let len = match head[1] & 0x7F {
+    126 => {
+      let mut len = [0; 2];
+      reader.read_exact(&mut len).await.ok()?;
+      u16::from_be_bytes(len) as u64
+    }
+    127 => {
+      let mut len = [0; 8];
+      reader.read_exact(&mut len).await.ok()?;
+      u64::from_be_bytes(len)
+    }
+    len => len as u64,
+  };
+  // frames sent by a client must be masked
+  if !masked || len > MAX_CONTROL_PAYLOAD_SIZE {
+    return None;
+  }
+  let mut mask = [0; 4];
+  reader.read_exact(&mut mask).await.ok()?;
+  let mut payload = vec![0; len as usize];
+  reader.read_exact(&mut payload).await.ok()?;
+  for (i, byte) in payload.iter_mut().enumerate() {
+    *byte ^= mask[i % 4];
+  }
+  Some(Frame { opcode, payload })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_accept_key() {
+    // the example of RFC 6455
+    assert_eq!(
+      accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+      "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+    );
+  }
+
+  #[test]
+  fn test_encode_frame() {
+    assert_eq!(encode_frame(OPCODE_TEXT, b"Hello"), b"\x81\x05Hello");
+    let frame = encode_frame(OPCODE_TEXT, &[b'a'; 256]);
+    assert_eq!(&frame[..4], b"\x81\x7E\x01\x00");
+    assert_eq!(frame.len(), 260);
+  }
+
+  #[tokio::test]
+  async fn test_read_frame() {
+    // a masked "Hello" from RFC 6455
+    let mut bytes: &[u8] = b"\x89\x85\x37\xfa\x21\x3d\x7f\x9f\x4d\x51\x58";
+    let frame = read_frame(&mut bytes).await.expect("should read frame");
+    assert_eq!(frame.opcode, OPCODE_PING);
+    assert_eq!(frame.payload, b"Hello");
+
+    let mut unmasked: &[u8] = b"\x81\x05Hello";
+    assert!(read_frame(&mut unmasked).await.is_none());
+  }
+}
@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use rspack_collections::{Identifiable, Identifier};
+use rspack_core::{
+  impl_module_meta_info, impl_source_map_config, module_update_hash,
+  rspack_sources::{RawSource, Source, SourceExt},
+  AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext, BuildInfo, BuildMeta, BuildResult,
+  CodeGenerationResult, Compilation, ConcatenationScope, Context, DependenciesBlock, DependencyId,
+  EntryDependency, FactoryMeta, Module, ModuleIdentifier, ModuleType, RuntimeGlobals, RuntimeSpec,
+  SourceType,
+};
+use rspack_error::{impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_util::{ext::DynHash, source_map::SourceMapKind};
+
+/// The entry module of a dll bundle, it depends on every module listed in the dll entry
+/// and exposes the `__webpack_require__` of the bundle, the dll references use it to
+/// require the modules of the dll by their ids.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct DllModule {
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+  identifier: ModuleIdentifier,
+  name: String,
+  context: Context,
+  entries: Vec<String>,
+  factory_meta: Option<FactoryMeta>,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+}
+
+impl DllModule {
+  pub fn new(name: String, context: Context, entries: Vec<String>) -> Self {
+    Self {
+      blocks: Vec::new(),
+      dependencies: Vec::new(),
+      identifier: ModuleIdentifier::from(format!("dll {}", &name)),
+      name,
+      context,
+      entries,
+      factory_meta: None,
+      build_info: None,
+      build_meta: None,
+      source_map_kind: SourceMapKind::empty(),
+    }
+  }
+}
+
+impl Identifiable for DllModule {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+impl DependenciesBlock for DllModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait]
+impl Module for DllModule {
+  impl_module_meta_info!();
+
+  fn size(&self, _source_type: Option<&SourceType>, _compilation: &Compilation) -> f64 {
+    12.0
+  }
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::JsDynamic
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    self.identifier.as_str().into()
+  }
+
+  fn get_diagnostics(&self) -> Vec<Diagnostic> {
+    vec![]
+  }
+
+  async fn build(
+    &mut self,
+    _build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    let dependencies = self
+      .entries
+      .iter()
+      .map(|request| {
+        Box::new(EntryDependency::new(
+          request.clone(),
+          self.context.clone(),
+          None,
+          false,
+        )) as BoxDependency
+      })
+      .collect();
+
+    Ok(BuildResult {
+      build_info: BuildInfo {
+        strict: true,
+        ..Default::default()
+      },
+      build_meta: Default::default(),
+      dependencies,
+      blocks: Vec::new(),
+      optimization_bailouts: vec![],
+    })
+  }
+
+  #[tracing::instrument(name = "DllModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  fn code_generation(
+    &self,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut codegen = CodeGenerationResult::default();
+    codegen.runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+    codegen.runtime_requirements.insert(RuntimeGlobals::MODULE);
+    let source = format!("module.exports = {};", RuntimeGlobals::REQUIRE);
+    Ok(codegen.with_javascript(RawSource::from(source).boxed()))
+  }
+
+  fn update_hash(
+    &self,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Result<()> {
+    module_update_hash(self, hasher, compilation, runtime);
+    "dll module".dyn_hash(hasher);
+    self.name.dyn_hash(hasher);
+    Ok(())
+  }
+}
+
+impl_empty_diagnosable_trait!(DllModule);
@@ -0,0 +1,4 @@
+pub mod dll_entry_dependency;
+pub mod dll_entry_plugin;
+pub mod dll_module;
+pub mod dll_module_factory;
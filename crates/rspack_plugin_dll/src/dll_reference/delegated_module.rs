@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use rspack_collections::{Identifiable, Identifier};
+use rspack_core::{
+  impl_module_meta_info, impl_source_map_config, module_raw, module_update_hash,
+  rspack_sources::{RawSource, Source, SourceExt},
+  throw_missing_module_error_block, AsyncDependenciesBlockIdentifier, BoxDependency, BuildContext,
+  BuildInfo, BuildMeta, BuildResult, CodeGenerationResult, Compilation, ConcatenationScope,
+  Context, DependenciesBlock, DependencyId, FactoryMeta, LibIdentOptions, Module, ModuleIdentifier,
+  ModuleType, RuntimeGlobals, RuntimeSpec, SourceType, StaticExportsDependency, StaticExportsSpec,
+};
+use rspack_error::{error, impl_empty_diagnosable_trait, Diagnostic, Result};
+use rspack_util::{ext::DynHash, source_map::SourceMapKind};
+
+use super::{
+  delegated_source_dependency::DelegatedSourceDependency, dll_reference_plugin::DllReferenceType,
+};
+use crate::DllManifestContentItem;
+
+/// A module of another compilation, it's required from the module of `source_request`, which
+/// is the external of the dll exposing the require function of the dll.
+#[impl_source_map_config]
+#[derive(Debug)]
+pub struct DelegatedModule {
+  blocks: Vec<AsyncDependenciesBlockIdentifier>,
+  dependencies: Vec<DependencyId>,
+  identifier: ModuleIdentifier,
+  readable_identifier: String,
+  source_request: String,
+  request: String,
+  r#type: DllReferenceType,
+  original_request: String,
+  delegate_data: DllManifestContentItem,
+  factory_meta: Option<FactoryMeta>,
+  build_info: Option<BuildInfo>,
+  build_meta: Option<BuildMeta>,
+}
+
+impl DelegatedModule {
+  pub fn new(
+    source_request: String,
+    request: String,
+    delegate_data: DllManifestContentItem,
+    r#type: DllReferenceType,
+    user_request: String,
+    original_request: String,
+  ) -> Self {
+    Self {
+      blocks: Vec::new(),
+      dependencies: Vec::new(),
+      identifier: ModuleIdentifier::from(format!(
+        "delegated {} from {}",
+        render_module_id(&request),
+        source_request
+      )),
+      readable_identifier: format!("delegated {} from {}", user_request, source_request),
+      source_request,
+      request,
+      r#type,
+      original_request,
+      delegate_data,
+      factory_meta: None,
+      build_info: None,
+      build_meta: None,
+      source_map_kind: SourceMapKind::empty(),
+    }
+  }
+}
+
+impl Identifiable for DelegatedModule {
+  fn identifier(&self) -> Identifier {
+    self.identifier
+  }
+}
+
+impl DependenciesBlock for DelegatedModule {
+  fn add_block_id(&mut self, block: AsyncDependenciesBlockIdentifier) {
+    self.blocks.push(block)
+  }
+
+  fn get_blocks(&self) -> &[AsyncDependenciesBlockIdentifier] {
+    &self.blocks
+  }
+
+  fn add_dependency_id(&mut self, dependency: DependencyId) {
+    self.dependencies.push(dependency)
+  }
+
+  fn get_dependencies(&self) -> &[DependencyId] {
+    &self.dependencies
+  }
+}
+
+#[async_trait]
+impl Module for DelegatedModule {
+  impl_module_meta_info!();
+
+  fn size(&self, _source_type: Option<&SourceType>, _compilation: &Compilation) -> f64 {
+    42.0
+  }
+
+  fn module_type(&self) -> &ModuleType {
+    &ModuleType::JsDynamic
+  }
+
+  fn source_types(&self) -> &[SourceType] {
+    &[SourceType::JavaScript]
+  }
+
+  fn original_source(&self) -> Option<&dyn Source> {
+    None
+  }
+
+  fn readable_identifier(&self, _context: &Context) -> Cow<str> {
+    self.readable_identifier.as_str().into()
+  }
+
+  fn lib_ident(&self, _options: LibIdentOptions) -> Option<Cow<str>> {
+    Some(self.original_request.as_str().into())
+  }
+
+  fn get_diagnostics(&self) -> Vec<Diagnostic> {
+    vec![]
+  }
+
+  async fn build(
+    &mut self,
+    _build_context: BuildContext<'_>,
+    _: Option<&Compilation>,
+  ) -> Result<BuildResult> {
+    let exports = match &self.delegate_data.exports {
+      Some(exports) => {
+        StaticExportsSpec::Array(exports.iter().map(|e| e.as_str().into()).collect())
+      }
+      None => StaticExportsSpec::True,
+    };
+    let dependencies: Vec<BoxDependency> = vec![
+      Box::new(DelegatedSourceDependency::new(self.source_request.clone())),
+      Box::new(StaticExportsDependency::new(exports, false)),
+    ];
+
+    Ok(BuildResult {
+      build_info: Default::default(),
+      build_meta: (&self.delegate_data.build_meta).into(),
+      dependencies,
+      blocks: Vec::new(),
+      optimization_bailouts: vec![],
+    })
+  }
+
+  #[tracing::instrument(name = "DelegatedModule::code_generation", skip_all, fields(identifier = ?self.identifier()))]
+  fn code_generation(
+    &self,
+    compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+    _: Option<ConcatenationScope>,
+  ) -> Result<CodeGenerationResult> {
+    let mut codegen = CodeGenerationResult::default();
+    codegen.runtime_requirements.insert(RuntimeGlobals::MODULE);
+    codegen.runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+
+    let Some(source_dependency) = self.dependencies.first() else {
+      return Err(error!(
+        "DelegatedModule: {} should depend on the module of {}",
+        self.identifier, self.source_request
+      ));
+    };
+    let source = if compilation
+      .get_module_graph()
+      .module_identifier_by_dependency_id(source_dependency)
+      .is_none()
+    {
+      throw_missing_module_error_block(&self.source_request)
+    } else {
+      let source_module = module_raw(
+        compilation,
+        &mut codegen.runtime_requirements,
+        source_dependency,
+        &self.source_request,
+        false,
+      );
+      let request = render_module_id(&self.request);
+      match self.r#type {
+        DllReferenceType::Require => format!("module.exports = ({source_module})({request});"),
+        DllReferenceType::Object => format!("module.exports = ({source_module})[{request}];"),
+      }
+    };
+
+    Ok(codegen.with_javascript(RawSource::from(source).boxed()))
+  }
+
+  fn update_hash(
+    &self,
+    hasher: &mut dyn std::hash::Hasher,
+    compilation: &Compilation,
+    runtime: Option<&RuntimeSpec>,
+  ) -> Result<()> {
+    module_update_hash(self, hasher, compilation, runtime);
+    self.r#type.dyn_hash(hasher);
+    self.request.dyn_hash(hasher);
+    Ok(())
+  }
+}
+
+impl_empty_diagnosable_trait!(DelegatedModule);
+
+/// Numeric module ids are rendered as numbers, the same as `module_id_expr`.
+fn render_module_id(id: &str) -> String {
+  match id.parse::<i32>() {
+    Ok(id) => serde_json::to_string(&id),
+    Err(_) => serde_json::to_string(id),
+  }
+  .expect("should render module id")
+}
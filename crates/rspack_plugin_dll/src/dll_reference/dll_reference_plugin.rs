@@ -0,0 +1,379 @@
+use async_trait::async_trait;
+use rspack_core::{
+  ApplyContext, BoxModule, Compilation, CompilationParams, CompilerCompilation, CompilerOptions,
+  Context, DependencyType, ExternalItemObject, ExternalItemValue, ExternalType, LibIdentOptions,
+  ModuleExt, ModuleFactoryCreateData, NormalModuleCreateData, NormalModuleFactoryFactorize,
+  NormalModuleFactoryModule, Plugin, PluginContext,
+};
+use rspack_error::{error, Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_plugin_externals::ExternalsPlugin;
+
+use super::delegated_module::DelegatedModule;
+use crate::{DllManifest, DllManifestContent};
+
+/// How the delegated modules get the modules of the dll from what the dll exposes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DllReferenceType {
+  /// The dll exposes the require function of the dll, e.g. a dll built with `DllPlugin`
+  #[default]
+  Require,
+  /// The dll exposes an object of the modules by their ids
+  Object,
+}
+
+#[derive(Debug)]
+pub struct DllReferencePluginOptions {
+  /// The context of the requests in the manifest, defaults to the context of the compiler
+  pub context: Option<Context>,
+  /// The extensions tried for the requests in `scope`
+  pub extensions: Vec<String>,
+  /// The manifest emitted with the dll
+  pub manifest: Option<DllManifest>,
+  /// The name the dll is exposed with, defaults to the name in the manifest
+  pub name: Option<String>,
+  /// The content of the manifest, defaults to the content in the manifest
+  pub content: Option<DllManifestContent>,
+  /// Only delegates the requests prefixed with the scope, e.g. `scope/node_modules/a`
+  pub scope: Option<String>,
+  /// The external type the dll is referenced with, defaults to the type in the manifest or
+  /// `var`
+  pub source_type: Option<ExternalType>,
+  pub r#type: DllReferenceType,
+}
+
+/// Replaces the requests of the modules in a dll with delegated modules, which require the
+/// modules from the dll, the dll itself is referenced as an external.
+#[plugin]
+#[derive(Debug)]
+pub struct DllReferencePlugin {
+  name: Option<String>,
+  source: String,
+  source_type: ExternalType,
+  content: DllManifestContent,
+  context: Option<Context>,
+  extensions: Vec<String>,
+  scope: Option<String>,
+  r#type: DllReferenceType,
+}
+
+impl DllReferencePlugin {
+  pub fn new(options: DllReferencePluginOptions) -> Self {
+    let DllReferencePluginOptions {
+      context,
+      extensions,
+      manifest,
+      name,
+      content,
+      scope,
+      source_type,
+      r#type,
+    } = options;
+    let manifest = manifest.unwrap_or_default();
+    let name = name.or(manifest.name);
+    let source = format!("dll-reference {}", name.as_deref().unwrap_or_default());
+    let source_type = source_type
+      .or(manifest.r#type)
+      .unwrap_or_else(|| "var".to_string());
+    let content = content.unwrap_or(manifest.content);
+    Self::new_inner(
+      name,
+      source,
+      source_type,
+      content,
+      context,
+      extensions,
+      scope,
+      r#type,
+    )
+  }
+
+  /// The delegated module of `request`, when it's in the manifest. The entries without a
+  /// module id can't be required from the dll, they are reported and built as usual.
+  fn delegate(
+    &self,
+    request: &str,
+    user_request: &str,
+    original_request: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) -> Option<BoxModule> {
+    let item = self.content.get(request)?;
+    let Some(id) = &item.id else {
+      diagnostics.push(Diagnostic::warn(
+        "DllReferencePlugin".to_string(),
+        format!(
+          "The manifest entry of {request} has no module id, so it's bundled instead of required from the dll"
+        ),
+      ));
+      return None;
+    };
+    Some(
+      DelegatedModule::new(
+        self.source.clone(),
+        id.clone(),
+        item.clone(),
+        self.r#type,
+        user_request.to_string(),
+        original_request.to_string(),
+      )
+      .boxed(),
+    )
+  }
+}
+
+#[plugin_hook(CompilerCompilation for DllReferencePlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  params: &mut CompilationParams,
+) -> Result<()> {
+  compilation.set_dependency_factory(
+    DependencyType::DelegatedSource,
+    params.normal_module_factory.clone(),
+  );
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryFactorize for DllReferencePlugin)]
+async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<BoxModule>> {
+  let Some(scope) = &self.scope else {
+    return Ok(None);
+  };
+  let Some(dependency) = data.dependency.as_module_dependency() else {
+    return Ok(None);
+  };
+  let request = dependency.request().to_string();
+  let Some(inner_request) = request
+    .strip_prefix(scope.as_str())
+    .filter(|inner_request| inner_request.starts_with('/'))
+  else {
+    return Ok(None);
+  };
+  let inner_request = format!(".{inner_request}");
+  if let Some(module) = self.delegate(
+    &inner_request,
+    &inner_request,
+    &request,
+    &mut data.diagnostics,
+  ) {
+    return Ok(Some(module));
+  }
+  for extension in &self.extensions {
+    let inner_request = format!("{inner_request}{extension}");
+    if let Some(module) = self.delegate(
+      &inner_request,
+      &inner_request,
+      &format!("{request}{extension}"),
+      &mut data.diagnostics,
+    ) {
+      return Ok(Some(module));
+    }
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryModule for DllReferencePlugin)]
+async fn normal_module_factory_module(
+  &self,
+  data: &mut ModuleFactoryCreateData,
+  _create_data: &mut NormalModuleCreateData,
+  module: &mut BoxModule,
+) -> Result<()> {
+  if self.scope.is_some() {
+    return Ok(());
+  }
+  let context = self.context.as_ref().unwrap_or(&data.options.context);
+  let Some(request) = module.lib_ident(LibIdentOptions {
+    context: context.as_str(),
+  }) else {
+    return Ok(());
+  };
+  let request = request.into_owned();
+  if let Some(delegated) = self.delegate(&request, &request, &request, &mut data.diagnostics) {
+    *module = delegated;
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for DllReferencePlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DllReferencePlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    let Some(name) = &self.name else {
+      return Err(error!(
+        "DllReferencePlugin: the name of the dll should be provided by the options or the manifest"
+      ));
+    };
+    let mut externals = ExternalItemObject::default();
+    externals.insert(self.source.clone(), ExternalItemValue::String(name.clone()));
+    ExternalsPlugin::new(self.source_type.clone(), vec![externals.into()])
+      .apply(PluginContext::with_context(&mut *ctx.context), options)?;
+
+    ctx
+      .context
+      .compiler_hooks
+      .compilation
+      .tap(compilation::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .factorize
+      .tap(factorize::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .module
+      .tap(normal_module_factory_module::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_collections::Identifiable;
+  use rspack_core::{testing::compiler_options, BoxPlugin, Compiler, EntryOptions};
+  use rspack_fs::MemoryFileSystem;
+  use rspack_ids::NaturalModuleIdsPlugin;
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_entry::EntryPlugin;
+  use rspack_plugin_javascript::JsPlugin;
+  use rspack_plugin_runtime::{ArrayPushCallbackChunkFormatPlugin, RuntimePlugin};
+
+  use super::*;
+  use crate::DllManifestContentItem;
+
+  const MANIFEST: &str = r#"{
+    "name": "vendor_lib",
+    "content": {
+      "./vendor/a.js": { "id": 42, "buildMeta": { "exportsType": "namespace" }, "exports": ["a"] },
+      "./vendor/b.js": { "buildMeta": {} }
+    }
+  }"#;
+
+  /// Builds `index` against the dll of [MANIFEST], and returns the compiler with the
+  /// content of `main.js`.
+  async fn build(index: &str, scope: Option<&str>) -> (Compiler<MemoryFileSystem>, String) {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    std::fs::create_dir(dir.path().join("vendor")).expect("should create dir");
+    std::fs::write(dir.path().join("index.js"), index).expect("should write file");
+    std::fs::write(dir.path().join("vendor/a.js"), "export const a = 1;")
+      .expect("should write file");
+    std::fs::write(
+      dir.path().join("vendor/b.js"),
+      "module.exports = 'bundled b';",
+    )
+    .expect("should write file");
+    let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+
+    let options = compiler_options(context);
+    let plugins: Vec<BoxPlugin> = vec![
+      Box::new(EntryPlugin::new(
+        options.context.clone(),
+        context.join("index.js").to_string(),
+        EntryOptions {
+          name: Some("main".to_string()),
+          ..Default::default()
+        },
+      )),
+      Box::new(DllReferencePlugin::new(DllReferencePluginOptions {
+        context: None,
+        extensions: vec![".js".to_string()],
+        manifest: Some(serde_json::from_str(MANIFEST).expect("should parse manifest")),
+        name: None,
+        content: None,
+        scope: scope.map(ToOwned::to_owned),
+        source_type: None,
+        r#type: DllReferenceType::Require,
+      })),
+      Box::<JsPlugin>::default(),
+      Box::<RuntimePlugin>::default(),
+      Box::<ArrayPushCallbackChunkFormatPlugin>::default(),
+      Box::<NaturalModuleIdsPlugin>::default(),
+    ];
+    let mut compiler = Compiler::new(options, plugins, MemoryFileSystem::default(), None, None);
+    compiler.build().await.expect("should build");
+
+    assert_eq!(compiler.compilation.get_errors().count(), 0);
+    let main = compiler
+      .compilation
+      .assets()
+      .get("main.js")
+      .and_then(|asset| asset.get_source())
+      .expect("should emit main.js")
+      .source()
+      .into_owned();
+    (compiler, main)
+  }
+
+  fn has_module(compiler: &Compiler<MemoryFileSystem>, identifier: &str) -> bool {
+    compiler
+      .compilation
+      .get_module_graph()
+      .modules()
+      .keys()
+      .any(|module| module.as_str() == identifier)
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_delegate_modules_of_manifest() {
+    let (compiler, main) = build("import { a } from './vendor/a.js'; console.log(a);", None).await;
+    assert!(has_module(
+      &compiler,
+      "delegated 42 from dll-reference vendor_lib"
+    ));
+    assert!(has_module(&compiler, "external var \"vendor_lib\""));
+    assert!(main.contains("module.exports = vendor_lib;"));
+    assert!(main.contains(")(42);"));
+    assert!(!main.contains("const a = 1"));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_delegate_scoped_requests() {
+    let (compiler, main) = build(
+      "import { a } from 'lib/vendor/a'; console.log(a);",
+      Some("lib"),
+    )
+    .await;
+    assert!(has_module(
+      &compiler,
+      "delegated 42 from dll-reference vendor_lib"
+    ));
+    assert!(main.contains(")(42);"));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_bundle_manifest_entries_without_id() {
+    let (compiler, main) = build("require('./vendor/b.js');", None).await;
+    assert!(main.contains("bundled b"));
+    let warnings = compiler
+      .compilation
+      .get_warnings()
+      .map(|warning| warning.message())
+      .collect::<Vec<_>>();
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("./vendor/b.js has no module id"));
+  }
+
+  #[test]
+  fn test_render_delegated_module_ids() {
+    let module = DelegatedModule::new(
+      "dll-reference vendor_lib".to_string(),
+      "./vendor/a.js".to_string(),
+      DllManifestContentItem::default(),
+      DllReferenceType::Object,
+      "./vendor/a.js".to_string(),
+      "./vendor/a.js".to_string(),
+    );
+    assert_eq!(
+      module.identifier().as_str(),
+      "delegated \"./vendor/a.js\" from dll-reference vendor_lib"
+    );
+  }
+}
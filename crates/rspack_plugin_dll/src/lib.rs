@@ -0,0 +1,13 @@
+mod dll_entry;
+mod dll_reference;
+mod flag_all_modules_as_used_plugin;
+mod lib_manifest_plugin;
+mod manifest;
+
+pub use dll_entry::dll_entry_plugin::DllEntryPlugin;
+pub use dll_reference::dll_reference_plugin::{
+  DllReferencePlugin, DllReferencePluginOptions, DllReferenceType,
+};
+pub use flag_all_modules_as_used_plugin::FlagAllModulesAsUsedPlugin;
+pub use lib_manifest_plugin::{LibManifestPlugin, LibManifestPluginOptions};
+pub use manifest::{DllManifest, DllManifestBuildMeta, DllManifestContent, DllManifestContentItem};
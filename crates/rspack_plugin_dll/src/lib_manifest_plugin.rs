@@ -0,0 +1,256 @@
+use async_trait::async_trait;
+use rspack_core::{
+  rspack_sources::{RawSource, SourceExt},
+  ApplyContext, AssetInfo, Compilation, CompilationAsset, CompilerEmit, CompilerOptions, Context,
+  DependencyType, FilenameTemplate, LibIdentOptions, PathData, Plugin, PluginContext,
+  ProvidedExports, SourceType,
+};
+use rspack_error::{error, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_util::infallible::ResultInfallibleExt as _;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::{DllManifest, DllManifestContent, DllManifestContentItem};
+
+#[derive(Debug)]
+pub struct LibManifestPluginOptions {
+  /// The context of the requests in the manifest, defaults to the context of the compiler
+  pub context: Option<Context>,
+  /// Only expose the modules required by the entries of the dll
+  pub entry_only: bool,
+  /// The name of the dll in the manifest, should be the name the dll is exposed with
+  pub name: Option<FilenameTemplate>,
+  /// The path of the manifest, a relative path is resolved against `output.path`
+  pub path: FilenameTemplate,
+  /// Pretty print the manifest
+  pub format: bool,
+  /// The type of the dll in the manifest, defaults to the type of `output.library`
+  pub r#type: Option<String>,
+}
+
+/// Emits the manifest of the modules of the initial chunks, which is read by
+/// [crate::DllReferencePlugin] to delegate the requests to the dll.
+#[plugin]
+#[derive(Debug)]
+pub struct LibManifestPlugin {
+  options: LibManifestPluginOptions,
+}
+
+impl LibManifestPlugin {
+  pub fn new(options: LibManifestPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+}
+
+#[plugin_hook(CompilerEmit for LibManifestPlugin)]
+async fn emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let chunk_graph = &compilation.chunk_graph;
+  let context = self
+    .options
+    .context
+    .as_ref()
+    .unwrap_or(&compilation.options.context);
+  let r#type = self.options.r#type.clone().or_else(|| {
+    compilation
+      .options
+      .output
+      .library
+      .as_ref()
+      .map(|library| library.library_type.clone())
+  });
+
+  let mut manifests: HashMap<String, String> = HashMap::default();
+  for (chunk_ukey, chunk) in compilation.chunk_by_ukey.iter() {
+    if !chunk.can_be_initial(&compilation.chunk_group_by_ukey) {
+      continue;
+    }
+
+    let target_path = compilation
+      .get_path(&self.options.path, PathData::default().chunk(chunk))
+      .always_ok();
+    if manifests.contains_key(&target_path) {
+      return Err(error!(
+        "LibManifestPlugin: each chunk must have a unique path, {target_path} is used by multiple chunks"
+      ));
+    }
+
+    let name = self.options.name.as_ref().map(|name| {
+      let content_hash = chunk
+        .content_hash
+        .get(&SourceType::JavaScript)
+        .map(|hash| hash.rendered(compilation.options.output.hash_digest_length));
+      compilation
+        .get_path(
+          name,
+          PathData::default()
+            .chunk(chunk)
+            .content_hash_optional(content_hash),
+        )
+        .always_ok()
+    });
+
+    let mut content = DllManifestContent::default();
+    for module in chunk_graph.get_ordered_chunk_modules(chunk_ukey, &module_graph) {
+      let module_identifier = module.identifier();
+      if self.options.entry_only
+        && !module_graph
+          .get_incoming_connections(&module_identifier)
+          .iter()
+          .any(|connection| {
+            module_graph
+              .dependency_by_id(&connection.dependency_id)
+              .is_some_and(|dep| dep.dependency_type() == &DependencyType::Entry)
+          })
+      {
+        continue;
+      }
+      let Some(ident) = module.lib_ident(LibIdentOptions {
+        context: context.as_str(),
+      }) else {
+        continue;
+      };
+      let exports = match module_graph
+        .get_exports_info(&module_identifier)
+        .get_provided_exports(&module_graph)
+      {
+        ProvidedExports::Vec(exports) => Some(exports.iter().map(|e| e.to_string()).collect()),
+        _ => None,
+      };
+      content.insert(
+        ident.into_owned(),
+        DllManifestContentItem {
+          id: chunk_graph
+            .get_module_id(module_identifier)
+            .map(ToOwned::to_owned),
+          build_meta: module.build_meta().map(Into::into).unwrap_or_default(),
+          exports,
+        },
+      );
+    }
+
+    let manifest = DllManifest {
+      name,
+      r#type: r#type.clone(),
+      content,
+    };
+    let manifest = if self.options.format {
+      serde_json::to_string_pretty(&manifest)
+    } else {
+      serde_json::to_string(&manifest)
+    }
+    .map_err(|e| error!("LibManifestPlugin: failed to serialize the manifest: {e}"))?;
+    manifests.insert(target_path, manifest);
+  }
+
+  for (path, manifest) in manifests {
+    compilation.emit_asset(
+      path,
+      CompilationAsset::new(
+        Some(RawSource::from(manifest).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  }
+  Ok(())
+}
+
+#[async_trait]
+impl Plugin for LibManifestPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.LibManifestPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx.context.compiler_hooks.emit.tap(emit::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_core::{testing::compiler_options, BoxPlugin, Compiler, EntryOptions};
+  use rspack_fs::MemoryFileSystem;
+  use rspack_ids::NaturalModuleIdsPlugin;
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_javascript::JsPlugin;
+
+  use super::*;
+  use crate::DllEntryPlugin;
+
+  /// Builds a dll of an esm and a commonjs module, and returns its manifest.
+  async fn build_manifest(entry_only: bool) -> DllManifest {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    std::fs::write(
+      dir.path().join("a.js"),
+      "import b from './b.js'; export const a = b;",
+    )
+    .expect("should write file");
+    std::fs::write(dir.path().join("b.js"), "module.exports = 1;").expect("should write file");
+    let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+
+    let options = compiler_options(context);
+    let plugins: Vec<BoxPlugin> = vec![
+      Box::new(DllEntryPlugin::new(
+        options.context.clone(),
+        vec!["./a.js".to_string()],
+        EntryOptions {
+          name: Some("vendor".to_string()),
+          ..Default::default()
+        },
+      )),
+      Box::new(LibManifestPlugin::new(LibManifestPluginOptions {
+        context: None,
+        entry_only,
+        name: Some("[name]_lib".to_string().into()),
+        path: "[name]-manifest.json".to_string().into(),
+        format: false,
+        r#type: Some("var".to_string()),
+      })),
+      Box::<JsPlugin>::default(),
+      Box::<NaturalModuleIdsPlugin>::default(),
+    ];
+    let mut compiler = Compiler::new(options, plugins, MemoryFileSystem::default(), None, None);
+    compiler.build().await.expect("should build");
+
+    let compilation = &compiler.compilation;
+    assert_eq!(compilation.get_errors().count(), 0);
+    let manifest = compilation
+      .assets()
+      .get("vendor-manifest.json")
+      .and_then(|asset| asset.get_source())
+      .expect("should emit the manifest")
+      .source();
+    serde_json::from_str(&manifest).expect("should parse the manifest")
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_manifest_of_dll() {
+    let manifest = build_manifest(false).await;
+    assert_eq!(manifest.name.as_deref(), Some("vendor_lib"));
+    assert_eq!(manifest.r#type.as_deref(), Some("var"));
+    assert_eq!(
+      manifest.content.keys().collect::<Vec<_>>(),
+      vec!["./a.js", "./b.js"]
+    );
+
+    let a = &manifest.content["./a.js"];
+    assert!(a.id.is_some());
+    assert_eq!(a.build_meta.exports_type.as_deref(), Some("namespace"));
+    assert!(a.build_meta.esm);
+    let b = &manifest.content["./b.js"];
+    assert!(b.id.is_some());
+    assert_ne!(a.id, b.id);
+    assert!(!b.build_meta.esm);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_manifest_of_entry_only_dll() {
+    let manifest = build_manifest(true).await;
+    assert_eq!(manifest.content.keys().collect::<Vec<_>>(), vec!["./a.js"]);
+  }
+}
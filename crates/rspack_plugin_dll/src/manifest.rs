@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{BuildMeta, BuildMetaDefaultObject, BuildMetaExportsType};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The manifest of a dll bundle, it's compatible with the manifest of webpack's `DllPlugin`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DllManifest {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  pub content: DllManifestContent,
+}
+
+/// Maps the requests of the modules in the dll, relative to the context of the dll, to
+/// their data.
+pub type DllManifestContent = BTreeMap<String, DllManifestContentItem>;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DllManifestContentItem {
+  #[serde(
+    default,
+    serialize_with = "serialize_module_id",
+    deserialize_with = "deserialize_module_id"
+  )]
+  pub id: Option<String>,
+  #[serde(default)]
+  pub build_meta: DllManifestBuildMeta,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub exports: Option<Vec<String>>,
+}
+
+/// The part of the build meta of a module which is needed to use it from another compilation.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DllManifestBuildMeta {
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub exports_type: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub default_object: Option<String>,
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub strict_harmony_module: bool,
+  #[serde(default, rename = "async", skip_serializing_if = "std::ops::Not::not")]
+  pub has_top_level_await: bool,
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub esm: bool,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub side_effect_free: Option<bool>,
+}
+
+impl From<&BuildMeta> for DllManifestBuildMeta {
+  fn from(build_meta: &BuildMeta) -> Self {
+    let exports_type = match build_meta.exports_type {
+      BuildMetaExportsType::Unset => None,
+      BuildMetaExportsType::Default => Some("default"),
+      BuildMetaExportsType::Namespace => Some("namespace"),
+      BuildMetaExportsType::Flagged => Some("flagged"),
+      BuildMetaExportsType::Dynamic => Some("dynamic"),
+    };
+    let default_object = match build_meta.default_object {
+      BuildMetaDefaultObject::False => None,
+      BuildMetaDefaultObject::Redirect => Some("redirect"),
+      BuildMetaDefaultObject::RedirectWarn { .. } => Some("redirect-warn"),
+    };
+    Self {
+      exports_type: exports_type.map(ToOwned::to_owned),
+      default_object: default_object.map(ToOwned::to_owned),
+      strict_harmony_module: build_meta.strict_harmony_module,
+      has_top_level_await: build_meta.has_top_level_await,
+      esm: build_meta.esm,
+      side_effect_free: build_meta.side_effect_free,
+    }
+  }
+}
+
+impl From<&DllManifestBuildMeta> for BuildMeta {
+  fn from(meta: &DllManifestBuildMeta) -> Self {
+    let exports_type = match meta.exports_type.as_deref() {
+      Some("default") => BuildMetaExportsType::Default,
+      Some("namespace") => BuildMetaExportsType::Namespace,
+      Some("flagged") => BuildMetaExportsType::Flagged,
+      Some("dynamic") => BuildMetaExportsType::Dynamic,
+      _ => BuildMetaExportsType::Unset,
+    };
+    let default_object = match meta.default_object.as_deref() {
+      Some("redirect") => BuildMetaDefaultObject::Redirect,
+      Some("redirect-warn") => BuildMetaDefaultObject::RedirectWarn { ignore: false },
+      _ => BuildMetaDefaultObject::False,
+    };
+    Self {
+      exports_type,
+      default_object,
+      strict_harmony_module: meta.strict_harmony_module,
+      has_top_level_await: meta.has_top_level_await,
+      esm: meta.esm,
+      side_effect_free: meta.side_effect_free,
+      ..Default::default()
+    }
+  }
+}
+
+/// Numeric module ids are written as numbers, the same as they are rendered in the bundle.
+fn serialize_module_id<S: Serializer>(
+  id: &Option<String>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match id.as_deref().map(|id| (id, id.parse::<i32>())) {
+    Some((_, Ok(id))) => serializer.serialize_i32(id),
+    Some((id, Err(_))) => serializer.serialize_str(id),
+    None => serializer.serialize_none(),
+  }
+}
+
+fn deserialize_module_id<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Option<String>, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum ModuleId {
+    Number(i64),
+    String(String),
+  }
+
+  Ok(
+    Option::<ModuleId>::deserialize(deserializer)?.map(|id| match id {
+      ModuleId::Number(id) => id.to_string(),
+      ModuleId::String(id) => id,
+    }),
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_manifest() {
+    let manifest: DllManifest = serde_json::from_str(
+      r#"{
+        "name": "vendor_lib",
+        "content": {
+          "./node_modules/a/index.js": { "id": 42, "buildMeta": { "exportsType": "namespace" }, "exports": ["default", "a"] },
+          "./node_modules/b/index.js": { "id": "./node_modules/b/index.js", "buildMeta": {} }
+        }
+      }"#,
+    )
+    .expect("should parse manifest");
+    assert_eq!(manifest.name.as_deref(), Some("vendor_lib"));
+    assert_eq!(manifest.r#type, None);
+
+    let a = &manifest.content["./node_modules/a/index.js"];
+    assert_eq!(a.id.as_deref(), Some("42"));
+    assert_eq!(
+      a.exports,
+      Some(vec!["default".to_string(), "a".to_string()])
+    );
+    let build_meta = BuildMeta::from(&a.build_meta);
+    assert_eq!(build_meta.exports_type, BuildMetaExportsType::Namespace);
+    assert_eq!(DllManifestBuildMeta::from(&build_meta), a.build_meta);
+
+    let b = &manifest.content["./node_modules/b/index.js"];
+    assert_eq!(b.id.as_deref(), Some("./node_modules/b/index.js"));
+    assert_eq!(b.exports, None);
+
+    assert_eq!(
+      serde_json::to_string(&manifest.content).expect("should serialize manifest content"),
+      r#"{"./node_modules/a/index.js":{"id":42,"buildMeta":{"exportsType":"namespace"},"exports":["default","a"]},"./node_modules/b/index.js":{"id":"./node_modules/b/index.js","buildMeta":{}}}"#
+    );
+  }
+}
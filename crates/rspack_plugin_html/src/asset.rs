@@ -247,8 +247,8 @@ impl HtmlPluginAssetTags {
 
     for tag in &asset_tags.scripts {
       match config.inject {
-        HtmlInject::Head => head_tags.push(tag.to_owned()),
-        HtmlInject::Body => body_tags.push(tag.to_owned()),
+        HtmlInject::Head | HtmlInject::HeadPrepend => head_tags.push(tag.to_owned()),
+        HtmlInject::Body | HtmlInject::BodyPrepend => body_tags.push(tag.to_owned()),
         HtmlInject::False => {
           if matches!(config.script_loading, HtmlScriptLoading::Blocking) {
             body_tags.push(tag.to_owned());
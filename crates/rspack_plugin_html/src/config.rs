@@ -6,14 +6,19 @@ use rspack_error::Result;
 use serde::Serialize;
 use sugar_path::SugarPath;
 
-use crate::sri::HtmlSriHashFunction;
+use crate::{
+  sri::HtmlSriHashFunction,
+  tag::{HtmlPluginAttribute, HtmlPluginTag},
+};
 
 #[derive(Serialize, Debug, Clone, Copy, Default)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "kebab-case")]
 pub enum HtmlInject {
   #[default]
   Head,
   Body,
+  HeadPrepend,
+  BodyPrepend,
   False,
 }
 
@@ -22,6 +27,8 @@ impl fmt::Display for HtmlInject {
     f.write_str(match self {
       HtmlInject::Head => "head",
       HtmlInject::Body => "body",
+      HtmlInject::HeadPrepend => "head-prepend",
+      HtmlInject::BodyPrepend => "body-prepend",
       HtmlInject::False => "false",
     })
   }
@@ -35,11 +42,15 @@ impl FromStr for HtmlInject {
       Ok(HtmlInject::Head)
     } else if s.eq("body") {
       Ok(HtmlInject::Body)
+    } else if s.eq("head-prepend") {
+      Ok(HtmlInject::HeadPrepend)
+    } else if s.eq("body-prepend") {
+      Ok(HtmlInject::BodyPrepend)
     } else if s.eq("false") {
       Ok(HtmlInject::False)
     } else {
       Err(anyhow::Error::msg(
-        "inject in html config only support 'head', 'body', or 'false'",
+        "inject in html config only support 'head', 'body', 'head-prepend', 'body-prepend', or 'false'",
       ))
     }
   }
@@ -114,6 +125,20 @@ impl std::fmt::Debug for TemplateRenderFn {
   }
 }
 
+type TagAttributesTsfn =
+  Box<dyn Fn(HtmlPluginTag) -> BoxFuture<'static, Result<Vec<HtmlPluginAttribute>>> + Sync + Send>;
+
+/// Returns the attributes to set on a generated tag
+pub struct TagAttributesFn {
+  pub inner: TagAttributesTsfn,
+}
+
+impl std::fmt::Debug for TagAttributesFn {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("TagAttributesFn").finish()
+  }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct HtmlRspackPluginOptions {
@@ -127,7 +152,7 @@ pub struct HtmlRspackPluginOptions {
   pub template_content: Option<String>,
   #[serde(skip)]
   pub template_parameters: TemplateParameters,
-  /// `head`, `body`, `false`
+  /// `head`, `body`, `head-prepend`, `body-prepend`, `false`
   #[serde(default = "default_inject")]
   pub inject: HtmlInject,
   /// path or `auto`
@@ -150,6 +175,8 @@ pub struct HtmlRspackPluginOptions {
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
   pub hash: Option<bool>,
   pub base: Option<HtmlRspackPluginBaseOptions>,
+  #[serde(skip)]
+  pub attributes: Option<TagAttributesFn>,
 }
 
 fn default_filename() -> String {
@@ -184,6 +211,7 @@ impl Default for HtmlRspackPluginOptions {
       meta: None,
       hash: None,
       base: None,
+      attributes: None,
     }
   }
 }
@@ -2,26 +2,37 @@ use swc_core::{common::DUMMY_SP, ecma::atoms::Atom};
 use swc_html::ast::{Child, Element, Text};
 use swc_html::visit::{VisitMut, VisitMutWith};
 
-use crate::tag::HtmlPluginTag;
+use crate::{config::HtmlInject, tag::HtmlPluginTag};
 
 #[derive(Debug)]
 pub struct AssetInjector<'a> {
   head_tags: &'a Vec<HtmlPluginTag>,
   body_tags: &'a Vec<HtmlPluginTag>,
+  inject: HtmlInject,
 }
 
 impl<'a> AssetInjector<'a> {
   pub fn new(
     head_tags: &'a Vec<HtmlPluginTag>,
     body_tags: &'a Vec<HtmlPluginTag>,
+    inject: HtmlInject,
   ) -> AssetInjector<'a> {
     AssetInjector {
       head_tags,
       body_tags,
+      inject,
     }
   }
 }
 
+fn inject_tags(children: &mut Vec<Child>, tags: Vec<Child>, prepend: bool) {
+  if prepend {
+    children.splice(0..0, tags);
+  } else {
+    children.extend(tags);
+  }
+}
+
 impl VisitMut for AssetInjector<'_> {
   fn visit_mut_element(&mut self, n: &mut Element) {
     let head_tags = &self.head_tags;
@@ -29,6 +40,7 @@ impl VisitMut for AssetInjector<'_> {
 
     match &*n.tag_name {
       "head" => {
+        let mut tags = vec![];
         for tag in head_tags.iter() {
           if tag.tag_name == "title" {
             if let Some(Child::Element(title_ele)) = n.children.iter_mut().find(|child| {
@@ -51,15 +63,24 @@ impl VisitMut for AssetInjector<'_> {
               continue;
             }
           }
-          n.children
-            .push(Child::Element(Element::from(tag.to_owned())));
+          tags.push(Child::Element(Element::from(tag.to_owned())));
         }
+        inject_tags(
+          &mut n.children,
+          tags,
+          matches!(self.inject, HtmlInject::HeadPrepend),
+        );
       }
       "body" => {
-        for tag in body_tags.iter() {
-          n.children
-            .push(Child::Element(Element::from(tag.to_owned())));
-        }
+        let tags = body_tags
+          .iter()
+          .map(|tag| Child::Element(Element::from(tag.to_owned())))
+          .collect();
+        inject_tags(
+          &mut n.children,
+          tags,
+          matches!(self.inject, HtmlInject::BodyPrepend),
+        );
       }
       _ => {}
     }
@@ -67,3 +88,115 @@ impl VisitMut for AssetInjector<'_> {
     n.visit_mut_children_with(self);
   }
 }
+
+#[cfg(test)]
+mod test {
+  use swc_html::ast::Document;
+
+  use super::*;
+  use crate::{
+    config::{HtmlRspackPluginOptions, HtmlScriptLoading},
+    parser::HtmlCompiler,
+  };
+
+  fn parse(html: &str) -> Document {
+    HtmlCompiler::new(&HtmlRspackPluginOptions::default())
+      .parse_file("index.html", html.to_string())
+      .expect("should parse html")
+      .split_into_parts()
+      .0
+  }
+
+  fn find<'a>(children: &'a [Child], tag_name: &str) -> Option<&'a Element> {
+    children.iter().find_map(|child| match child {
+      Child::Element(element) if element.tag_name == tag_name => Some(element),
+      Child::Element(element) => find(&element.children, tag_name),
+      _ => None,
+    })
+  }
+
+  /// Describes the elements in `<head>` or `<body>` by their tag names and sources.
+  fn children_of(document: &Document, tag_name: &str) -> Vec<String> {
+    find(&document.children, tag_name)
+      .expect("should have element")
+      .children
+      .iter()
+      .filter_map(|child| match child {
+        Child::Element(element) => Some(
+          element
+            .attributes
+            .iter()
+            .find(|attr| attr.name == "src" || attr.name == "href")
+            .and_then(|attr| attr.value.as_deref())
+            .map_or_else(
+              || element.tag_name.to_string(),
+              |src| format!("{} {src}", element.tag_name),
+            ),
+        ),
+        _ => None,
+      })
+      .collect()
+  }
+
+  fn inject(inject: HtmlInject) -> Document {
+    let mut document = parse(
+      "<!DOCTYPE html><html><head><meta charset=\"UTF-8\"><script src=\"head.js\"></script></head>\
+       <body><div></div></body></html>",
+    );
+    let head_tags = vec![
+      HtmlPluginTag::create_style("main.css"),
+      HtmlPluginTag::create_script("main.js", &HtmlScriptLoading::Defer),
+    ];
+    let body_tags = vec![HtmlPluginTag::create_script(
+      "body.js",
+      &HtmlScriptLoading::Blocking,
+    )];
+    document.visit_mut_with(&mut AssetInjector::new(&head_tags, &body_tags, inject));
+    document
+  }
+
+  #[test]
+  fn test_inject_appends_tags() {
+    let document = inject(HtmlInject::Head);
+    assert_eq!(
+      children_of(&document, "head"),
+      vec!["meta", "script head.js", "link main.css", "script main.js"]
+    );
+    assert_eq!(
+      children_of(&document, "body"),
+      vec!["div", "script body.js"]
+    );
+  }
+
+  #[test]
+  fn test_inject_prepends_tags_in_order() {
+    let document = inject(HtmlInject::HeadPrepend);
+    assert_eq!(
+      children_of(&document, "head"),
+      vec!["link main.css", "script main.js", "meta", "script head.js"]
+    );
+
+    let document = inject(HtmlInject::BodyPrepend);
+    assert_eq!(
+      children_of(&document, "body"),
+      vec!["script body.js", "div"]
+    );
+  }
+
+  #[test]
+  fn test_inject_title_into_existing_title() {
+    let mut document = parse("<html><head><title>old</title></head><body></body></html>");
+    let head_tags = vec![HtmlPluginTag::create_title("new")];
+    document.visit_mut_with(&mut AssetInjector::new(
+      &head_tags,
+      &vec![],
+      HtmlInject::HeadPrepend,
+    ));
+    assert_eq!(children_of(&document, "head"), vec!["title"]);
+    let title = find(&document.children, "title").expect("should have title");
+    assert!(matches!(
+      title.children.as_slice(),
+      [Child::Text(text)] if text.data == "new"
+    ));
+  }
+}
@@ -1,18 +1,23 @@
 use std::sync::Arc;
 
+use lightningcss::stylesheet::{ParserOptions, PrinterOptions, StyleAttribute, StyleSheet};
 use rspack_core::{Compilation, ErrorSpan};
 use rspack_error::{error, DiagnosticKind, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_plugin_swc_js_minimizer::minify_inline_script;
 use swc_core::common::{sync::Lrc, FileName, FilePathMapping, SourceFile, SourceMap, GLOBALS};
 use swc_html::{
-  ast::Document,
+  ast::{Child, Document, Element, Namespace},
   codegen::{
     writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
     CodeGenerator, CodegenConfig, Emit,
   },
   parser::{error::Error, parse_file_as_document, parser::ParserConfig},
+  visit::{VisitMut, VisitMutWith},
 };
-use swc_html_minifier::option::MinifyOptions;
-use swc_html_minifier::{minify_document_with_custom_css_minifier, MinifyCss};
+use swc_html_minifier::option::{
+  CollapseWhitespaces, MinifyCssOption, MinifyJsOption, MinifyOptions,
+};
+use swc_html_minifier::{minify_document_with_custom_css_minifier, CssMinificationMode, MinifyCss};
 
 use crate::config::HtmlRspackPluginOptions;
 
@@ -57,10 +62,18 @@ impl<'a> HtmlCompiler<'a> {
       GLOBALS.set(&Default::default(), || {
         minify_document_with_custom_css_minifier(
           ast,
-          &MinifyOptions::<()>::default(),
-          &NoopCssMinifier,
+          &MinifyOptions::<()> {
+            collapse_whitespaces: CollapseWhitespaces::Conservative,
+            remove_comments: true,
+            // the inline scripts are minified by the swc minimizer of rspack below
+            minify_js: MinifyJsOption::Bool(false),
+            minify_css: MinifyCssOption::Bool(true),
+            ..Default::default()
+          },
+          &LightningCssMinifier,
         );
-      })
+      });
+      ast.visit_mut_with(&mut InlineScriptMinifier { compilation });
     }
 
     let mut output = String::new();
@@ -88,17 +101,161 @@ pub fn html_parse_error_to_traceable_error(error: Error, fm: &SourceFile) -> rsp
   traceable_error.into()
 }
 
-struct NoopCssMinifier;
+/// Minifies the inline `<script>` elements with the swc minimizer of rspack, the scripts
+/// which can't be parsed are kept as is.
+struct InlineScriptMinifier<'a> {
+  compilation: &'a Compilation,
+}
+
+impl VisitMut for InlineScriptMinifier<'_> {
+  fn visit_mut_element(&mut self, n: &mut Element) {
+    n.visit_mut_children_with(self);
+
+    if n.tag_name != "script"
+      || n.namespace != Namespace::HTML
+      || n.attributes.iter().any(|attr| attr.name == "src")
+    {
+      return;
+    }
+    let script_type = n
+      .attributes
+      .iter()
+      .find(|attr| attr.name == "type")
+      .and_then(|attr| attr.value.as_deref())
+      .map(|value| value.trim().to_ascii_lowercase());
+    let module = match script_type.as_deref() {
+      None | Some("") => false,
+      Some("module") => true,
+      Some(script_type) if JAVASCRIPT_MIME_TYPES.contains(&script_type) => false,
+      // json, templates and other data blocks
+      Some(_) => return,
+    };
+
+    for child in &mut n.children {
+      let Child::Text(text) = child else {
+        continue;
+      };
+      if text.data.trim().is_empty() {
+        continue;
+      }
+      if let Some(minified) = minify_inline_script(
+        self.compilation,
+        text.data.to_string(),
+        "inline script",
+        module,
+      ) {
+        text.data = minified.into();
+        text.raw = None;
+      }
+    }
+  }
+}
+
+/// The `type` of the `<script>` elements whose content is a classic script
+const JAVASCRIPT_MIME_TYPES: &[&str] = &[
+  "text/javascript",
+  "text/ecmascript",
+  "text/jscript",
+  "text/livescript",
+  "application/javascript",
+  "application/x-javascript",
+  "application/ecmascript",
+  "application/x-ecmascript",
+];
+
+/// Minifies the inline `<style>` elements and `style` attributes with lightningcss
+struct LightningCssMinifier;
 
-impl MinifyCss for NoopCssMinifier {
+impl MinifyCss for LightningCssMinifier {
   type Options = ();
 
   fn minify_css(
     &self,
-    _options: &swc_html_minifier::option::MinifyCssOption<Self::Options>,
+    _options: &MinifyCssOption<Self::Options>,
     data: String,
-    _mode: swc_html_minifier::CssMinificationMode,
+    mode: CssMinificationMode,
   ) -> Option<String> {
-    Some(data)
+    let printer_options = || PrinterOptions {
+      minify: true,
+      ..Default::default()
+    };
+    match mode {
+      CssMinificationMode::Stylesheet => {
+        let mut stylesheet = StyleSheet::parse(&data, ParserOptions::default()).ok()?;
+        stylesheet.minify(Default::default()).ok()?;
+        stylesheet
+          .to_css(printer_options())
+          .ok()
+          .map(|res| res.code)
+      }
+      CssMinificationMode::ListOfDeclarations => {
+        let mut style = StyleAttribute::parse(&data, ParserOptions::default()).ok()?;
+        style.minify(Default::default());
+        style.to_css(printer_options()).ok().map(|res| res.code)
+      }
+      // keep the `media` attributes as is
+      CssMinificationMode::MediaQueryList => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_core::{testing::compiler_options, Compiler};
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::Utf8Path;
+
+  use crate::{config::HtmlRspackPluginOptions, HtmlRspackPlugin};
+
+  /// Builds the minified page of `template`.
+  async fn build_minified(template: &str) -> String {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+    let plugin = HtmlRspackPlugin::new(HtmlRspackPluginOptions {
+      template_content: Some(template.to_string()),
+      minify: Some(true),
+      ..Default::default()
+    });
+    let mut compiler = Compiler::new(
+      compiler_options(context),
+      vec![Box::new(plugin)],
+      MemoryFileSystem::default(),
+      None,
+      None,
+    );
+    compiler.build().await.expect("should build");
+    assert_eq!(compiler.compilation.get_errors().count(), 0);
+    compiler
+      .compilation
+      .assets()
+      .get("index.html")
+      .and_then(|asset| asset.get_source())
+      .expect("should emit index.html")
+      .source()
+      .into_owned()
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_minify_inline_scripts() {
+    let html = build_minified(
+      "<html><head>
+        <script>
+          var a = 123;
+          console.log(a);
+        </script>
+        <script type=\"module\">
+          const b = 456;
+          console.log(b);
+        </script>
+        <script type=\"application/json\">{ \"c\": 789 }</script>
+        <script>this is not javascript</script>
+      </head><body></body></html>",
+    )
+    .await;
+    assert!(html.contains("<script>var a=123;console.log(a);</script>"));
+    // module scripts are minified with their top level scope
+    assert!(html.contains("<script type=\"module\">console.log(456);</script>"));
+    assert!(html.contains("<script type=\"application/json\">{\"c\":789}</script>"));
+    assert!(html.contains("<script>this is not javascript</script>"));
   }
 }
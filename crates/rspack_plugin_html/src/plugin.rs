@@ -98,7 +98,7 @@ async fn generate_html(
   let (head_tags, body_tags) =
     HtmlPluginAssetTags::to_groups(config, alter_asset_tags_data.asset_tags);
 
-  let alter_asset_tag_groups_data = hooks
+  let mut alter_asset_tag_groups_data = hooks
     .alter_asset_tag_groups
     .call(AlterAssetTagGroupsData {
       head_tags,
@@ -108,6 +108,18 @@ async fn generate_html(
     })
     .await?;
 
+  if let Some(attributes) = &config.attributes {
+    for tag in alter_asset_tag_groups_data
+      .head_tags
+      .iter_mut()
+      .chain(alter_asset_tag_groups_data.body_tags.iter_mut())
+    {
+      for attribute in (attributes.inner)(tag.clone()).await? {
+        tag.set_attribute(attribute);
+      }
+    }
+  }
+
   template
     .create_parameters(
       config,
@@ -151,6 +163,7 @@ async fn generate_html(
     let mut visitor = AssetInjector::new(
       &after_template_execution_data.head_tags,
       &after_template_execution_data.body_tags,
+      config.inject,
     );
     current_ast.visit_mut_with(&mut visitor);
   }
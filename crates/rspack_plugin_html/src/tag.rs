@@ -97,6 +97,18 @@ where
 }
 
 impl HtmlPluginTag {
+  pub fn set_attribute(&mut self, attribute: HtmlPluginAttribute) {
+    if let Some(existing) = self
+      .attributes
+      .iter_mut()
+      .find(|attr| attr.attr_name == attribute.attr_name)
+    {
+      *existing = attribute;
+    } else {
+      self.attributes.push(attribute);
+    }
+  }
+
   pub fn create_style(href: &str) -> HtmlPluginTag {
     HtmlPluginTag {
       tag_name: "link".to_string(),
@@ -287,3 +299,70 @@ impl From<HtmlPluginTag> for Element {
     }
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn attribute(name: &str, value: Option<&str>) -> HtmlPluginAttribute {
+    HtmlPluginAttribute {
+      attr_name: name.to_string(),
+      attr_value: value.map(ToOwned::to_owned),
+    }
+  }
+
+  fn attributes_of(tag: &HtmlPluginTag) -> Vec<(&str, Option<&str>)> {
+    tag
+      .attributes
+      .iter()
+      .map(|attr| (attr.attr_name.as_str(), attr.attr_value.as_deref()))
+      .collect()
+  }
+
+  #[test]
+  fn test_set_attribute_overrides_in_place() {
+    let mut tag = HtmlPluginTag::create_script("main.js", &HtmlScriptLoading::Module);
+    tag.set_attribute(attribute("type", Some("text/javascript")));
+    tag.set_attribute(attribute("nonce", Some("abc")));
+    tag.set_attribute(attribute("nonce", Some("def")));
+    tag.set_attribute(attribute("async", None));
+    assert_eq!(
+      attributes_of(&tag),
+      vec![
+        ("type", Some("text/javascript")),
+        ("src", Some("main.js")),
+        ("nonce", Some("def")),
+        ("async", None),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_set_attribute_overrides_boolean_attributes() {
+    let mut tag = HtmlPluginTag::create_script("main.js", &HtmlScriptLoading::Defer);
+    tag.set_attribute(attribute("defer", Some("false")));
+    assert_eq!(
+      attributes_of(&tag),
+      vec![("defer", Some("false")), ("src", Some("main.js"))]
+    );
+  }
+
+  #[test]
+  fn test_element_of_tag_has_sorted_attributes() {
+    let mut tag = HtmlPluginTag::create_style("main.css");
+    tag.set_attribute(attribute("fetchpriority", Some("high")));
+    let element = Element::from(tag);
+    assert_eq!(
+      element
+        .attributes
+        .iter()
+        .map(|attr| (&*attr.name, attr.value.as_deref()))
+        .collect::<Vec<_>>(),
+      vec![
+        ("fetchpriority", Some("high")),
+        ("href", Some("main.css")),
+        ("rel", Some("stylesheet")),
+      ]
+    );
+  }
+}
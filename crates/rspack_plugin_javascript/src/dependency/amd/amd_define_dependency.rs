@@ -0,0 +1,279 @@
+use rspack_core::{
+  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, RuntimeGlobals, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
+};
+
+use super::LocalModule;
+
+/// Which arguments a `define()` call got, see [AMDDefineDependency::branch].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Branch {
+  /// `define(function () {})`
+  Function,
+  /// `define({})`
+  Object,
+  /// `define(value)`, which might be a factory or an object
+  ObjectOrFunction,
+  /// `define([], function () {})`
+  ArrayFunction,
+  /// `define([], {})`
+  ArrayObject,
+  /// `define([], value)`
+  ArrayObjectOrFunction,
+}
+
+impl Branch {
+  /// The declarations inserted at the top of the module and the code the call is replaced with,
+  /// each `#` is a placeholder for one of the arguments.
+  fn definition(&self) -> (&'static str, &'static str, RuntimeGlobals) {
+    match self {
+      Self::Function => (
+        "var __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_RESULT__ = (#).call(exports, __webpack_require__, exports, module),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::REQUIRE
+          .union(RuntimeGlobals::EXPORTS)
+          .union(RuntimeGlobals::MODULE),
+      ),
+      Self::Object => ("", "!(module.exports = #)", RuntimeGlobals::MODULE),
+      Self::ObjectOrFunction => (
+        "var __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.call(exports, __webpack_require__, exports, module)) :\n\t\t__WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::REQUIRE
+          .union(RuntimeGlobals::EXPORTS)
+          .union(RuntimeGlobals::MODULE),
+      ),
+      Self::ArrayFunction => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_RESULT__ = (#).apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::EXPORTS.union(RuntimeGlobals::MODULE),
+      ),
+      Self::ArrayObject => ("", "!(#, module.exports = #)", RuntimeGlobals::MODULE),
+      Self::ArrayObjectOrFunction => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, __WEBPACK_AMD_DEFINE_FACTORY__, __WEBPACK_AMD_DEFINE_RESULT__;",
+        "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, __WEBPACK_AMD_DEFINE_FACTORY__ = (#),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ = (typeof __WEBPACK_AMD_DEFINE_FACTORY__ === 'function' ?\n\t\t(__WEBPACK_AMD_DEFINE_FACTORY__.apply(exports, __WEBPACK_AMD_DEFINE_ARRAY__)) : __WEBPACK_AMD_DEFINE_FACTORY__),\n\t\t__WEBPACK_AMD_DEFINE_RESULT__ !== undefined && (module.exports = __WEBPACK_AMD_DEFINE_RESULT__))",
+        RuntimeGlobals::EXPORTS.union(RuntimeGlobals::MODULE),
+      ),
+    }
+  }
+
+  /// Like [Branch::definition], but the result is stored in the variable of a used local module,
+  /// `XXX` is a placeholder for the variable and `YYY` for the name of the module.
+  fn local_definition(&self) -> (&'static str, &'static str, RuntimeGlobals) {
+    match self {
+      Self::Function => (
+        "var XXX, XXXmodule;",
+        "!(XXXmodule = { id: YYY, exports: {}, loaded: false }, XXX = (#).call(XXXmodule.exports, __webpack_require__, XXXmodule.exports, XXXmodule), XXXmodule.loaded = true, XXX === undefined && (XXX = XXXmodule.exports))",
+        RuntimeGlobals::REQUIRE.union(RuntimeGlobals::MODULE),
+      ),
+      Self::Object => ("var XXX;", "!(XXX = #)", RuntimeGlobals::empty()),
+      Self::ObjectOrFunction => (
+        "var XXX, XXXfactory, XXXmodule;",
+        "!(XXXfactory = (#), (typeof XXXfactory === 'function' ? ((XXXmodule = { id: YYY, exports: {}, loaded: false }), (XXX = XXXfactory.call(XXXmodule.exports, __webpack_require__, XXXmodule.exports, XXXmodule)), (XXXmodule.loaded = true), XXX === undefined && (XXX = XXXmodule.exports)) : XXX = XXXfactory))",
+        RuntimeGlobals::REQUIRE.union(RuntimeGlobals::MODULE),
+      ),
+      Self::ArrayFunction => (
+        "var __WEBPACK_AMD_DEFINE_ARRAY__, XXX, XXXexports;",
+        "!(__WEBPACK_AMD_DEFINE_ARRAY__ = #, XXX = (#).apply(XXXexports = {}, __WEBPACK_AMD_DEFINE_ARRAY__), XXX === undefined && (XXX = XXXexports))",
+        RuntimeGlobals::empty(),
+      ),
+      Self::ArrayObject => ("var XXX;", "!(#, XXX = #)", RuntimeGlobals::empty()),
+      Self::ArrayObjectOrFunction => (
+        "var XXXarray, XXXfactory, XXXexports, XXX;",
+        "!(XXXarray = #, XXXfactory = (#), (typeof XXXfactory === 'function' ? ((XXX = XXXfactory.apply(XXXexports = {}, XXXarray)), XXX === undefined && (XXX = XXXexports)) : (XXX = XXXfactory)))",
+        RuntimeGlobals::empty(),
+      ),
+    }
+  }
+}
+
+/// Rewrites a `define(...)` call into an assignment of `module.exports`.
+#[derive(Debug, Clone)]
+pub struct AMDDefineDependency {
+  id: DependencyId,
+  range: (u32, u32),
+  array_range: Option<(u32, u32)>,
+  function_range: Option<(u32, u32)>,
+  object_range: Option<(u32, u32)>,
+  /// The local module of a named `define("name", ...)`
+  local_module: Option<LocalModule>,
+}
+
+impl AMDDefineDependency {
+  pub fn new(
+    range: (u32, u32),
+    array_range: Option<(u32, u32)>,
+    function_range: Option<(u32, u32)>,
+    object_range: Option<(u32, u32)>,
+    local_module: Option<LocalModule>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      range,
+      array_range,
+      function_range,
+      object_range,
+      local_module,
+    }
+  }
+
+  pub fn local_module_mut(&mut self) -> Option<&mut LocalModule> {
+    self.local_module.as_mut()
+  }
+
+  /// The local module is only stored in its variable when the module requires it,
+  /// otherwise the result is exported as usual.
+  fn used_local_module(&self) -> Option<&LocalModule> {
+    self
+      .local_module
+      .as_ref()
+      .filter(|local_module| local_module.is_used())
+  }
+
+  fn branch(&self) -> Branch {
+    match (
+      self.array_range.is_some(),
+      self.object_range.is_some(),
+      self.function_range.is_some(),
+    ) {
+      (false, false, _) => Branch::Function,
+      (false, true, false) => Branch::Object,
+      (false, true, true) => Branch::ObjectOrFunction,
+      (true, false, _) => Branch::ArrayFunction,
+      (true, true, false) => Branch::ArrayObject,
+      (true, true, true) => Branch::ArrayObjectOrFunction,
+    }
+  }
+}
+
+impl Dependency for AMDDefineDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdDefine
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for AMDDefineDependency {}
+impl AsContextDependency for AMDDefineDependency {}
+
+impl DependencyTemplate for AMDDefineDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let local_module = self.used_local_module();
+    let (definition, content, requirements) = if local_module.is_some() {
+      self.branch().local_definition()
+    } else {
+      self.branch().definition()
+    };
+    code_generatable_context
+      .runtime_requirements
+      .insert(requirements);
+
+    let (definition, content) = match local_module {
+      Some(local_module) => {
+        let variable_name = local_module.variable_name();
+        (
+          definition.replace("XXX", &variable_name),
+          content.replace("XXX", &variable_name),
+        )
+      }
+      None => (definition.to_string(), content.to_string()),
+    };
+    if !definition.is_empty() {
+      source.insert(0, &definition, None);
+    }
+    // the name is only filled in after splitting, as it may contain a `#`
+    let name = local_module
+      .map(|local_module| serde_json::to_string(local_module.name()).expect("should be json"))
+      .unwrap_or_default();
+    let mut texts = content.split('#').map(|text| text.replace("YYY", &name));
+    let mut current = self.range.0;
+    if let Some(array_range) = self.array_range {
+      source.replace(
+        current,
+        array_range.0,
+        &texts.next().unwrap_or_default(),
+        None,
+      );
+      current = array_range.1;
+    }
+    if let Some(object_range) = self.object_range {
+      source.replace(
+        current,
+        object_range.0,
+        &texts.next().unwrap_or_default(),
+        None,
+      );
+      current = object_range.1;
+    } else if let Some(function_range) = self.function_range {
+      source.replace(
+        current,
+        function_range.0,
+        &texts.next().unwrap_or_default(),
+        None,
+      );
+      current = function_range.1;
+    }
+    source.replace(
+      current,
+      self.range.1,
+      &texts.next().unwrap_or_default(),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_branch() {
+    let dep =
+      |array, function, object| AMDDefineDependency::new((0, 10), array, function, object, None);
+    assert_eq!(dep(None, Some((7, 9)), None).branch(), Branch::Function);
+    assert_eq!(dep(None, None, Some((7, 9))).branch(), Branch::Object);
+    assert_eq!(
+      dep(None, Some((7, 9)), Some((7, 9))).branch(),
+      Branch::ObjectOrFunction
+    );
+    assert_eq!(
+      dep(Some((7, 8)), Some((8, 9)), None).branch(),
+      Branch::ArrayFunction
+    );
+    assert_eq!(
+      dep(Some((7, 8)), None, Some((8, 9))).branch(),
+      Branch::ArrayObject
+    );
+    assert_eq!(
+      dep(Some((7, 8)), Some((8, 9)), Some((8, 9))).branch(),
+      Branch::ArrayObjectOrFunction
+    );
+  }
+}
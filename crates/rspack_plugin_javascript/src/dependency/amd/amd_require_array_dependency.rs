@@ -0,0 +1,108 @@
+use rspack_core::{
+  module_raw, AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, RuntimeGlobals, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
+};
+
+#[derive(Debug, Clone)]
+pub enum AMDRequireArrayItem {
+  /// `require`, `exports` or `module`
+  Special(&'static str),
+  /// An [super::AMDRequireItemDependency] and its request
+  Dependency(DependencyId, String),
+  /// The variable of a [super::LocalModule]
+  LocalModule(String),
+}
+
+/// Replaces an array of constant requests with an array of the required modules.
+#[derive(Debug, Clone)]
+pub struct AMDRequireArrayDependency {
+  id: DependencyId,
+  items: Vec<AMDRequireArrayItem>,
+  range: (u32, u32),
+}
+
+impl AMDRequireArrayDependency {
+  pub fn new(items: Vec<AMDRequireArrayItem>, range: (u32, u32)) -> Self {
+    Self {
+      id: DependencyId::new(),
+      items,
+      range,
+    }
+  }
+}
+
+impl Dependency for AMDRequireArrayDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireArray
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for AMDRequireArrayDependency {}
+impl AsContextDependency for AMDRequireArrayDependency {}
+
+impl DependencyTemplate for AMDRequireArrayDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let items = self
+      .items
+      .iter()
+      .map(|item| match item {
+        AMDRequireArrayItem::Special("require") => {
+          runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+          RuntimeGlobals::REQUIRE.name().to_string()
+        }
+        AMDRequireArrayItem::Special(name) => {
+          runtime_requirements.insert(if *name == "module" {
+            RuntimeGlobals::MODULE
+          } else {
+            RuntimeGlobals::EXPORTS
+          });
+          name.to_string()
+        }
+        AMDRequireArrayItem::Dependency(id, request) => {
+          module_raw(compilation, runtime_requirements, id, request, false)
+        }
+        AMDRequireArrayItem::LocalModule(variable_name) => variable_name.clone(),
+      })
+      .collect::<Vec<_>>();
+    source.replace(
+      self.range.0,
+      self.range.1,
+      &format!("[{}]", items.join(", ")),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
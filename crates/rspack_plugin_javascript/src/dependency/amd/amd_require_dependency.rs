@@ -0,0 +1,177 @@
+use rspack_core::{
+  block_promise, AsContextDependency, AsModuleDependency, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+
+/// Loads the chunk of the block created for a callback form `require([...], callback, errorCallback)`,
+/// and calls the callback with the required modules once it is loaded.
+#[derive(Debug, Clone)]
+pub struct AMDRequireDependency {
+  id: DependencyId,
+  outer_range: (u32, u32),
+  array_range: Option<(u32, u32)>,
+  function_range: Option<(u32, u32)>,
+  error_callback_range: Option<(u32, u32)>,
+  function_bind_this: bool,
+  error_callback_bind_this: bool,
+}
+
+impl AMDRequireDependency {
+  pub fn new(
+    outer_range: (u32, u32),
+    array_range: Option<(u32, u32)>,
+    function_range: Option<(u32, u32)>,
+    error_callback_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      outer_range,
+      array_range,
+      function_range,
+      error_callback_range,
+      function_bind_this: false,
+      error_callback_bind_this: false,
+    }
+  }
+
+  /// Whether `this` should be bound to the callbacks, as they are wrapped into other functions.
+  pub fn set_bind_this(&mut self, function_bind_this: bool, error_callback_bind_this: bool) {
+    self.function_bind_this = function_bind_this;
+    self.error_callback_bind_this = error_callback_bind_this;
+  }
+}
+
+impl Dependency for AMDRequireDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequire
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for AMDRequireDependency {}
+impl AsContextDependency for AMDRequireDependency {}
+
+impl DependencyTemplate for AMDRequireDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let module_graph = compilation.get_module_graph();
+    let block = module_graph.get_parent_block(&self.id);
+    let promise = block_promise(block, runtime_requirements, compilation, "AMD require");
+    let bind_this = |bind: bool| if bind { ".bind(this)" } else { "" };
+
+    match (
+      self.array_range,
+      self.function_range,
+      self.error_callback_range,
+    ) {
+      (Some(array_range), None, _) => {
+        runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+        source.replace(
+          self.outer_range.0,
+          array_range.0,
+          &format!("{promise}.then(function() {{"),
+          None,
+        );
+        source.replace(
+          array_range.1,
+          self.outer_range.1,
+          &format!(";}})['catch']({})", RuntimeGlobals::UNCAUGHT_ERROR_HANDLER),
+          None,
+        );
+      }
+      (None, Some(function_range), _) => {
+        runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+        source.replace(
+          self.outer_range.0,
+          function_range.0,
+          &format!("{promise}.then(("),
+          None,
+        );
+        source.replace(
+          function_range.1,
+          self.outer_range.1,
+          &format!(
+            ").bind(exports, {}, exports, module))['catch']({})",
+            RuntimeGlobals::REQUIRE,
+            RuntimeGlobals::UNCAUGHT_ERROR_HANDLER
+          ),
+          None,
+        );
+      }
+      (Some(array_range), Some(function_range), error_callback_range) => {
+        source.replace(
+          self.outer_range.0,
+          array_range.0,
+          &format!("{promise}.then(function() {{ "),
+          None,
+        );
+        source.insert(array_range.0, "var __WEBPACK_AMD_REQUIRE_ARRAY__ = ", None);
+        source.replace(array_range.1, function_range.0, "; (", None);
+        source.insert(
+          function_range.1,
+          ").apply(null, __WEBPACK_AMD_REQUIRE_ARRAY__);",
+          None,
+        );
+        if let Some(error_callback_range) = error_callback_range {
+          source.replace(
+            function_range.1,
+            error_callback_range.0,
+            &format!("}}{})['catch'](", bind_this(self.function_bind_this)),
+            None,
+          );
+          source.replace(
+            error_callback_range.1,
+            self.outer_range.1,
+            &format!("{})", bind_this(self.error_callback_bind_this)),
+            None,
+          );
+        } else {
+          runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+          source.replace(
+            function_range.1,
+            self.outer_range.1,
+            &format!(
+              "}}{})['catch']({})",
+              bind_this(self.function_bind_this),
+              RuntimeGlobals::UNCAUGHT_ERROR_HANDLER
+            ),
+            None,
+          );
+        }
+      }
+      (None, None, _) => {}
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
@@ -0,0 +1,107 @@
+use rspack_core::{
+  module_raw, AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
+  DependencyTemplate, DependencyType, ModuleDependency, RuntimeSpec, TemplateContext,
+  TemplateReplaceSource,
+};
+
+/// A module requested by the array of an AMD `define()` or `require()`.
+///
+/// Items of arrays with only constant strings are rendered by [super::AMDRequireArrayDependency]
+/// and have no range, other items are replaced in place.
+#[derive(Debug, Clone)]
+pub struct AMDRequireItemDependency {
+  id: DependencyId,
+  request: String,
+  range: Option<(u32, u32)>,
+  optional: bool,
+}
+
+impl AMDRequireItemDependency {
+  pub fn new(request: String, range: Option<(u32, u32)>, optional: bool) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      range,
+      optional,
+    }
+  }
+}
+
+impl Dependency for AMDRequireItemDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Amd
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::AmdRequireItem
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+}
+
+impl ModuleDependency for AMDRequireItemDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn get_optional(&self) -> bool {
+    self.optional
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl DependencyTemplate for AMDRequireItemDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let Some(range) = self.range else {
+      return;
+    };
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    source.replace(
+      range.0,
+      range.1,
+      &module_raw(
+        compilation,
+        runtime_requirements,
+        &self.id,
+        &self.request,
+        false,
+      ),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
+
+impl AsContextDependency for AMDRequireItemDependency {}
@@ -0,0 +1,39 @@
+/// A module defined with a name by `define("name", ...)`, the module that defines it can
+/// require it by that name.
+#[derive(Debug, Clone)]
+pub struct LocalModule {
+  name: String,
+  idx: usize,
+  used: bool,
+}
+
+impl LocalModule {
+  pub fn new(name: String, idx: usize) -> Self {
+    Self {
+      name,
+      idx,
+      used: false,
+    }
+  }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn idx(&self) -> usize {
+    self.idx
+  }
+
+  pub fn flag_used(&mut self) {
+    self.used = true;
+  }
+
+  pub fn is_used(&self) -> bool {
+    self.used
+  }
+
+  /// The variable the value of the local module is stored in.
+  pub fn variable_name(&self) -> String {
+    format!("__WEBPACK_LOCAL_MODULE_{}__", self.idx)
+  }
+}
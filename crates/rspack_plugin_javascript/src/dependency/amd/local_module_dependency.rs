@@ -0,0 +1,68 @@
+use rspack_core::{
+  AsContextDependency, AsModuleDependency, Compilation, Dependency, DependencyId,
+  DependencyTemplate, DependencyType, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+
+use super::LocalModule;
+
+/// Replaces a request of a [LocalModule] with the variable its value is stored in.
+#[derive(Debug, Clone)]
+pub struct LocalModuleDependency {
+  id: DependencyId,
+  local_module: LocalModule,
+  range: (u32, u32),
+}
+
+impl LocalModuleDependency {
+  pub fn new(local_module: LocalModule, range: (u32, u32)) -> Self {
+    Self {
+      id: DependencyId::new(),
+      local_module,
+      range,
+    }
+  }
+}
+
+impl Dependency for LocalModuleDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::LocalModule
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for LocalModuleDependency {}
+impl AsContextDependency for LocalModuleDependency {}
+
+impl DependencyTemplate for LocalModuleDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(
+      self.range.0,
+      self.range.1,
+      &self.local_module.variable_name(),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
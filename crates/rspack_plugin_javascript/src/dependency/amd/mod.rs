@@ -0,0 +1,13 @@
+mod amd_define_dependency;
+mod amd_require_array_dependency;
+mod amd_require_dependency;
+mod amd_require_item_dependency;
+mod local_module;
+mod local_module_dependency;
+
+pub use amd_define_dependency::AMDDefineDependency;
+pub use amd_require_array_dependency::{AMDRequireArrayDependency, AMDRequireArrayItem};
+pub use amd_require_dependency::AMDRequireDependency;
+pub use amd_require_item_dependency::AMDRequireItemDependency;
+pub use local_module::LocalModule;
+pub use local_module_dependency::LocalModuleDependency;
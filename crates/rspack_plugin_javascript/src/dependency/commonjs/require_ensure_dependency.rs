@@ -0,0 +1,108 @@
+use rspack_core::{
+  block_promise, AsContextDependency, AsModuleDependency, Compilation, Dependency,
+  DependencyCategory, DependencyId, DependencyTemplate, DependencyType, RuntimeGlobals,
+  RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+
+/// Loads the chunk of the block created for `require.ensure(dependencies, callback[, errorCallback][, chunkName])`,
+/// and calls the callback with `__webpack_require__` once it is loaded.
+#[derive(Debug, Clone)]
+pub struct RequireEnsureDependency {
+  id: DependencyId,
+  range: (u32, u32),
+  content_range: (u32, u32),
+  error_handler_range: Option<(u32, u32)>,
+}
+
+impl RequireEnsureDependency {
+  pub fn new(
+    range: (u32, u32),
+    content_range: (u32, u32),
+    error_handler_range: Option<(u32, u32)>,
+  ) -> Self {
+    Self {
+      id: DependencyId::new(),
+      range,
+      content_range,
+      error_handler_range,
+    }
+  }
+}
+
+impl Dependency for RequireEnsureDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireEnsure
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::False
+  }
+}
+
+impl AsModuleDependency for RequireEnsureDependency {}
+impl AsContextDependency for RequireEnsureDependency {}
+
+impl DependencyTemplate for RequireEnsureDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let TemplateContext {
+      compilation,
+      runtime_requirements,
+      ..
+    } = code_generatable_context;
+    let module_graph = compilation.get_module_graph();
+    let block = module_graph.get_parent_block(&self.id);
+    let promise = block_promise(block, runtime_requirements, compilation, "require.ensure");
+
+    source.replace(
+      self.range.0,
+      self.content_range.0,
+      &format!("{promise}.then(("),
+      None,
+    );
+    if let Some(error_handler_range) = self.error_handler_range {
+      source.replace(
+        self.content_range.1,
+        error_handler_range.0,
+        &format!(").bind(null, {}))['catch'](", RuntimeGlobals::REQUIRE),
+        None,
+      );
+      source.replace(error_handler_range.1, self.range.1, ")", None);
+    } else {
+      runtime_requirements.insert(RuntimeGlobals::UNCAUGHT_ERROR_HANDLER);
+      source.replace(
+        self.content_range.1,
+        self.range.1,
+        &format!(
+          ").bind(null, {}))['catch']({})",
+          RuntimeGlobals::REQUIRE,
+          RuntimeGlobals::UNCAUGHT_ERROR_HANDLER
+        ),
+        None,
+      );
+    }
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
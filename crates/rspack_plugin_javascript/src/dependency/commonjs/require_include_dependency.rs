@@ -0,0 +1,91 @@
+use rspack_core::{
+  AsContextDependency, Compilation, Dependency, DependencyCategory, DependencyId,
+  DependencyTemplate, DependencyType, ErrorSpan, ExtendedReferencedExport, ModuleDependency,
+  ModuleGraph, RealDependencyLocation, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+
+/// `require.include(request)` adds the module to the chunk without executing it.
+#[derive(Debug, Clone)]
+pub struct RequireIncludeDependency {
+  id: DependencyId,
+  request: String,
+  range: RealDependencyLocation,
+}
+
+impl RequireIncludeDependency {
+  pub fn new(request: String, range: RealDependencyLocation) -> Self {
+    Self {
+      id: DependencyId::new(),
+      request,
+      range,
+    }
+  }
+}
+
+impl Dependency for RequireIncludeDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::CommonJS
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::RequireInclude
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.range.start, self.range.end))
+  }
+
+  fn get_referenced_exports(
+    &self,
+    _module_graph: &ModuleGraph,
+    _runtime: Option<&RuntimeSpec>,
+  ) -> Vec<ExtendedReferencedExport> {
+    vec![]
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+}
+
+impl ModuleDependency for RequireIncludeDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request;
+  }
+}
+
+impl DependencyTemplate for RequireIncludeDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    _code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(self.range.start, self.range.end, "undefined", None);
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
+
+impl AsContextDependency for RequireIncludeDependency {}
@@ -17,6 +17,7 @@ pub struct ImportContextDependency {
   range_callee: (u32, u32),
   resource_identifier: String,
   optional: bool,
+  weak: bool,
 }
 
 impl ImportContextDependency {
@@ -25,6 +26,7 @@ impl ImportContextDependency {
     range: RealDependencyLocation,
     range_callee: (u32, u32),
     optional: bool,
+    weak: bool,
   ) -> Self {
     let resource_identifier = create_resource_identifier_for_context_dependency(None, &options);
     Self {
@@ -34,6 +36,7 @@ impl ImportContextDependency {
       id: DependencyId::new(),
       resource_identifier,
       optional,
+      weak,
     }
   }
 }
@@ -85,6 +88,10 @@ impl ContextDependency for ImportContextDependency {
     self.optional
   }
 
+  fn weak(&self) -> bool {
+    self.weak
+  }
+
   fn type_prefix(&self) -> rspack_core::ContextTypePrefix {
     rspack_core::ContextTypePrefix::Import
   }
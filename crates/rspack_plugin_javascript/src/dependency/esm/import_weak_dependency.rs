@@ -0,0 +1,136 @@
+use rspack_core::{
+  module_namespace_promise, AsContextDependency, Compilation, Dependency, DependencyCategory,
+  DependencyId, DependencyTemplate, DependencyType, ErrorSpan, ImportAttributes, ModuleDependency,
+  RealDependencyLocation, RuntimeSpec, TemplateContext, TemplateReplaceSource,
+};
+use swc_core::ecma::atoms::Atom;
+
+use super::{
+  create_resource_identifier_for_esm_dependency,
+  import_dependency::create_import_dependency_referenced_exports,
+};
+
+/// `import(/* webpackMode: "weak" */ request)`, resolves to the module only when it is already
+/// available, it never adds the module to a chunk.
+#[derive(Debug, Clone)]
+pub struct ImportWeakDependency {
+  id: DependencyId,
+  request: Atom,
+  range: RealDependencyLocation,
+  referenced_exports: Option<Vec<Atom>>,
+  attributes: Option<ImportAttributes>,
+  resource_identifier: String,
+}
+
+impl ImportWeakDependency {
+  pub fn new(
+    request: Atom,
+    range: RealDependencyLocation,
+    referenced_exports: Option<Vec<Atom>>,
+    attributes: Option<ImportAttributes>,
+  ) -> Self {
+    let resource_identifier =
+      create_resource_identifier_for_esm_dependency(request.as_str(), attributes.as_ref());
+    Self {
+      request,
+      range,
+      id: DependencyId::new(),
+      referenced_exports,
+      attributes,
+      resource_identifier,
+    }
+  }
+}
+
+impl Dependency for ImportWeakDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn resource_identifier(&self) -> Option<&str> {
+    Some(&self.resource_identifier)
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::DynamicImportWeak
+  }
+
+  fn get_attributes(&self) -> Option<&ImportAttributes> {
+    self.attributes.as_ref()
+  }
+
+  fn span(&self) -> Option<ErrorSpan> {
+    Some(ErrorSpan::new(self.range.start, self.range.end))
+  }
+
+  fn get_referenced_exports(
+    &self,
+    module_graph: &rspack_core::ModuleGraph,
+    _runtime: Option<&rspack_core::RuntimeSpec>,
+  ) -> Vec<rspack_core::ExtendedReferencedExport> {
+    create_import_dependency_referenced_exports(&self.id, &self.referenced_exports, module_graph)
+  }
+
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+}
+
+impl ModuleDependency for ImportWeakDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.request
+  }
+
+  fn weak(&self) -> bool {
+    true
+  }
+
+  fn set_request(&mut self, request: String) {
+    self.request = request.into();
+  }
+}
+
+impl DependencyTemplate for ImportWeakDependency {
+  fn apply(
+    &self,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    source.replace(
+      self.range.start,
+      self.range.end,
+      module_namespace_promise(
+        code_generatable_context,
+        &self.id,
+        None,
+        &self.request,
+        self.dependency_type().as_str(),
+        true,
+      )
+      .as_str(),
+      None,
+    );
+  }
+
+  fn dependency_id(&self) -> Option<DependencyId> {
+    Some(self.id)
+  }
+
+  fn update_hash(
+    &self,
+    _hasher: &mut dyn std::hash::Hasher,
+    _compilation: &Compilation,
+    _runtime: Option<&RuntimeSpec>,
+  ) {
+  }
+}
+
+impl AsContextDependency for ImportWeakDependency {}
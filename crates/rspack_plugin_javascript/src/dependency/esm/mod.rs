@@ -8,6 +8,7 @@ mod harmony_import_dependency;
 mod harmony_import_specifier_dependency;
 mod import_dependency;
 mod import_eager_dependency;
+mod import_weak_dependency;
 mod provide_dependency;
 
 use rspack_core::DependencyCategory;
@@ -26,6 +27,7 @@ pub use self::harmony_import_dependency::HarmonyImportSideEffectDependency;
 pub use self::harmony_import_specifier_dependency::HarmonyImportSpecifierDependency;
 pub use self::import_dependency::ImportDependency;
 pub use self::import_eager_dependency::ImportEagerDependency;
+pub use self::import_weak_dependency::ImportWeakDependency;
 pub use self::provide_dependency::ProvideDependency;
 
 pub fn create_resource_identifier_for_esm_dependency(
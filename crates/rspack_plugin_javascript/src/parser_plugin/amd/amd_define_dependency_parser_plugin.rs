@@ -0,0 +1,97 @@
+use rspack_core::SpanExt;
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, Lit};
+
+use super::{add_local_module, param_names, process_array, SPECIAL_REQUESTS};
+use crate::dependency::AMDDefineDependency;
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::visitors::{expr_name, JavascriptParser};
+
+fn is_callable(expr: &Expr) -> bool {
+  matches!(expr, Expr::Fn(_) | Expr::Arrow(_))
+}
+
+fn range(expr: &Expr) -> (u32, u32) {
+  (expr.span().real_lo(), expr.span().real_hi())
+}
+
+/// Parses `define([name, ][dependencies, ]factory)`.
+pub struct AMDDefineDependencyParserPlugin;
+
+impl JavascriptParserPlugin for AMDDefineDependencyParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::DEFINE || expr.args.iter().any(|arg| arg.spread.is_some()) {
+      return None;
+    }
+    let args = expr.args.iter().map(|arg| &*arg.expr).collect::<Vec<_>>();
+    let (named_module, array, factory) = match args.as_slice() {
+      [factory] => (None, None, *factory),
+      [Expr::Lit(Lit::Str(name)), factory] => (Some(name.value.as_str()), None, *factory),
+      [array, factory] => (None, Some(*array), *factory),
+      [Expr::Lit(Lit::Str(name)), array, factory] => {
+        (Some(name.value.as_str()), Some(*array), *factory)
+      }
+      [_, array, factory] => (None, Some(*array), *factory),
+      _ => return None,
+    };
+    let array = match array {
+      Some(Expr::Array(array)) => Some(array),
+      Some(_) => return None,
+      None => None,
+    };
+    let (function, object) = if is_callable(factory) {
+      (Some(factory), None)
+    } else if matches!(factory, Expr::Object(_)) {
+      (None, Some(factory))
+    } else {
+      (Some(factory), Some(factory))
+    };
+
+    parser.bailout();
+
+    let params = param_names(factory);
+    let mut dependencies = vec![];
+    let renames = if let Some(array) = array {
+      let identifiers = process_array(parser, array, &mut dependencies, named_module);
+      params
+        .into_iter()
+        .zip(identifiers)
+        .filter_map(|(param, identifier)| Some((param?, identifier?)))
+        .collect::<Vec<_>>()
+    } else {
+      params
+        .into_iter()
+        .zip(SPECIAL_REQUESTS)
+        .filter_map(|(param, identifier)| Some((param?, identifier)))
+        .collect::<Vec<_>>()
+    };
+    parser.dependencies.extend(dependencies);
+    parser.walk_callback_with_renames(factory, &renames);
+
+    // added after walking the factory, the module can't require itself
+    let local_module = named_module.map(|name| add_local_module(parser, name));
+    parser
+      .presentational_dependencies
+      .push(Box::new(AMDDefineDependency::new(
+        (expr.span.real_lo(), expr.span.real_hi()),
+        array.map(|array| (array.span.real_lo(), array.span.real_hi())),
+        function.map(range),
+        object.map(range),
+        local_module,
+      )));
+    Some(true)
+  }
+
+  fn finish(&self, parser: &mut JavascriptParser) -> Option<bool> {
+    // the local modules are only known to be used once the whole module is parsed
+    for dep in parser.presentational_dependencies.iter_mut() {
+      if let Some(dep) = (**dep).as_any_mut().downcast_mut::<AMDDefineDependency>()
+        && let Some(local_module) = dep.local_module_mut()
+        && parser.local_modules[local_module.idx()].is_used()
+      {
+        local_module.flag_used();
+      }
+    }
+    None
+  }
+}
@@ -0,0 +1,129 @@
+use rspack_core::{ConstDependency, RuntimeGlobals, SpanExt};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{Expr, Ident, MemberExpr, UnaryExpr};
+
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+use crate::visitors::{expr_name, JavascriptParser};
+
+fn is_amd_options(for_name: &str) -> bool {
+  for_name == expr_name::DEFINE_AMD || for_name == expr_name::REQUIRE_AMD
+}
+
+fn add_amd_define(parser: &mut JavascriptParser, start: u32, end: u32) {
+  parser
+    .presentational_dependencies
+    .push(Box::new(ConstDependency::new(
+      start,
+      end,
+      RuntimeGlobals::AMD_DEFINE.name().into(),
+      Some(RuntimeGlobals::AMD_DEFINE),
+    )));
+}
+
+/// Gives `define` and the AMD options in `define.amd` and `require.amd` their runtime values.
+pub struct AMDPlugin;
+
+impl JavascriptParserPlugin for AMDPlugin {
+  fn member(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &MemberExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if !is_amd_options(for_name) {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        RuntimeGlobals::AMD_OPTIONS.name().into(),
+        Some(RuntimeGlobals::AMD_OPTIONS),
+      )));
+    Some(true)
+  }
+
+  fn evaluate_identifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    ident: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression> {
+    is_amd_options(ident).then(|| {
+      let root = ident.split('.').next().unwrap_or_default();
+      eval::evaluate_to_identifier(ident.to_string(), root.to_string(), Some(true), start, end)
+    })
+  }
+
+  fn evaluate_typeof(
+    &self,
+    _parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<BasicEvaluatedExpression> {
+    let value = if for_name == expr_name::DEFINE {
+      "function"
+    } else if is_amd_options(for_name) {
+      "object"
+    } else {
+      return None;
+    };
+    Some(eval::evaluate_to_string(
+      value.to_string(),
+      expr.span.real_lo(),
+      expr.span.real_hi(),
+    ))
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    let value = if for_name == expr_name::DEFINE {
+      "'function'"
+    } else if is_amd_options(for_name) {
+      "'object'"
+    } else {
+      return None;
+    };
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        value.into(),
+        None,
+      )));
+    Some(true)
+  }
+
+  fn identifier(
+    &self,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name != expr_name::DEFINE {
+      return None;
+    }
+    add_amd_define(parser, ident.span.real_lo(), ident.span.real_hi());
+    Some(true)
+  }
+
+  fn can_rename(&self, _parser: &mut JavascriptParser, str: &str) -> Option<bool> {
+    (str == expr_name::DEFINE).then_some(true)
+  }
+
+  fn rename(&self, parser: &mut JavascriptParser, expr: &Expr, str: &str) -> Option<bool> {
+    if str != expr_name::DEFINE {
+      return None;
+    }
+    add_amd_define(parser, expr.span().real_lo(), expr.span().real_hi());
+    Some(false)
+  }
+}
@@ -0,0 +1,112 @@
+use rspack_core::{
+  throw_missing_module_error_block, AsyncDependenciesBlock, ConstDependency, DependencyLocation,
+  RealDependencyLocation, SpanExt,
+};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, Lit};
+
+use super::{param_names, process_array, special_request};
+use crate::dependency::AMDRequireDependency;
+use crate::parser_plugin::JavascriptParserPlugin;
+use crate::visitors::{create_traceable_error, expr_name, JavascriptParser};
+
+/// Walks a callback of `require()`, returns whether `this` has to be bound to it.
+fn process_function_argument(parser: &mut JavascriptParser, expr: &Expr) -> bool {
+  let renames = param_names(expr)
+    .into_iter()
+    .flatten()
+    .filter_map(|param| special_request(param).map(|special| (param, special)))
+    .collect::<Vec<_>>();
+  parser.walk_callback_with_renames(expr, &renames);
+  !matches!(expr, Expr::Fn(_) | Expr::Arrow(_))
+}
+
+/// Replaces a `require(…, …)` whose dependencies are not an array with code throwing at runtime.
+fn add_unsupported(parser: &mut JavascriptParser, expr: &CallExpr) {
+  parser
+    .presentational_dependencies
+    .push(Box::new(ConstDependency::new(
+      expr.span.real_lo(),
+      expr.span.real_hi(),
+      format!(
+        "Object(function webpackMissingModule() {{ {} }}())",
+        throw_missing_module_error_block("unsupported")
+      )
+      .into(),
+      None,
+    )));
+  let line = parser.source_map.lookup_char_pos(expr.span.lo).line;
+  parser.errors.push(Box::new(create_traceable_error(
+    "Unsupported feature".into(),
+    format!("Cannot statically analyse 'require(…, …)' in line {line}"),
+    parser.source_file,
+    expr.span.into(),
+  )));
+}
+
+/// Parses `require(dependencies[, callback[, errorCallback]])`, the dependencies are loaded
+/// in an async block.
+pub struct AMDRequireDependenciesBlockParserPlugin;
+
+impl JavascriptParserPlugin for AMDRequireDependenciesBlockParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::REQUIRE
+      || !(1..=3).contains(&expr.args.len())
+      || expr.args.iter().any(|arg| arg.spread.is_some())
+    {
+      return None;
+    }
+    let Expr::Array(array) = &*expr.args[0].expr else {
+      // `require(request)` is a CommonJS require
+      if expr.args.len() == 1 {
+        return None;
+      }
+      add_unsupported(parser, expr);
+      return Some(true);
+    };
+
+    let mut dependencies = vec![];
+    process_array(parser, array, &mut dependencies, None);
+    let request = array
+      .elems
+      .iter()
+      .flatten()
+      .filter_map(|elem| elem.expr.as_lit())
+      .filter_map(|lit| match lit {
+        Lit::Str(str) => Some(str.value.to_string()),
+        _ => None,
+      })
+      .collect::<Vec<_>>()
+      .join(" ");
+
+    let callback = expr.args.get(1).map(|arg| &*arg.expr);
+    let error_callback = expr.args.get(2).map(|arg| &*arg.expr);
+    let range = |expr: &Expr| (expr.span().real_lo(), expr.span().real_hi());
+    let mut dep = AMDRequireDependency::new(
+      (expr.span.real_lo(), expr.span.real_hi()),
+      Some((array.span.real_lo(), array.span.real_hi())),
+      callback.map(range),
+      error_callback.map(range),
+    );
+    let function_bind_this = callback
+      .map(|callback| process_function_argument(parser, callback))
+      .unwrap_or_default();
+    let error_callback_bind_this = error_callback
+      .map(|error_callback| process_function_argument(parser, error_callback))
+      .unwrap_or_default();
+    dep.set_bind_this(function_bind_this, error_callback_bind_this);
+    dependencies.push(Box::new(dep));
+
+    let block = AsyncDependenciesBlock::new(
+      *parser.module_identifier,
+      Some(DependencyLocation::Real(
+        Into::<RealDependencyLocation>::into(expr.span).with_source(parser.source_map.clone()),
+      )),
+      None,
+      dependencies,
+      Some(request),
+    );
+    parser.blocks.push(Box::new(block));
+    Some(true)
+  }
+}
@@ -0,0 +1,206 @@
+mod amd_define_dependency_parser_plugin;
+mod amd_plugin;
+mod amd_require_dependencies_block_parser_plugin;
+
+use rspack_core::{BoxDependency, ConstDependency, Dependency, RuntimeGlobals, SpanExt};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{ArrayLit, Expr, Pat};
+
+pub use self::amd_define_dependency_parser_plugin::AMDDefineDependencyParserPlugin;
+pub use self::amd_plugin::AMDPlugin;
+pub use self::amd_require_dependencies_block_parser_plugin::AMDRequireDependenciesBlockParserPlugin;
+use crate::dependency::{
+  AMDRequireArrayDependency, AMDRequireArrayItem, AMDRequireItemDependency, LocalModule,
+  LocalModuleDependency,
+};
+use crate::visitors::JavascriptParser;
+
+const SPECIAL_REQUESTS: [&str; 3] = ["require", "exports", "module"];
+
+fn special_request(request: &str) -> Option<&'static str> {
+  SPECIAL_REQUESTS
+    .into_iter()
+    .find(|special| *special == request)
+}
+
+/// Resolve a relative request of a local module from the name of the module it is made in.
+fn lookup(parent: &str, request: &str) -> String {
+  if !request.starts_with('.') {
+    return request.to_string();
+  }
+  let mut path = parent.split('/').collect::<Vec<_>>();
+  path.pop();
+  for segment in request.split('/') {
+    match segment {
+      ".." => {
+        path.pop();
+      }
+      "." => {}
+      segment => path.push(segment),
+    }
+  }
+  path.join("/")
+}
+
+/// Add the local module of a named `define("name", ...)`.
+fn add_local_module(parser: &mut JavascriptParser, name: &str) -> LocalModule {
+  let local_module = LocalModule::new(name.to_string(), parser.local_modules.len());
+  parser.local_modules.push(local_module.clone());
+  local_module
+}
+
+/// Find the local module `request` refers to and flag it as used, `named_module` is the name
+/// of the module the request is made in.
+pub(crate) fn use_local_module(
+  parser: &mut JavascriptParser,
+  request: &str,
+  named_module: Option<&str>,
+) -> Option<LocalModule> {
+  let name = match named_module {
+    Some(named_module) => lookup(named_module, request),
+    None => request.to_string(),
+  };
+  let local_module = parser
+    .local_modules
+    .iter_mut()
+    .find(|local_module| local_module.name() == name)?;
+  local_module.flag_used();
+  Some(local_module.clone())
+}
+
+/// Create the dependencies of the requests in the array of an AMD `define()` or `require()`,
+/// module dependencies are pushed to `dependencies`, requests of local modules are resolved
+/// from `named_module`.
+///
+/// Returns which items of the array are `require`, `exports` or `module`.
+fn process_array(
+  parser: &mut JavascriptParser,
+  array: &ArrayLit,
+  dependencies: &mut Vec<BoxDependency>,
+  named_module: Option<&str>,
+) -> Vec<Option<&'static str>> {
+  let items = array
+    .elems
+    .iter()
+    .map(|elem| {
+      elem.as_ref().and_then(|elem| {
+        let param = parser.evaluate_expression(&elem.expr);
+        param
+          .is_string()
+          .then(|| (param.string().to_string(), &*elem.expr))
+      })
+    })
+    .collect::<Vec<_>>();
+
+  if items.iter().all(Option::is_some) {
+    // only constant requests, the whole array is replaced
+    let mut array_items = vec![];
+    let mut identifiers = vec![];
+    for (request, _) in items.into_iter().flatten() {
+      let special = special_request(&request);
+      identifiers.push(special);
+      if let Some(special) = special {
+        array_items.push(AMDRequireArrayItem::Special(special));
+      } else if let Some(local_module) = use_local_module(parser, &request, named_module) {
+        array_items.push(AMDRequireArrayItem::LocalModule(
+          local_module.variable_name(),
+        ));
+      } else {
+        let dep = AMDRequireItemDependency::new(request.clone(), None, parser.in_try);
+        array_items.push(AMDRequireArrayItem::Dependency(*dep.id(), request));
+        dependencies.push(Box::new(dep));
+      }
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(AMDRequireArrayDependency::new(
+        array_items,
+        (array.span.real_lo(), array.span.real_hi()),
+      )));
+    return identifiers;
+  }
+
+  array
+    .elems
+    .iter()
+    .zip(items)
+    .map(|(elem, item)| {
+      let Some((request, expr)) = item else {
+        if let Some(elem) = elem {
+          parser.walk_expression(&elem.expr);
+        }
+        return None;
+      };
+      let range = (expr.span().real_lo(), expr.span().real_hi());
+      let special = special_request(&request);
+      match special {
+        Some("require") => parser
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            range.0,
+            range.1,
+            RuntimeGlobals::REQUIRE.name().into(),
+            Some(RuntimeGlobals::REQUIRE),
+          ))),
+        Some(special) => parser
+          .presentational_dependencies
+          .push(Box::new(ConstDependency::new(
+            range.0,
+            range.1,
+            special.into(),
+            Some(if special == "module" {
+              RuntimeGlobals::MODULE
+            } else {
+              RuntimeGlobals::EXPORTS
+            }),
+          ))),
+        None => {
+          if let Some(local_module) = use_local_module(parser, &request, named_module) {
+            parser
+              .presentational_dependencies
+              .push(Box::new(LocalModuleDependency::new(local_module, range)));
+          } else {
+            dependencies.push(Box::new(AMDRequireItemDependency::new(
+              request,
+              Some(range),
+              parser.in_try,
+            )));
+          }
+        }
+      }
+      special
+    })
+    .collect()
+}
+
+/// Names of the parameters of a function expression, `None` for destructured ones.
+fn param_names(expr: &Expr) -> Vec<Option<&str>> {
+  fn name(pat: &Pat) -> Option<&str> {
+    pat.as_ident().map(|ident| ident.sym.as_str())
+  }
+  match expr {
+    Expr::Fn(expr) => expr
+      .function
+      .params
+      .iter()
+      .map(|param| name(&param.pat))
+      .collect(),
+    Expr::Arrow(expr) => expr.params.iter().map(name).collect(),
+    _ => vec![],
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_lookup() {
+    assert_eq!(lookup("my-dir/my-module", "other"), "other");
+    assert_eq!(lookup("my-dir/my-module", "./other"), "my-dir/other");
+    assert_eq!(
+      lookup("my-dir/my-other-dir/my-module", "../other"),
+      "my-dir/other"
+    );
+  }
+}
@@ -4,12 +4,14 @@ use rspack_core::{
 use rspack_core::{ContextNameSpaceObject, ContextOptions};
 use rspack_error::Severity;
 use swc_core::common::{Span, Spanned};
-use swc_core::ecma::ast::{CallExpr, Expr, Ident, Lit, MemberExpr, UnaryExpr};
+use swc_core::ecma::ast::{CallExpr, Expr, Ident, MemberExpr, UnaryExpr};
 
+use super::amd::use_local_module;
 use super::JavascriptParserPlugin;
-use crate::dependency::RequireHeaderDependency;
+use crate::dependency::RequireResolveContextDependency;
 use crate::dependency::{CommonJsFullRequireDependency, CommonJsRequireContextDependency};
 use crate::dependency::{CommonJsRequireDependency, RequireResolveDependency};
+use crate::dependency::{LocalModuleDependency, RequireHeaderDependency};
 use crate::utils::eval::{self, BasicEvaluatedExpression};
 use crate::visitors::{
   context_reg_exp, create_context_dependency, create_traceable_error, expr_matcher, expr_name,
@@ -47,22 +49,70 @@ fn create_commonjs_require_context_dependency(
   CommonJsRequireContextDependency::new(options, span.into(), (start, end), parser.in_try)
 }
 
+fn create_require_resolve_context_dependency(
+  parser: &mut JavascriptParser,
+  param: &BasicEvaluatedExpression,
+  expr: &Expr,
+  span: Span,
+  callee_span: Span,
+  weak: bool,
+) -> RequireResolveContextDependency {
+  let start = callee_span.real_lo();
+  let end = callee_span.real_hi();
+  let result = create_context_dependency(param, expr, parser);
+  let options = ContextOptions {
+    mode: if weak {
+      ContextMode::Weak
+    } else {
+      ContextMode::Sync
+    },
+    recursive: true,
+    reg_exp: context_reg_exp(&result.reg, "", None, parser),
+    include: None,
+    exclude: None,
+    category: DependencyCategory::CommonJS,
+    request: format!("{}{}{}", result.context, result.query, result.fragment),
+    context: result.context,
+    namespace_object: ContextNameSpaceObject::Unset,
+    group_options: None,
+    replaces: result.replaces,
+    start,
+    end,
+    referenced_exports: None,
+    attributes: None,
+  };
+  RequireResolveContextDependency::new(options, span.into(), (start, end), parser.in_try, weak)
+}
+
 pub struct CommonJsImportsParserPlugin;
 
 impl CommonJsImportsParserPlugin {
   fn add_require_resolve(&self, parser: &mut JavascriptParser, node: &CallExpr, weak: bool) {
-    if !node.args.is_empty()
-      && let Some(Lit::Str(str)) = node.args.first().and_then(|x| x.expr.as_lit())
-    {
+    let Some(arg) = node.args.first() else {
+      return;
+    };
+    let param = parser.evaluate_expression(&arg.expr);
+    if param.is_string() {
       let range: RealDependencyLocation = node.span.into();
       parser
         .dependencies
         .push(Box::new(RequireResolveDependency::new(
-          str.value.to_string(),
+          param.string().to_string(),
           range,
           weak,
           parser.in_try,
         )));
+    } else {
+      // `require.resolve("./" + name)` resolves to the id of a module in the context
+      let dep = create_require_resolve_context_dependency(
+        parser,
+        &param,
+        &arg.expr,
+        node.span,
+        node.callee.span(),
+        weak,
+      );
+      parser.dependencies.push(Box::new(dep));
     }
   }
 
@@ -176,7 +226,18 @@ impl CommonJsImportsParserPlugin {
       }
     }
 
-    // FIXME: should support `LocalModuleDependency`
+    if param.is_string()
+      && let Some(local_module) = use_local_module(parser, param.string(), None)
+    {
+      parser
+        .presentational_dependencies
+        .push(Box::new(LocalModuleDependency::new(
+          local_module,
+          (call_expr.span.real_lo(), call_expr.span.real_hi()),
+        )));
+      return Some(true);
+    }
+
     if self
       .process_require_item(parser, call_expr.span, &param)
       .is_none()
@@ -11,7 +11,9 @@ use swc_core::ecma::ast::{CallExpr, Callee};
 use swc_core::ecma::atoms::Atom;
 
 use super::JavascriptParserPlugin;
-use crate::dependency::{ImportContextDependency, ImportDependency, ImportEagerDependency};
+use crate::dependency::{
+  ImportContextDependency, ImportDependency, ImportEagerDependency, ImportWeakDependency,
+};
 use crate::utils::object_properties::{get_attributes, get_value_by_obj_prop};
 use crate::visitors::{
   context_reg_exp, create_context_dependency, create_traceable_error, parse_order_string,
@@ -117,6 +119,16 @@ impl JavascriptParserPlugin for ImportParserPlugin {
         parser.dependencies.push(Box::new(dep));
         return Some(true);
       }
+      if matches!(mode, DynamicImportMode::Weak) {
+        let dep = ImportWeakDependency::new(
+          param.string().as_str().into(),
+          node.span.into(),
+          exports,
+          attributes,
+        );
+        parser.dependencies.push(Box::new(dep));
+        return Some(true);
+      }
       let dep = Box::new(ImportDependency::new(
         param.string().as_str().into(),
         node.span.into(),
@@ -181,6 +193,7 @@ impl JavascriptParserPlugin for ImportParserPlugin {
           node.span().into(),
           (import_call.span.real_lo(), import_call.span.real_hi()),
           parser.in_try,
+          matches!(mode, DynamicImportMode::Weak),
         )));
       Some(true)
     }
@@ -1,3 +1,4 @@
+mod amd;
 mod api_plugin;
 mod check_var_decl;
 mod common_js_exports_parse_plugin;
@@ -21,6 +22,8 @@ mod javascript_meta_info_plugin;
 mod node_stuff_plugin;
 mod override_strict_plugin;
 mod require_context_dependency_parser_plugin;
+mod require_ensure_dependencies_block_parser_plugin;
+mod require_include_dependency_parser_plugin;
 mod r#trait;
 mod url_plugin;
 mod use_strict_plugin;
@@ -30,6 +33,9 @@ mod worker_plugin;
 pub mod define_plugin;
 pub mod provide_plugin;
 
+pub(crate) use self::amd::{
+  AMDDefineDependencyParserPlugin, AMDPlugin, AMDRequireDependenciesBlockParserPlugin,
+};
 pub(crate) use self::api_plugin::APIPlugin;
 pub(crate) use self::check_var_decl::CheckVarDeclaratorIdent;
 pub(crate) use self::common_js_exports_parse_plugin::CommonJsExportsParserPlugin;
@@ -54,6 +60,8 @@ pub(crate) use self::override_strict_plugin::OverrideStrictPlugin;
 pub(crate) use self::r#const::{is_logic_op, ConstPlugin};
 pub use self::r#trait::{BoxJavascriptParserPlugin, JavascriptParserPlugin};
 pub(crate) use self::require_context_dependency_parser_plugin::RequireContextDependencyParserPlugin;
+pub(crate) use self::require_ensure_dependencies_block_parser_plugin::RequireEnsureDependenciesBlockParserPlugin;
+pub(crate) use self::require_include_dependency_parser_plugin::RequireIncludeDependencyParserPlugin;
 pub(crate) use self::url_plugin::URLPlugin;
 pub(crate) use self::use_strict_plugin::UseStrictPlugin;
 pub(crate) use self::webpack_included_plugin::WebpackIsIncludedPlugin;
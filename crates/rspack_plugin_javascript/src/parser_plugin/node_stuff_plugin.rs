@@ -1,8 +1,17 @@
-use rspack_core::{get_context, CachedConstDependency, ConstDependency, RuntimeGlobals, SpanExt};
+use rspack_core::{
+  get_context, CachedConstDependency, ConstDependency, NodeDirnameOption, NodeGlobalOption,
+  RuntimeGlobals, SpanExt,
+};
+use rspack_error::Severity;
 use sugar_path::SugarPath;
+use swc_core::{common::Spanned, ecma::ast::Ident};
 
 use super::JavascriptParserPlugin;
-use crate::{dependency::ExternalModuleDependency, utils::eval};
+use crate::{
+  dependency::ExternalModuleDependency,
+  utils::eval,
+  visitors::{create_traceable_error, JavascriptParser},
+};
 
 const DIR_NAME: &str = "__dirname";
 const FILE_NAME: &str = "__filename";
@@ -10,11 +19,53 @@ const GLOBAL: &str = "global";
 
 pub struct NodeStuffPlugin;
 
+/// Warn about a Node.js feature which isn't available in browsers.
+fn node_stuff_in_web_warning(parser: &mut JavascriptParser, ident: &Ident, message: String) {
+  parser.warning_diagnostics.push(Box::new(
+    create_traceable_error(
+      "NodeStuffInWebError".into(),
+      message,
+      parser.source_file,
+      ident.span().into(),
+    )
+    .with_severity(Severity::Warn),
+  ));
+}
+
+/// The directory of the module relative to the context, the value of `__dirname` with `node.__dirname: true`.
+fn relative_dirname(parser: &JavascriptParser) -> Option<String> {
+  Some(
+    parser
+      .resource_data
+      .resource_path
+      .as_deref()?
+      .parent()?
+      .as_std_path()
+      .relative(&parser.compiler_options.context)
+      .to_string_lossy()
+      .to_string(),
+  )
+}
+
+/// The module relative to the context, the value of `__filename` with `node.__filename: true`.
+fn relative_filename(parser: &JavascriptParser) -> Option<String> {
+  Some(
+    parser
+      .resource_data
+      .resource_path
+      .as_deref()?
+      .as_std_path()
+      .relative(&parser.compiler_options.context)
+      .to_string_lossy()
+      .to_string(),
+  )
+}
+
 impl JavascriptParserPlugin for NodeStuffPlugin {
   fn identifier(
     &self,
-    parser: &mut crate::visitors::JavascriptParser,
-    ident: &swc_core::ecma::ast::Ident,
+    parser: &mut JavascriptParser,
+    ident: &Ident,
     _for_name: &str,
   ) -> Option<bool> {
     let Some(node_option) = parser.compiler_options.node.as_ref() else {
@@ -25,10 +76,17 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
       return None;
     }
     if str == DIR_NAME {
-      let dirname = match node_option.dirname.as_str() {
-        "mock" => Some("/".to_string()),
-        "warn-mock" => Some("/".to_string()),
-        "node-module" => {
+      let dirname = match node_option.dirname {
+        NodeDirnameOption::Mock => Some("/".to_string()),
+        NodeDirnameOption::WarnMock => {
+          node_stuff_in_web_warning(
+            parser,
+            ident,
+            format!("{DIR_NAME} is a Node.js feature and isn't available in browsers."),
+          );
+          Some("/".to_string())
+        }
+        NodeDirnameOption::NodeModule => {
           // `ExternalModuleDependency` extends `CachedConstDependency` in webpack.
           // We need to create two separate dependencies in Rspack.
           let external_url_dep = ExternalModuleDependency::new(
@@ -64,18 +122,8 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
           parser.presentational_dependencies.push(Box::new(const_dep));
           return Some(true);
         }
-        "true" => Some(
-          parser
-            .resource_data
-            .resource_path
-            .as_deref()?
-            .parent()?
-            .as_std_path()
-            .relative(&parser.compiler_options.context)
-            .to_string_lossy()
-            .to_string(),
-        ),
-        _ => None,
+        NodeDirnameOption::True => Some(relative_dirname(parser)?),
+        NodeDirnameOption::False | NodeDirnameOption::EvalOnly => None,
       };
       if let Some(dirname) = dirname {
         parser
@@ -89,10 +137,17 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
         return Some(true);
       }
     } else if str == FILE_NAME {
-      let filename = match node_option.filename.as_str() {
-        "mock" => Some("/index.js".to_string()),
-        "warn-mock" => Some("/index.js".to_string()),
-        "node-module" => {
+      let filename = match node_option.filename {
+        NodeDirnameOption::Mock => Some("/index.js".to_string()),
+        NodeDirnameOption::WarnMock => {
+          node_stuff_in_web_warning(
+            parser,
+            ident,
+            format!("{FILE_NAME} is a Node.js feature and isn't available in browsers."),
+          );
+          Some("/index.js".to_string())
+        }
+        NodeDirnameOption::NodeModule => {
           // `ExternalModuleDependency` extends `CachedConstDependency` in webpack.
           // We need to create two separate dependencies in Rspack.
           let external_dep = ExternalModuleDependency::new(
@@ -119,17 +174,8 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
           parser.presentational_dependencies.push(Box::new(const_dep));
           return Some(true);
         }
-        "true" => Some(
-          parser
-            .resource_data
-            .resource_path
-            .as_deref()?
-            .as_std_path()
-            .relative(&parser.compiler_options.context)
-            .to_string_lossy()
-            .to_string(),
-        ),
-        _ => None,
+        NodeDirnameOption::True => Some(relative_filename(parser)?),
+        NodeDirnameOption::False | NodeDirnameOption::EvalOnly => None,
       };
       if let Some(filename) = filename {
         parser
@@ -144,7 +190,15 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
           )));
         return Some(true);
       }
-    } else if str == GLOBAL && matches!(node_option.global.as_str(), "true" | "warn") {
+    } else if str == GLOBAL && node_option.global != NodeGlobalOption::False {
+      if node_option.global == NodeGlobalOption::Warn {
+        node_stuff_in_web_warning(
+          parser,
+          ident,
+          "The global namespace object is a Node.js feature and isn't available in browsers."
+            .to_string(),
+        );
+      }
       parser
         .presentational_dependencies
         .push(Box::new(ConstDependency::new(
@@ -160,19 +214,30 @@ impl JavascriptParserPlugin for NodeStuffPlugin {
 
   fn evaluate_identifier(
     &self,
-    parser: &mut crate::visitors::JavascriptParser,
+    parser: &mut JavascriptParser,
     ident: &str,
     start: u32,
     end: u32,
   ) -> Option<crate::utils::eval::BasicEvaluatedExpression> {
-    if ident == DIR_NAME {
-      Some(eval::evaluate_to_string(
-        get_context(parser.resource_data).as_str().to_string(),
-        start,
-        end,
-      ))
+    let node_option = parser.compiler_options.node.as_ref()?;
+    // evaluates to the value the identifier is replaced with, and to the real path when it's kept
+    let value = if ident == DIR_NAME {
+      match node_option.dirname {
+        NodeDirnameOption::Mock | NodeDirnameOption::WarnMock => "/".to_string(),
+        NodeDirnameOption::True => relative_dirname(parser)?,
+        NodeDirnameOption::EvalOnly => get_context(parser.resource_data).as_str().to_string(),
+        NodeDirnameOption::False | NodeDirnameOption::NodeModule => return None,
+      }
+    } else if ident == FILE_NAME {
+      match node_option.filename {
+        NodeDirnameOption::Mock | NodeDirnameOption::WarnMock => "/index.js".to_string(),
+        NodeDirnameOption::True => relative_filename(parser)?,
+        NodeDirnameOption::EvalOnly => parser.resource_data.resource_path.as_deref()?.to_string(),
+        NodeDirnameOption::False | NodeDirnameOption::NodeModule => return None,
+      }
     } else {
-      None
-    }
+      return None;
+    };
+    Some(eval::evaluate_to_string(value, start, end))
   }
 }
@@ -0,0 +1,360 @@
+use rspack_core::{
+  AsyncDependenciesBlock, BoxDependency, ChunkGroupOptions, ConstDependency, DependencyLocation,
+  GroupOptions, RealDependencyLocation, SpanExt,
+};
+use swc_core::common::Spanned;
+use swc_core::ecma::ast::{CallExpr, Expr, Pat, UnaryExpr};
+
+use super::JavascriptParserPlugin;
+use crate::dependency::{RequireEnsureDependency, RequireEnsureItemDependency};
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+use crate::visitors::{expr_name, JavascriptParser};
+
+/// Unwraps `function() {}` and `function() {}.bind(expr)`, returns the function and the
+/// argument of `bind`.
+fn get_function_expression(expr: &Expr) -> Option<(&Expr, Option<&Expr>)> {
+  match expr {
+    Expr::Paren(paren) => get_function_expression(&paren.expr),
+    Expr::Fn(_) | Expr::Arrow(_) => Some((expr, None)),
+    Expr::Call(call) => {
+      let member = call.callee.as_expr()?.as_member()?;
+      if call.args.len() != 1
+        || call.args[0].spread.is_some()
+        || !member
+          .prop
+          .as_ident()
+          .is_some_and(|prop| prop.sym == "bind")
+      {
+        return None;
+      }
+      let (function, None) = get_function_expression(&member.obj)? else {
+        return None;
+      };
+      Some((function, Some(&call.args[0].expr)))
+    }
+    _ => None,
+  }
+}
+
+/// Walks the function of a callback, a `require` parameter is bound to `__webpack_require__`
+/// so it still refers to the free variable.
+fn walk_function(parser: &mut JavascriptParser, function: &Expr) {
+  let has_require_param = |pat: &Pat| {
+    pat
+      .as_ident()
+      .is_some_and(|ident| ident.sym == expr_name::REQUIRE)
+  };
+  let renames = match function {
+    Expr::Fn(expr) => expr
+      .function
+      .params
+      .iter()
+      .any(|param| has_require_param(&param.pat)),
+    Expr::Arrow(expr) => expr.params.iter().any(has_require_param),
+    _ => false,
+  }
+  .then_some((expr_name::REQUIRE, expr_name::REQUIRE));
+  parser.walk_callback_with_renames(function, renames.as_slice());
+}
+
+/// Parses `require.ensure(dependencies, callback[, errorCallback][, chunkName])`, the dependencies
+/// and the requests in the callback are loaded in an async block.
+pub struct RequireEnsureDependenciesBlockParserPlugin;
+
+impl JavascriptParserPlugin for RequireEnsureDependenciesBlockParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::REQUIRE_ENSURE
+      || !(2..=4).contains(&expr.args.len())
+      || expr.args.iter().any(|arg| arg.spread.is_some())
+    {
+      return None;
+    }
+
+    let mut chunk_name = None;
+    if let Some(arg) = expr.args.get(3) {
+      let evaluated = parser.evaluate_expression(&arg.expr);
+      if !evaluated.is_string() {
+        return None;
+      }
+      chunk_name = Some(evaluated.string().to_string());
+    }
+    let mut error_expression_arg = None;
+    let mut error_expression = None;
+    if let Some(arg) = expr.args.get(2) {
+      error_expression_arg = Some(&*arg.expr);
+      error_expression = get_function_expression(&arg.expr);
+      if error_expression.is_none() && chunk_name.is_none() {
+        let evaluated = parser.evaluate_expression(&arg.expr);
+        if !evaluated.is_string() {
+          return None;
+        }
+        chunk_name = Some(evaluated.string().to_string());
+        error_expression_arg = None;
+      }
+    }
+
+    let dependencies_expr = parser.evaluate_expression(&expr.args[0].expr);
+    let requests = if dependencies_expr.is_array() {
+      dependencies_expr.items().iter().collect::<Vec<_>>()
+    } else {
+      vec![&dependencies_expr]
+    };
+    if !requests.iter().all(|request| request.is_string()) {
+      return None;
+    }
+    let requests = requests
+      .into_iter()
+      .map(|request| request.string().to_string())
+      .collect::<Vec<_>>();
+
+    let success_expression_arg = &*expr.args[1].expr;
+    let success_expression = get_function_expression(success_expression_arg);
+    if let Some((_, Some(bind_this))) = success_expression {
+      parser.walk_expression(bind_this);
+    }
+    if let Some((_, Some(bind_this))) = error_expression {
+      parser.walk_expression(bind_this);
+    }
+
+    let range = |expr: &Expr| (expr.span().real_lo(), expr.span().real_hi());
+    let mut dependencies: Vec<BoxDependency> = vec![Box::new(RequireEnsureDependency::new(
+      (expr.span.real_lo(), expr.span.real_hi()),
+      range(success_expression_arg),
+      error_expression_arg.map(range),
+    ))];
+    dependencies.extend(
+      requests
+        .into_iter()
+        .map(|request| Box::new(RequireEnsureItemDependency::new(request)) as BoxDependency),
+    );
+
+    // requests and async blocks in the callback belong to the block, so they are loaded with the chunk
+    let start = parser.dependencies.len();
+    let blocks_start = parser.blocks.len();
+    if let Some((function, _)) = success_expression {
+      walk_function(parser, function);
+    }
+    dependencies.extend(parser.dependencies.drain(start..));
+    let nested_blocks = parser.blocks.drain(blocks_start..).collect::<Vec<_>>();
+
+    let mut block = AsyncDependenciesBlock::new(
+      *parser.module_identifier,
+      Some(DependencyLocation::Real(
+        Into::<RealDependencyLocation>::into(expr.span).with_source(parser.source_map.clone()),
+      )),
+      None,
+      dependencies,
+      None,
+    );
+    block.set_group_options(GroupOptions::ChunkGroup(ChunkGroupOptions::new(
+      chunk_name, None, None, None,
+    )));
+    for nested_block in nested_blocks {
+      block.add_block(nested_block);
+    }
+    parser.blocks.push(Box::new(block));
+
+    if success_expression.is_none() {
+      parser.walk_expression(success_expression_arg);
+    }
+    if let Some((function, _)) = error_expression {
+      walk_function(parser, function);
+    } else if let Some(error_expression_arg) = error_expression_arg {
+      parser.walk_expression(error_expression_arg);
+    }
+    Some(true)
+  }
+
+  fn evaluate_typeof(
+    &self,
+    _parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<BasicEvaluatedExpression> {
+    (for_name == expr_name::REQUIRE_ENSURE).then(|| {
+      eval::evaluate_to_string(
+        "function".to_string(),
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+      )
+    })
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name != expr_name::REQUIRE_ENSURE {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        "'function'".into(),
+        None,
+      )));
+    Some(true)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_core::{
+    testing::compiler_options, BoxPlugin, Chunk, ChunkGroupUkey, Compilation, Compiler,
+    EntryOptions,
+  };
+  use rspack_fs::MemoryFileSystem;
+  use rspack_ids::{NaturalChunkIdsPlugin, NaturalModuleIdsPlugin};
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_entry::EntryPlugin;
+
+  use crate::JsPlugin;
+
+  /// Builds the `index.js` of `files`, the modules of the other files export their names.
+  async fn build(files: &[(&str, &str)]) -> Compiler<MemoryFileSystem> {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    for (name, content) in files {
+      std::fs::write(dir.path().join(name), content).expect("should write file");
+    }
+    let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+
+    let options = compiler_options(context);
+    let plugins: Vec<BoxPlugin> = vec![
+      Box::new(EntryPlugin::new(
+        options.context.clone(),
+        context.join("index.js").to_string(),
+        EntryOptions {
+          name: Some("main".to_string()),
+          ..Default::default()
+        },
+      )),
+      Box::<JsPlugin>::default(),
+      Box::<NaturalModuleIdsPlugin>::default(),
+      Box::<NaturalChunkIdsPlugin>::default(),
+    ];
+    let mut compiler = Compiler::new(options, plugins, MemoryFileSystem::default(), None, None);
+    compiler.build().await.expect("should build");
+    assert_eq!(compiler.compilation.get_errors().count(), 0);
+    compiler
+  }
+
+  /// The chunks of the module of the file `name`.
+  fn chunks_of<'a>(compilation: &'a Compilation, name: &str) -> Vec<&'a Chunk> {
+    let module = compilation
+      .get_module_graph()
+      .modules()
+      .into_keys()
+      .find(|module| module.as_str().ends_with(&format!("/{name}")))
+      .unwrap_or_else(|| panic!("should have module {name}"));
+    compilation
+      .chunk_graph
+      .get_module_chunks(module)
+      .iter()
+      .map(|chunk| compilation.chunk_by_ukey.expect_get(chunk))
+      .collect()
+  }
+
+  /// The chunk group of the only chunk of the module of the file `name`.
+  fn chunk_group_of(compilation: &Compilation, name: &str) -> ChunkGroupUkey {
+    let chunks = chunks_of(compilation, name);
+    assert_eq!(chunks.len(), 1, "{name} should be in a single chunk");
+    *chunks[0]
+      .groups
+      .iter()
+      .next()
+      .expect("should have chunk group")
+  }
+
+  fn is_initial(compilation: &Compilation, chunk: &Chunk) -> bool {
+    chunk.can_be_initial(&compilation.chunk_group_by_ukey)
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_nested_require_ensure() {
+    let compiler = build(&[
+      (
+        "index.js",
+        "require.ensure(['./a'], function() {
+          require.ensure([], function() {
+            require.ensure(['./a'], function() {
+              require.ensure([], function() { require('./b'); });
+            });
+          });
+        });",
+      ),
+      ("a.js", "module.exports = 'a';"),
+      ("b.js", "module.exports = 'b';"),
+    ])
+    .await;
+    let compilation = &compiler.compilation;
+
+    // `a` is available in the chunks of the nested blocks from the chunk of the outer block
+    let a = chunks_of(compilation, "a.js");
+    assert_eq!(a.len(), 1);
+    assert!(!is_initial(compilation, a[0]));
+    let b = chunk_group_of(compilation, "b.js");
+    assert!(!is_initial(compilation, chunks_of(compilation, "b.js")[0]));
+    assert!(compilation
+      .chunk_group_by_ukey
+      .expect_get(&b)
+      .ancestors(&compilation.chunk_group_by_ukey)
+      .contains(&chunk_group_of(compilation, "a.js")));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_import_in_require_ensure() {
+    let compiler = build(&[
+      (
+        "index.js",
+        "require.ensure(['./a'], function(require) {
+          require('./a');
+          import('./b');
+        });",
+      ),
+      ("a.js", "module.exports = 'a';"),
+      ("b.js", "export default 'b';"),
+    ])
+    .await;
+    let compilation = &compiler.compilation;
+
+    let a = chunk_group_of(compilation, "a.js");
+    let b = chunk_group_of(compilation, "b.js");
+    assert_ne!(a, b);
+    assert!(compilation
+      .chunk_group_by_ukey
+      .expect_get(&b)
+      .parents_iterable()
+      .any(|parent| *parent == a));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_require_include() {
+    let compiler = build(&[
+      (
+        "index.js",
+        "require.include('./a');
+        require.ensure(['./b'], function(require) {
+          require('./a');
+          require('./b');
+        });",
+      ),
+      ("a.js", "module.exports = 'a';"),
+      ("b.js", "module.exports = 'b';"),
+    ])
+    .await;
+    let compilation = &compiler.compilation;
+
+    // the included module is put in the chunk of the entry, and isn't duplicated in the
+    // chunk of the block which requires it
+    let a = chunks_of(compilation, "a.js");
+    assert_eq!(a.len(), 1);
+    assert!(is_initial(compilation, a[0]));
+    let b = chunks_of(compilation, "b.js");
+    assert_eq!(b.len(), 1);
+    assert!(!is_initial(compilation, b[0]));
+  }
+}
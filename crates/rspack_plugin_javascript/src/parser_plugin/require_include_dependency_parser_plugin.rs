@@ -0,0 +1,68 @@
+use rspack_core::{ConstDependency, SpanExt};
+use swc_core::ecma::ast::{CallExpr, UnaryExpr};
+
+use super::JavascriptParserPlugin;
+use crate::dependency::RequireIncludeDependency;
+use crate::utils::eval::{self, BasicEvaluatedExpression};
+use crate::visitors::{expr_name, JavascriptParser};
+
+/// Parses `require.include(request)`, the module is added to the current chunk without being
+/// executed.
+pub struct RequireIncludeDependencyParserPlugin;
+
+impl JavascriptParserPlugin for RequireIncludeDependencyParserPlugin {
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::REQUIRE_INCLUDE
+      || expr.args.len() != 1
+      || expr.args[0].spread.is_some()
+    {
+      return None;
+    }
+    let param = parser.evaluate_expression(&expr.args[0].expr);
+    if !param.is_string() {
+      return None;
+    }
+    parser
+      .dependencies
+      .push(Box::new(RequireIncludeDependency::new(
+        param.string().to_string(),
+        expr.span.into(),
+      )));
+    Some(true)
+  }
+
+  fn evaluate_typeof(
+    &self,
+    _parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<BasicEvaluatedExpression> {
+    (for_name == expr_name::REQUIRE_INCLUDE).then(|| {
+      eval::evaluate_to_string(
+        "function".to_string(),
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+      )
+    })
+  }
+
+  fn r#typeof(
+    &self,
+    parser: &mut JavascriptParser,
+    expr: &UnaryExpr,
+    for_name: &str,
+  ) -> Option<bool> {
+    if for_name != expr_name::REQUIRE_INCLUDE {
+      return None;
+    }
+    parser
+      .presentational_dependencies
+      .push(Box::new(ConstDependency::new(
+        expr.span.real_lo(),
+        expr.span.real_hi(),
+        "'function'".into(),
+        None,
+      )));
+    Some(true)
+  }
+}
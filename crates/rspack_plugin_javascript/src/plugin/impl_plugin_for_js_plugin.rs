@@ -66,6 +66,25 @@ async fn compilation(
     DependencyType::RequireResolve,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::RequireResolveContext,
+    params.context_module_factory.clone(),
+  );
+  // AMDPlugin
+  compilation.set_dependency_factory(
+    DependencyType::AmdRequireItem,
+    params.normal_module_factory.clone(),
+  );
+  // RequireEnsurePlugin
+  compilation.set_dependency_factory(
+    DependencyType::RequireEnsureItem,
+    params.normal_module_factory.clone(),
+  );
+  // RequireIncludePlugin
+  compilation.set_dependency_factory(
+    DependencyType::RequireInclude,
+    params.normal_module_factory.clone(),
+  );
   // RequireContextPlugin
   compilation.set_dependency_factory(
     DependencyType::RequireContext,
@@ -93,6 +112,10 @@ async fn compilation(
     DependencyType::DynamicImportEager,
     params.normal_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::DynamicImportWeak,
+    params.normal_module_factory.clone(),
+  );
   compilation.set_dependency_factory(
     DependencyType::ImportContext,
     params.context_module_factory.clone(),
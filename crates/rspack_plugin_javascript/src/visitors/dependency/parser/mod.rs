@@ -29,6 +29,7 @@ use swc_core::ecma::ast::{
 use swc_core::ecma::ast::{Expr, Ident, Lit, MemberExpr, RestPat};
 use swc_core::ecma::utils::ExprFactory;
 
+use crate::dependency::LocalModule;
 use crate::parser_plugin::InnerGraphState;
 use crate::parser_plugin::{self, JavaScriptParserPluginDrive, JavascriptParserPlugin};
 use crate::utils::eval::{self, BasicEvaluatedExpression};
@@ -246,6 +247,8 @@ pub struct JavascriptParser<'parser> {
   pub(crate) top_level_scope: TopLevelScope,
   pub(crate) last_harmony_import_order: i32,
   pub(crate) inner_graph: InnerGraphState,
+  /// Modules defined with a name by AMD `define("name", ...)`
+  pub(crate) local_modules: Vec<LocalModule>,
 }
 
 impl<'parser> JavascriptParser<'parser> {
@@ -304,6 +307,19 @@ impl<'parser> JavascriptParser<'parser> {
     }
 
     if module_type.is_js_auto() || module_type.is_js_dynamic() {
+      if compiler_options.amd.is_some() {
+        plugins.push(Box::new(
+          parser_plugin::AMDRequireDependenciesBlockParserPlugin,
+        ));
+        plugins.push(Box::new(parser_plugin::AMDDefineDependencyParserPlugin));
+        plugins.push(Box::new(parser_plugin::AMDPlugin));
+      }
+      plugins.push(Box::new(
+        parser_plugin::RequireEnsureDependenciesBlockParserPlugin,
+      ));
+      plugins.push(Box::new(
+        parser_plugin::RequireIncludeDependencyParserPlugin,
+      ));
       plugins.push(Box::new(parser_plugin::CommonJsImportsParserPlugin));
       plugins.push(Box::new(parser_plugin::CommonJsPlugin));
       plugins.push(Box::new(parser_plugin::CommonJsExportsParserPlugin));
@@ -397,6 +413,7 @@ impl<'parser> JavascriptParser<'parser> {
       current_tag_info: None,
       prev_statement: None,
       inner_graph: InnerGraphState::new(),
+      local_modules: Default::default(),
       additional_data,
     }
   }
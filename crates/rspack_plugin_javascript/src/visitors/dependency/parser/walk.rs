@@ -1231,6 +1231,73 @@ impl<'parser> JavascriptParser<'parser> {
     self.top_level_scope = was_top_level;
   }
 
+  /// Walk a callback like the one of an AMD `define()` or `require.ensure()`, the parameters in
+  /// `renames` are bound to free variables like `require` instead of being local variables.
+  pub(crate) fn walk_callback_with_renames(&mut self, expr: &Expr, renames: &[(&str, &str)]) {
+    let is_renamed = |pat: &Pat| {
+      pat
+        .as_ident()
+        .is_some_and(|ident| renames.iter().any(|(name, _)| ident.sym == *name))
+    };
+    let (has_this, mut scope_params, body) = match expr {
+      Expr::Fn(expr) => (
+        true,
+        expr
+          .function
+          .params
+          .iter()
+          .map(|param| &param.pat)
+          .filter(|pat| !is_renamed(pat))
+          .map(Cow::Borrowed)
+          .collect::<Vec<_>>(),
+        expr.function.body.as_ref().map(|body| (Some(body), None)),
+      ),
+      Expr::Arrow(expr) => (
+        false,
+        expr
+          .params
+          .iter()
+          .filter(|pat| !is_renamed(pat))
+          .map(Cow::Borrowed)
+          .collect::<Vec<_>>(),
+        Some(match &*expr.body {
+          BlockStmtOrExpr::BlockStmt(body) => (Some(body), None),
+          BlockStmtOrExpr::Expr(body) => (None, Some(&**body)),
+        }),
+      ),
+      _ => {
+        self.walk_expression(expr);
+        return;
+      }
+    };
+    if let Expr::Fn(FnExpr {
+      ident: Some(ident), ..
+    }) = expr
+    {
+      scope_params.push(Cow::Owned(warp_ident_to_pat(ident.clone())));
+    }
+
+    let was_top_level = self.top_level_scope;
+    self.top_level_scope = TopLevelScope::False;
+    self.in_function_scope(has_this, scope_params.into_iter(), |this| {
+      for (name, variable) in renames {
+        this.set_variable(name.to_string(), variable.to_string());
+      }
+      match body {
+        Some((Some(stmt), _)) => {
+          this.detect_mode(&stmt.stmts);
+          let prev = this.prev_statement;
+          this.pre_walk_statement(Statement::Block(stmt));
+          this.prev_statement = prev;
+          this.walk_statement(Statement::Block(stmt));
+        }
+        Some((None, Some(expr))) => this.walk_expression(expr),
+        _ => {}
+      }
+    });
+    self.top_level_scope = was_top_level;
+  }
+
   fn walk_pattern(&mut self, pat: &Pat) {
     match pat {
       Pat::Array(array) => self.walk_array_pattern(array),
@@ -198,11 +198,9 @@ pub(crate) mod expr_matcher {
     is_object_define_property: "Object.defineProperty",
     // unsupported
     is_require_extensions: "require.extensions",
-    is_require_ensure: "require.ensure",
     is_require_config: "require.config",
     is_require_version: "require.version",
     is_require_amd: "require.amd",
-    is_require_include: "require.include",
     is_require_onerror: "require.onError",
     is_require_main_require: "require.main.require",
     is_module_parent_require: "module.parent.require",
@@ -210,11 +208,16 @@ pub(crate) mod expr_matcher {
 }
 
 pub mod expr_name {
+  pub const DEFINE: &str = "define";
+  pub const DEFINE_AMD: &str = "define.amd";
   pub const MODULE: &str = "module";
   pub const MODULE_HOT: &str = "module.hot";
   pub const MODULE_HOT_ACCEPT: &str = "module.hot.accept";
   pub const MODULE_HOT_DECLINE: &str = "module.hot.decline";
   pub const REQUIRE: &str = "require";
+  pub const REQUIRE_AMD: &str = "require.amd";
+  pub const REQUIRE_ENSURE: &str = "require.ensure";
+  pub const REQUIRE_INCLUDE: &str = "require.include";
   pub const REQUIRE_RESOLVE: &str = "require.resolve";
   pub const REQUIRE_RESOLVE_WEAK: &str = "require.resolveWeak";
   pub const IMPORT_META: &str = "import.meta";
@@ -0,0 +1,388 @@
+use std::{
+  fmt::Debug,
+  net::{Ipv4Addr, SocketAddr},
+  sync::{Arc, Mutex},
+  time::Duration,
+};
+
+use rspack_core::ModuleIdentifier;
+use rspack_error::{error, Result};
+use rustc_hash::{FxHashMap, FxHashSet};
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  sync::watch,
+};
+
+use crate::backend::{Backend, ModuleInfo};
+
+const PREFIX: &str = "/lazy-compilation-using-";
+const MAX_REQUEST_HEAD_SIZE: usize = 8 * 1024;
+
+const EVENT_STREAM_RESPONSE: &[u8] = b"HTTP/1.1 200 OK\r\n\
+content-type: text/event-stream\r\n\
+cache-control: no-cache\r\n\
+Access-Control-Allow-Origin: *\r\n\
+Access-Control-Allow-Methods: *\r\n\
+Access-Control-Allow-Headers: *\r\n\
+\r\n\
+\n";
+const NOT_FOUND_RESPONSE: &[u8] =
+  b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+
+/// Called with the resources of the modules which got activated by a client,
+/// the compiler should rebuild them as if they changed.
+pub type LazyCompilationInvalidate = Arc<dyn Fn(FxHashSet<String>) + Send + Sync>;
+
+pub struct ServerBackendOptions {
+  /// Request of the client runtime the proxy modules use to connect to the server
+  pub client: String,
+  /// Address the server listens to, port `0` picks a free one
+  pub listen: SocketAddr,
+  /// How long a module stays active after its last client disconnected
+  pub idle_timeout: Duration,
+  pub invalidate: Option<LazyCompilationInvalidate>,
+}
+
+impl Debug for ServerBackendOptions {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ServerBackendOptions")
+      .field("client", &self.client)
+      .field("listen", &self.listen)
+      .field("idle_timeout", &self.idle_timeout)
+      .finish()
+  }
+}
+
+impl Default for ServerBackendOptions {
+  fn default() -> Self {
+    Self {
+      client: String::new(),
+      listen: SocketAddr::from((Ipv4Addr::LOCALHOST, 0)),
+      idle_timeout: Duration::from_secs(120),
+      invalidate: None,
+    }
+  }
+}
+
+#[derive(Default)]
+struct State {
+  /// Number of clients using each module
+  active_modules: FxHashMap<String, usize>,
+  files_by_key: FxHashMap<String, String>,
+}
+
+struct Shared {
+  state: Mutex<State>,
+  idle_timeout: Duration,
+  invalidate: Option<LazyCompilationInvalidate>,
+}
+
+impl Shared {
+  fn activate(&self, keys: &[String]) {
+    let activated = {
+      let mut state = self.state.lock().expect("should lock state");
+      let mut activated = FxHashSet::default();
+      for key in keys {
+        let count = state.active_modules.entry(key.clone()).or_default();
+        *count += 1;
+        if *count == 1
+          && let Some(file) = state.files_by_key.get(key)
+        {
+          activated.insert(file.clone());
+        }
+      }
+      activated
+    };
+    if !activated.is_empty()
+      && let Some(invalidate) = &self.invalidate
+    {
+      invalidate(activated);
+    }
+  }
+
+  fn deactivate(&self, keys: &[String]) {
+    let mut state = self.state.lock().expect("should lock state");
+    for key in keys {
+      if let Some(count) = state.active_modules.get_mut(key) {
+        *count -= 1;
+        if *count == 0 {
+          state.active_modules.remove(key);
+        }
+      }
+    }
+  }
+}
+
+/// A lazy compilation backend serving the clients of the proxy modules with a local
+/// Server-Sent-Events server, which is started for the first proxy module.
+///
+/// A module is active as long as a client keeps a connection open for it, and for
+/// [ServerBackendOptions::idle_timeout] after the last one closed.
+#[derive(Debug)]
+pub struct ServerBackend {
+  options: ServerBackendOptions,
+  server: Option<Server>,
+}
+
+struct Server {
+  shared: Arc<Shared>,
+  client: String,
+  url_base: String,
+  /// Dropping it stops the server and closes all connections
+  _closed: watch::Sender<()>,
+}
+
+impl Debug for Server {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Server")
+      .field("url_base", &self.url_base)
+      .finish()
+  }
+}
+
+impl ServerBackend {
+  pub fn new(options: ServerBackendOptions) -> Self {
+    Self {
+      options,
+      server: None,
+    }
+  }
+
+  /// The origin of the server, e.g. `http://127.0.0.1:8080`, `None` before it's started.
+  pub fn url_base(&self) -> Option<&str> {
+    self.server.as_ref().map(|server| server.url_base.as_str())
+  }
+
+  async fn server(&mut self) -> Result<&Server> {
+    if self.server.is_none() {
+      self.server = Some(Server::listen(&self.options).await?);
+    }
+    Ok(
+      self
+        .server
+        .as_ref()
+        .expect("should have started the server"),
+    )
+  }
+}
+
+impl Server {
+  async fn listen(options: &ServerBackendOptions) -> Result<Self> {
+    let listener = TcpListener::bind(options.listen)
+      .await
+      .map_err(|e| error!("Failed to start the lazy compilation server: {e}"))?;
+    let addr = listener
+      .local_addr()
+      .map_err(|e| error!("Failed to start the lazy compilation server: {e}"))?;
+    let url_base = if addr.ip().is_unspecified() {
+      format!("http://localhost:{}", addr.port())
+    } else {
+      format!("http://{addr}")
+    };
+
+    let shared = Arc::new(Shared {
+      state: Default::default(),
+      idle_timeout: options.idle_timeout,
+      invalidate: options.invalidate.clone(),
+    });
+    let (closed_tx, closed) = watch::channel(());
+    tokio::spawn(accept(listener, shared.clone(), closed));
+
+    Ok(Self {
+      shared,
+      client: format!(
+        "{}?{}",
+        options.client,
+        urlencoding::encode(&format!("{url_base}{PREFIX}"))
+      ),
+      url_base,
+      _closed: closed_tx,
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl Backend for ServerBackend {
+  async fn module(
+    &mut self,
+    original_module: ModuleIdentifier,
+    path: String,
+  ) -> Result<ModuleInfo> {
+    let server = self.server().await?;
+    let key = module_key(&original_module);
+    let mut state = server.shared.state.lock().expect("should lock state");
+    let active = state.active_modules.contains_key(&key);
+    state.files_by_key.insert(key.clone(), path);
+    Ok(ModuleInfo {
+      active,
+      data: key,
+      client: server.client.clone(),
+    })
+  }
+}
+
+/// Key of a module in the urls of the clients, `@` is reserved as the separator of the keys.
+fn module_key(identifier: &str) -> String {
+  let key = identifier.replace('\\', "/").replace('@', "_");
+  let mut key = urlencoding::encode(&key).into_owned();
+  // keep the key readable, these characters are allowed in the path of an url
+  for (encoded, decoded) in [
+    ("%2F", "/"),
+    ("%3A", ":"),
+    ("%24", "$"),
+    ("%26", "&"),
+    ("%2B", "+"),
+    ("%2C", ","),
+    ("%3B", ";"),
+    ("%3D", "="),
+  ] {
+    key = key.replace(encoded, decoded);
+  }
+  key
+}
+
+async fn accept(listener: TcpListener, shared: Arc<Shared>, mut closed: watch::Receiver<()>) {
+  loop {
+    tokio::select! {
+      accepted = listener.accept() => {
+        if let Ok((stream, _)) = accepted {
+          tokio::spawn(serve(stream, shared.clone(), closed.clone()));
+        }
+      }
+      _ = closed.changed() => return,
+    }
+  }
+}
+
+async fn serve(mut stream: TcpStream, shared: Arc<Shared>, mut closed: watch::Receiver<()>) {
+  let Some(keys) = read_keys(&mut stream).await else {
+    let _ = stream.write_all(NOT_FOUND_RESPONSE).await;
+    return;
+  };
+  let _ = stream.set_nodelay(true);
+  if stream.write_all(EVENT_STREAM_RESPONSE).await.is_err() {
+    return;
+  }
+  shared.activate(&keys);
+
+  // nothing is sent to the client, the open connection is what keeps the modules active
+  let mut buf = [0; 256];
+  loop {
+    tokio::select! {
+      read = stream.read(&mut buf) => {
+        if !matches!(read, Ok(n) if n > 0) {
+          break;
+        }
+      }
+      _ = closed.changed() => return,
+    }
+  }
+
+  tokio::select! {
+    _ = tokio::time::sleep(shared.idle_timeout) => shared.deactivate(&keys),
+    _ = closed.changed() => {}
+  }
+}
+
+/// Read the head of a `GET /lazy-compilation-using-<key>@<key>` request.
+async fn read_keys(stream: &mut TcpStream) -> Option<Vec<String>> {
+  let mut head = Vec::new();
+  let mut buf = [0; 1024];
+  while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+    let n = stream.read(&mut buf).await.ok()?;
+    if n == 0 || head.len() + n > MAX_REQUEST_HEAD_SIZE {
+      return None;
+    }
+    head.extend_from_slice(&buf[..n]);
+  }
+  let head = std::str::from_utf8(&head).ok()?;
+  let mut request_line = head.lines().next()?.split_whitespace();
+  if request_line.next()? != "GET" {
+    return None;
+  }
+  let keys = request_line.next()?.strip_prefix(PREFIX)?;
+  Some(keys.split('@').map(|key| key.to_string()).collect())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_module_key() {
+    assert_eq!(
+      module_key("javascript/auto|C:\\app\\node_modules\\@scope\\pkg\\index.js?a=1"),
+      "javascript/auto%7CC:/app/node_modules/_scope/pkg/index.js%3Fa=1"
+    );
+  }
+
+  // the clock is paused before any IO, it only advances when the runtime has nothing else to do
+  #[tokio::test(start_paused = true)]
+  async fn test_activate_modules() {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    let mut backend = ServerBackend::new(ServerBackendOptions {
+      client: "lazy-compilation-web.js".to_string(),
+      idle_timeout: Duration::from_secs(60),
+      invalidate: Some(Arc::new(move |files| {
+        let _ = sender.send(files);
+      })),
+      ..Default::default()
+    });
+    assert!(backend.url_base().is_none());
+
+    let info = backend
+      .module("/app/src/page.js".into(), "/app/src/page.js".to_string())
+      .await
+      .expect("should get module info");
+    assert!(!info.active);
+    let url_base = backend.url_base().expect("should listen").to_string();
+    assert_eq!(info.data, "/app/src/page.js");
+    assert_eq!(
+      info.client,
+      format!(
+        "lazy-compilation-web.js?{}",
+        urlencoding::encode(&format!("{url_base}{PREFIX}"))
+      )
+    );
+
+    let addr = url_base.trim_start_matches("http://");
+    let mut stream = TcpStream::connect(addr).await.expect("should connect");
+    stream
+      .write_all(format!("GET {PREFIX}{} HTTP/1.1\r\nHost: {addr}\r\n\r\n", info.data).as_bytes())
+      .await
+      .expect("should send request");
+    let mut response = [0; EVENT_STREAM_RESPONSE.len()];
+    stream
+      .read_exact(&mut response)
+      .await
+      .expect("should read response");
+    assert_eq!(response, EVENT_STREAM_RESPONSE);
+
+    let files = receiver.recv().await.expect("should invalidate");
+    assert_eq!(
+      files,
+      FxHashSet::from_iter(["/app/src/page.js".to_string()])
+    );
+    let info = backend
+      .module("/app/src/page.js".into(), "/app/src/page.js".to_string())
+      .await
+      .expect("should get module info");
+    assert!(info.active);
+
+    drop(stream);
+    tokio::time::sleep(Duration::from_secs(59)).await;
+    let info = backend
+      .module("/app/src/page.js".into(), "/app/src/page.js".to_string())
+      .await
+      .expect("should get module info");
+    assert!(info.active);
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let info = backend
+      .module("/app/src/page.js".into(), "/app/src/page.js".to_string())
+      .await
+      .expect("should get module info");
+    assert!(!info.active);
+  }
+}
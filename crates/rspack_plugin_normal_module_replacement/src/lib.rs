@@ -0,0 +1,109 @@
+#![feature(let_chains)]
+
+use std::path::Path;
+
+use rspack_core::{
+  parse_resource, ApplyContext, CompilerOptions, ModuleFactoryCreateData, NormalModuleCreateData,
+  NormalModuleFactoryAfterResolve, NormalModuleFactoryBeforeResolve, Plugin, PluginContext,
+  ResourceData,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use sugar_path::SugarPath;
+
+#[derive(Debug)]
+pub struct NormalModuleReplacementPluginOptions {
+  pub resource_reg_exp: RspackRegex,
+  /// Replaces the request before it is resolved, and the resolved resource when the resolved
+  /// resource matches, a relative path is resolved against the directory of the resource
+  pub new_resource: String,
+}
+
+/// Replaces the requests and the resolved resources matching `resource_reg_exp` with
+/// `new_resource`.
+#[plugin]
+#[derive(Debug)]
+pub struct NormalModuleReplacementPlugin {
+  options: NormalModuleReplacementPluginOptions,
+}
+
+impl NormalModuleReplacementPlugin {
+  pub fn new(options: NormalModuleReplacementPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn replace_resource(&self, resource_data: &mut ResourceData) {
+    let new_resource = &self.options.new_resource;
+    let new_resource = if Path::new(new_resource).is_absolute() {
+      new_resource.to_owned()
+    } else {
+      let dir = Path::new(&resource_data.resource)
+        .parent()
+        .unwrap_or_else(|| Path::new(""));
+      dir
+        .join(new_resource)
+        .normalize()
+        .to_string_lossy()
+        .into_owned()
+    };
+    if resource_data.resource_path.is_some()
+      && let Some(parsed) = parse_resource(&new_resource)
+    {
+      resource_data.set_path(parsed.path);
+      resource_data.set_query_optional(parsed.query);
+      resource_data.set_fragment_optional(parsed.fragment);
+    }
+    resource_data.set_resource(new_resource);
+  }
+}
+
+#[plugin_hook(NormalModuleFactoryBeforeResolve for NormalModuleReplacementPlugin)]
+async fn before_resolve(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<bool>> {
+  if let Some(dependency) = data.dependency.as_module_dependency_mut()
+    && self.options.resource_reg_exp.test(dependency.request())
+  {
+    dependency.set_request(self.options.new_resource.clone());
+  }
+  Ok(None)
+}
+
+#[plugin_hook(NormalModuleFactoryAfterResolve for NormalModuleReplacementPlugin)]
+async fn after_resolve(
+  &self,
+  _data: &mut ModuleFactoryCreateData,
+  create_data: &mut NormalModuleCreateData,
+) -> Result<Option<bool>> {
+  if self
+    .options
+    .resource_reg_exp
+    .test(&create_data.resource_resolve_data.resource)
+  {
+    self.replace_resource(&mut create_data.resource_resolve_data);
+  }
+  Ok(None)
+}
+
+impl Plugin for NormalModuleReplacementPlugin {
+  fn name(&self) -> &'static str {
+    "NormalModuleReplacementPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut ApplyContext>,
+    _options: &mut CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .before_resolve
+      .tap(before_resolve::new(self));
+    ctx
+      .context
+      .normal_module_factory_hooks
+      .after_resolve
+      .tap(after_resolve::new(self));
+    Ok(())
+  }
+}
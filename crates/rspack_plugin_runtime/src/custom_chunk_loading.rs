@@ -0,0 +1,243 @@
+use std::fmt::Debug;
+
+use rspack_core::{
+  BoxRuntimeModule, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation,
+  CompilationRuntimeRequirementInTree, CompilerOptions, Plugin, PluginContext, RuntimeGlobals,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::runtime_module::is_enabled_for_chunk;
+
+/// The runtime of a [ChunkLoadingType::Custom] chunk loading.
+pub trait CustomChunkLoading: Debug + Send + Sync {
+  /// Runtime requirements of the runtime module, based on the requirements of the chunk tree.
+  fn runtime_requirements(&self, _runtime_requirements: &RuntimeGlobals) -> RuntimeGlobals {
+    RuntimeGlobals::default()
+  }
+
+  /// Creates the runtime module that registers the chunk loading handlers,
+  /// e.g. `__webpack_require__.f.<name>` for [RuntimeGlobals::ENSURE_CHUNK_HANDLERS].
+  fn create_runtime_module(
+    &self,
+    compilation: &Compilation,
+    chunk_ukey: &ChunkUkey,
+    runtime_requirements: &RuntimeGlobals,
+  ) -> Result<BoxRuntimeModule>;
+}
+
+/// Registers the runtime of a custom chunk loading, which is used by chunks whose `chunkLoading`
+/// is `name`.
+#[plugin]
+#[derive(Debug)]
+pub struct CustomChunkLoadingPlugin {
+  name: String,
+  chunk_loading: Box<dyn CustomChunkLoading>,
+}
+
+impl CustomChunkLoadingPlugin {
+  pub fn new(name: String, chunk_loading: Box<dyn CustomChunkLoading>) -> Self {
+    Self::new_inner(name, chunk_loading)
+  }
+}
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for CustomChunkLoadingPlugin)]
+fn runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  let chunk_loading_value = ChunkLoading::Enable(ChunkLoadingType::Custom(self.name.clone()));
+  if !is_enabled_for_chunk(chunk_ukey, &chunk_loading_value, compilation) {
+    return Ok(None);
+  }
+
+  let has_chunk_loading = runtime_requirements.intersects(
+    RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+      | RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS
+      | RuntimeGlobals::HMR_DOWNLOAD_MANIFEST
+      | RuntimeGlobals::ON_CHUNKS_LOADED
+      | RuntimeGlobals::BASE_URI,
+  );
+  if has_chunk_loading {
+    runtime_requirements_mut.insert(
+      self
+        .chunk_loading
+        .runtime_requirements(runtime_requirements),
+    );
+    let runtime_module =
+      self
+        .chunk_loading
+        .create_runtime_module(compilation, chunk_ukey, runtime_requirements)?;
+    compilation.add_runtime_module(chunk_ukey, runtime_module)?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for CustomChunkLoadingPlugin {
+  fn name(&self) -> &'static str {
+    "CustomChunkLoadingPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    options: &mut CompilerOptions,
+  ) -> Result<()> {
+    options
+      .output
+      .custom_chunk_loading_types
+      .push(self.name.clone());
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use rspack_collections::Identifier;
+  use rspack_core::{
+    impl_runtime_module,
+    rspack_sources::{BoxSource, RawSource, SourceExt},
+    testing::compiler_options,
+    BoxPlugin, Compiler, EntryOptions, RuntimeModule,
+  };
+  use rspack_fs::MemoryFileSystem;
+  use rspack_ids::{NaturalChunkIdsPlugin, NaturalModuleIdsPlugin};
+  use rspack_paths::Utf8Path;
+  use rspack_plugin_entry::EntryPlugin;
+  use rspack_plugin_javascript::JsPlugin;
+
+  use super::*;
+  use crate::{ArrayPushCallbackChunkFormatPlugin, RuntimePlugin};
+
+  const RUNTIME_MODULE_NAME: &str = "webpack/runtime/test_chunk_loading";
+
+  #[impl_runtime_module]
+  #[derive(Debug)]
+  struct TestChunkLoadingRuntimeModule {
+    id: Identifier,
+  }
+
+  impl RuntimeModule for TestChunkLoadingRuntimeModule {
+    fn name(&self) -> Identifier {
+      self.id
+    }
+
+    fn generate(&self, _compilation: &Compilation) -> Result<BoxSource> {
+      Ok(
+        RawSource::from(format!(
+          "{}.test = function(chunkId, promises) {{}};",
+          RuntimeGlobals::ENSURE_CHUNK_HANDLERS
+        ))
+        .boxed(),
+      )
+    }
+  }
+
+  #[derive(Debug)]
+  struct TestChunkLoading;
+
+  impl CustomChunkLoading for TestChunkLoading {
+    fn create_runtime_module(
+      &self,
+      _compilation: &Compilation,
+      _chunk_ukey: &ChunkUkey,
+      _runtime_requirements: &RuntimeGlobals,
+    ) -> Result<BoxRuntimeModule> {
+      Ok(Box::new(TestChunkLoadingRuntimeModule::with_default(
+        Identifier::from(RUNTIME_MODULE_NAME),
+      )))
+    }
+  }
+
+  /// Creates a project whose entry loads an async chunk.
+  fn create_fixture() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    std::fs::write(dir.path().join("index.js"), "import(\"./async.js\");")
+      .expect("should write file");
+    std::fs::write(dir.path().join("async.js"), "export default 1;").expect("should write file");
+    dir
+  }
+
+  async fn build(
+    dir: &Utf8Path,
+    chunk_loading: &str,
+    plugins: Vec<BoxPlugin>,
+  ) -> Compiler<MemoryFileSystem> {
+    let mut options = compiler_options(dir);
+    options.output.chunk_loading =
+      ChunkLoading::Enable(ChunkLoadingType::Custom(chunk_loading.to_string()));
+    let entry = EntryPlugin::new(
+      options.context.clone(),
+      dir.join("index.js").to_string(),
+      EntryOptions {
+        name: Some("main".to_string()),
+        ..Default::default()
+      },
+    );
+    let mut all_plugins: Vec<BoxPlugin> = vec![
+      Box::new(entry),
+      Box::<JsPlugin>::default(),
+      Box::<RuntimePlugin>::default(),
+      Box::<ArrayPushCallbackChunkFormatPlugin>::default(),
+      Box::<NaturalModuleIdsPlugin>::default(),
+      Box::<NaturalChunkIdsPlugin>::default(),
+    ];
+    all_plugins.extend(plugins);
+    let mut compiler = Compiler::new(
+      options,
+      all_plugins,
+      MemoryFileSystem::default(),
+      None,
+      None,
+    );
+    compiler.build().await.expect("should build");
+    compiler
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_custom_chunk_loading_runtime_module() {
+    let dir = create_fixture();
+    let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+    let compiler = build(
+      context,
+      "test",
+      vec![Box::new(CustomChunkLoadingPlugin::new(
+        "test".to_string(),
+        Box::new(TestChunkLoading),
+      ))],
+    )
+    .await;
+
+    let compilation = &compiler.compilation;
+    assert_eq!(compilation.get_errors().count(), 0);
+    // the identifiers of the runtime modules are prefixed with the runtime of their chunk
+    assert!(compilation
+      .runtime_modules
+      .values()
+      .any(|module| module.name().as_str() == RUNTIME_MODULE_NAME));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_unknown_chunk_loading_type() {
+    let dir = create_fixture();
+    let context = Utf8Path::from_path(dir.path()).expect("should be utf8");
+    let compiler = build(context, "unknown", vec![]).await;
+
+    let messages = compiler
+      .compilation
+      .get_errors()
+      .map(|error| error.message())
+      .collect::<Vec<_>>();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].contains("Unknown chunk loading type \"unknown\""));
+  }
+}
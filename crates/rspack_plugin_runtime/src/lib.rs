@@ -9,7 +9,9 @@ pub use runtime_plugin::RuntimePlugin;
 mod array_push_callback_chunk_format;
 pub use array_push_callback_chunk_format::ArrayPushCallbackChunkFormatPlugin;
 mod common_js_chunk_loading;
+mod custom_chunk_loading;
 pub use common_js_chunk_loading::CommonJsChunkLoadingPlugin;
+pub use custom_chunk_loading::{CustomChunkLoading, CustomChunkLoadingPlugin};
 mod jsonp_chunk_loading;
 pub use jsonp_chunk_loading::JsonpChunkLoadingPlugin;
 mod module_chunk_format;
@@ -63,5 +65,7 @@ pub fn enable_chunk_loading_plugin(loading_type: ChunkLoadingType, plugins: &mut
       plugins.push(ImportScriptsChunkLoadingPlugin::default().boxed());
     }
     ChunkLoadingType::Import => plugins.push(ModuleChunkLoadingPlugin::default().boxed()),
+    // provided by a `CustomChunkLoadingPlugin`, unknown names are reported by `RuntimePlugin`
+    ChunkLoadingType::Custom(_) => {}
   }
 }
@@ -0,0 +1,34 @@
+use rspack_collections::Identifier;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule,
+};
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct AmdDefineRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for AmdDefineRuntimeModule {
+  fn default() -> Self {
+    Self::with_default(Identifier::from("webpack/runtime/amd_define"))
+  }
+}
+
+impl RuntimeModule for AmdDefineRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(
+      RawSource::from(format!(
+        "{} = function () {{\n  throw new Error('define cannot be used indirect');\n}};",
+        RuntimeGlobals::AMD_DEFINE
+      ))
+      .boxed(),
+    )
+  }
+}
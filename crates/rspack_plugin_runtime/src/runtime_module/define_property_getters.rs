@@ -5,6 +5,8 @@ use rspack_core::{
   Compilation, RuntimeModule,
 };
 
+use crate::runtime_module::utils::render_basic_functions;
+
 #[impl_runtime_module]
 #[derive(Debug)]
 pub struct DefinePropertyGettersRuntimeModule {
@@ -22,7 +24,13 @@ impl RuntimeModule for DefinePropertyGettersRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
-    Ok(RawSource::from(include_str!("runtime/define_property_getters.js")).boxed())
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(
+      RawSource::from(render_basic_functions(
+        &compilation.options.output.environment,
+        include_str!("runtime/define_property_getters.js"),
+      ))
+      .boxed(),
+    )
   }
 }
@@ -5,7 +5,7 @@ use rspack_core::{
   ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule,
 };
 
-use crate::get_chunk_runtime_requirements;
+use crate::{get_chunk_runtime_requirements, runtime_module::utils::render_basic_functions};
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -28,18 +28,23 @@ impl RuntimeModule for EnsureChunkRuntimeModule {
   fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
     let chunk_ukey = self.chunk.expect("should have chunk");
     let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk_ukey);
+    let environment = &compilation.options.output.environment;
     Ok(
       RawSource::from(
         match runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
-          true => include_str!("runtime/ensure_chunk.js").replace(
-            "$FETCH_PRIORITY$",
-            if runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY) {
-              ", fetchPriority"
-            } else {
-              ""
-            },
+          true => render_basic_functions(environment, include_str!("runtime/ensure_chunk.js"))
+            .replace(
+              "$FETCH_PRIORITY$",
+              if runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY) {
+                ", fetchPriority"
+              } else {
+                ""
+              },
+            ),
+          false => render_basic_functions(
+            environment,
+            include_str!("runtime/ensure_chunk_with_inline.js"),
           ),
-          false => include_str!("runtime/ensure_chunk_with_inline.js").to_string(),
         },
       )
       .boxed(),
@@ -22,7 +22,15 @@ impl RuntimeModule for GlobalRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+    if compilation
+      .options
+      .output
+      .environment
+      .supports_global_this()
+    {
+      return Ok(RawSource::from("__webpack_require__.g = globalThis;").boxed());
+    }
     Ok(RawSource::from(include_str!("runtime/global.js")).boxed())
   }
 }
@@ -9,7 +9,9 @@ use rspack_core::{
 use super::generate_javascript_hmr_runtime;
 use crate::{
   get_chunk_runtime_requirements,
-  runtime_module::utils::{chunk_has_js, get_initial_chunk_ids, stringify_chunks},
+  runtime_module::utils::{
+    chunk_has_js, get_initial_chunk_ids, render_basic_functions, stringify_chunks,
+  },
 };
 
 #[impl_runtime_module]
@@ -50,6 +52,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       .expect_get(&self.chunk.expect("The chunk should be attached"));
 
     let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk.ukey);
+    let environment = &compilation.options.output.environment;
     let with_base_uri = runtime_requirements.contains(RuntimeGlobals::BASE_URI);
     let with_loading = runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
     let with_on_chunk_load = runtime_requirements.contains(RuntimeGlobals::ON_CHUNKS_LOADED);
@@ -98,8 +101,24 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
       let body = if matches!(has_js_matcher, BooleanMatcher::Condition(false)) {
         "installedChunks[chunkId] = 0;".to_string()
       } else {
-        include_str!("runtime/jsonp_chunk_loading.js")
+        render_basic_functions(environment, include_str!("runtime/jsonp_chunk_loading.js"))
           .replace("$JS_MATCHER$", &js_matcher)
+          .replace(
+            "$REAL_SRC$",
+            if environment.supports_optional_chaining() {
+              "event?.target?.src"
+            } else {
+              "event && event.target && event.target.src"
+            },
+          )
+          .replace(
+            "$CHUNK_LOAD_ERROR_MESSAGE$",
+            if environment.supports_template_literal() {
+              r"`Loading chunk ${chunkId} failed.\n(${errorType}: ${realSrc})`"
+            } else {
+              "'Loading chunk ' +\n\t\t\t\t\t\t\tchunkId +\n\t\t\t\t\t\t\t' failed.\\n(' +\n\t\t\t\t\t\t\terrorType +\n\t\t\t\t\t\t\t': ' +\n\t\t\t\t\t\t\trealSrc +\n\t\t\t\t\t\t\t')'"
+            },
+          )
           .replace(
             "$MATCH_FALLBACK$",
             if matches!(has_js_matcher, BooleanMatcher::Condition(true)) {
@@ -222,15 +241,34 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
         &compilation.options.output.global_object, &compilation.options.output.chunk_loading_global
       );
       source.add(RawSource::from(
-        include_str!("runtime/jsonp_chunk_loading_with_callback.js")
-          .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
-          .replace(
-            "$WITH_ON_CHUNK_LOAD$",
-            match with_on_chunk_load {
-              true => "return __webpack_require__.O(result);",
-              false => "",
-            },
-          ),
+        render_basic_functions(
+          environment,
+          include_str!("runtime/jsonp_chunk_loading_with_callback.js"),
+        )
+        .replace("$CHUNK_LOADING_GLOBAL_EXPR$", &chunk_loading_global_expr)
+        .replace(
+          "$CHUNK_DATA_DECLARATIONS$",
+          if environment.supports_destructuring() {
+            "var [chunkIds, moreModules, runtime] = data;"
+          } else {
+            "var chunkIds = data[0];\n\tvar moreModules = data[1];\n\tvar runtime = data[2];"
+          },
+        )
+        .replace(
+          "$FOR_EACH_CHUNK_ID$",
+          if environment.supports_for_of() {
+            "for (chunkId of chunkIds) {"
+          } else {
+            "for (; i < chunkIds.length; i++) {\n\t\tchunkId = chunkIds[i];"
+          },
+        )
+        .replace(
+          "$WITH_ON_CHUNK_LOAD$",
+          match with_on_chunk_load {
+            true => "return __webpack_require__.O(result);",
+            false => "",
+          },
+        ),
       ));
     }
 
@@ -5,7 +5,7 @@ use rspack_core::{
   ChunkUkey, Compilation, CrossOriginLoading, RuntimeGlobals, RuntimeModule,
 };
 
-use crate::get_chunk_runtime_requirements;
+use crate::{get_chunk_runtime_requirements, runtime_module::utils::render_basic_functions};
 
 #[impl_runtime_module]
 #[derive(Debug)]
@@ -83,7 +83,7 @@ impl RuntimeModule for LoadScriptRuntimeModule {
     };
 
     Ok(RawSource::from(
-      include_str!("runtime/load_script.js")
+      render_basic_functions(&compilation.options.output.environment, include_str!("runtime/load_script.js"))
         .replace(
           "__CROSS_ORIGIN_LOADING_PLACEHOLDER__",
           &cross_origin_loading,
@@ -1,3 +1,5 @@
+mod amd_define;
+mod amd_options;
 mod async_module;
 mod auto_public_path;
 mod base_uri;
@@ -38,7 +40,10 @@ mod runtime_id;
 mod startup_chunk_dependencies;
 mod startup_entry_point;
 mod system_context;
+mod uncaught_error_handler;
 mod utils;
+pub use amd_define::AmdDefineRuntimeModule;
+pub use amd_options::AmdOptionsRuntimeModule;
 pub use async_module::AsyncRuntimeModule;
 pub use auto_public_path::AutoPublicPathRuntimeModule;
 pub use base_uri::BaseUriRuntimeModule;
@@ -79,4 +84,5 @@ pub use runtime_id::RuntimeIdRuntimeModule;
 pub use startup_chunk_dependencies::StartupChunkDependenciesRuntimeModule;
 pub use startup_entry_point::StartupEntrypointRuntimeModule;
 pub use system_context::SystemContextRuntimeModule;
+pub use uncaught_error_handler::UncaughtErrorHandlerRuntimeModule;
 pub use utils::*;
@@ -1,4 +1,4 @@
-__webpack_require__.d = function(exports, definition) {
+__webpack_require__.d = $FUNCTION$(exports, definition)$ARROW$ {
 	for(var key in definition) {
         if(__webpack_require__.o(definition, key) && !__webpack_require__.o(exports, key)) {
             Object.defineProperty(exports, key, { enumerable: true, get: definition[key] });
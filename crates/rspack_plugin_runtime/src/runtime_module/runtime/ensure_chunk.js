@@ -1,9 +1,9 @@
 __webpack_require__.f = {};
 // This file contains only the entry chunk.
 // The chunk loading function for additional chunks
-__webpack_require__.e = function (chunkId$FETCH_PRIORITY$) {
+__webpack_require__.e = $FUNCTION$(chunkId$FETCH_PRIORITY$)$ARROW$ {
 	return Promise.all(
-		Object.keys(__webpack_require__.f).reduce(function (promises, key) {
+		Object.keys(__webpack_require__.f).reduce($FUNCTION$(promises, key)$ARROW$ {
 			__webpack_require__.f[key](chunkId, promises$FETCH_PRIORITY$);
 			return promises;
 		}, [])
@@ -1,6 +1,6 @@
 // The chunk loading function for additional chunks
 // Since all referenced chunks are already included
 // in this file, this function is empty here.
-__webpack_require__.e = function () {
+__webpack_require__.e = $FUNCTION$()$ARROW$ {
 	return Promise.resolve();
 };
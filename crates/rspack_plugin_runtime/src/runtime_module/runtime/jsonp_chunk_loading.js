@@ -11,7 +11,7 @@ if (installedChunkData !== 0) {
 	} else {
 		if ($JS_MATCHER$) {
 			// setup Promise in chunk cache
-			var promise = new Promise(function (resolve, reject) {
+			var promise = new Promise($FUNCTION$(resolve, reject)$ARROW$ {
 				installedChunkData = installedChunks[chunkId] = [resolve, reject];
 			});
 			promises.push((installedChunkData[2] = promise));
@@ -20,22 +20,16 @@ if (installedChunkData !== 0) {
 			var url = __webpack_require__.p + __webpack_require__.u(chunkId);
 			// create error before stack unwound to get useful stacktrace later
 			var error = new Error();
-			var loadingEnded = function (event) {
+			var loadingEnded = $FUNCTION$(event)$ARROW$ {
 				if (__webpack_require__.o(installedChunks, chunkId)) {
 					installedChunkData = installedChunks[chunkId];
 					if (installedChunkData !== 0) installedChunks[chunkId] = undefined;
 					if (installedChunkData) {
 						var errorType =
 							event && (event.type === 'load' ? 'missing' : event.type);
-						var realSrc = event && event.target && event.target.src;
+						var realSrc = $REAL_SRC$;
 						error.message =
-							'Loading chunk ' +
-							chunkId +
-							' failed.\n(' +
-							errorType +
-							': ' +
-							realSrc +
-							')';
+							$CHUNK_LOAD_ERROR_MESSAGE$;
 						error.name = 'ChunkLoadError';
 						error.type = errorType;
 						error.request = realSrc;
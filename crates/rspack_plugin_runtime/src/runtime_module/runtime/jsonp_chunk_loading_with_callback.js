@@ -1,14 +1,12 @@
 // install a JSONP callback for chunk loading
-var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
-	var chunkIds = data[0];
-	var moreModules = data[1];
-	var runtime = data[2];
+var webpackJsonpCallback = $FUNCTION$(parentChunkLoadingFunction, data)$ARROW$ {
+	$CHUNK_DATA_DECLARATIONS$
 	// add "moreModules" to the modules object,
 	// then flag all "chunkIds" as loaded and fire callback
 	var moduleId,
 		chunkId,
 		i = 0;
-	if (chunkIds.some(function (id) { return installedChunks[id] !== 0 })) {
+	if (chunkIds.some($FUNCTION$(id)$ARROW$ { return installedChunks[id] !== 0 })) {
 		for (moduleId in moreModules) {
 			if (__webpack_require__.o(moreModules, moduleId)) {
 				__webpack_require__.m[moduleId] = moreModules[moduleId];
@@ -17,8 +15,7 @@ var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
 		if (runtime) var result = runtime(__webpack_require__);
 	}
 	if (parentChunkLoadingFunction) parentChunkLoadingFunction(data);
-	for (; i < chunkIds.length; i++) {
-		chunkId = chunkIds[i];
+	$FOR_EACH_CHUNK_ID$
 		if (
 			__webpack_require__.o(installedChunks, chunkId) &&
 			installedChunks[chunkId]
@@ -2,7 +2,7 @@ var inProgress = {};
 
 $UNIQUE_PREFIX$
 // loadScript function to load a script via script tag
-__webpack_require__.l = function (url, done, key, chunkId$FETCH_PRIORITY$) {
+__webpack_require__.l = $FUNCTION$(url, done, key, chunkId$FETCH_PRIORITY$)$ARROW$ {
 	if (inProgress[url]) {
 		inProgress[url].push(done);
 		return;
@@ -34,14 +34,14 @@ __webpack_require__.l = function (url, done, key, chunkId$FETCH_PRIORITY$) {
 		__CROSS_ORIGIN_LOADING_PLACEHOLDER__
 	}
 	inProgress[url] = [done];
-	var onScriptComplete = function (prev, event) {
+	var onScriptComplete = $FUNCTION$(prev, event)$ARROW$ {
 		script.onerror = script.onload = null;
 		clearTimeout(timeout);
 		var doneFns = inProgress[url];
 		delete inProgress[url];
 		script.parentNode && script.parentNode.removeChild(script);
 		doneFns &&
-			doneFns.forEach(function (fn) {
+			doneFns.forEach($FUNCTION$(fn)$ARROW$ {
 				return fn(event);
 			});
 		if (prev) return prev(event);
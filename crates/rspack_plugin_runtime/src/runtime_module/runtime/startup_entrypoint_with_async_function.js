@@ -0,0 +1,10 @@
+__webpack_require__.X = async function (result, chunkIds, fn) {
+    // arguments: chunkIds, moduleId are deprecated
+    var moduleId = chunkIds;
+    if (!fn) chunkIds = result, fn = function () {
+        return __webpack_require__(__webpack_require__.s = moduleId);
+    };
+    await Promise.all(chunkIds.map(__webpack_require__.e, __webpack_require__));
+    var r = fn();
+    return r === undefined ? result : r;
+}
\ No newline at end of file
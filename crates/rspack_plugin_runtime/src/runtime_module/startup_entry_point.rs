@@ -26,9 +26,18 @@ impl RuntimeModule for StartupEntrypointRuntimeModule {
     self.id
   }
 
-  fn generate(&self, _compilation: &Compilation) -> rspack_error::Result<BoxSource> {
+  fn generate(&self, compilation: &Compilation) -> rspack_error::Result<BoxSource> {
     let source = if self.async_chunk_loading {
-      include_str!("runtime/startup_entrypoint_with_async.js")
+      if compilation
+        .options
+        .output
+        .environment
+        .supports_async_function()
+      {
+        include_str!("runtime/startup_entrypoint_with_async_function.js")
+      } else {
+        include_str!("runtime/startup_entrypoint_with_async.js")
+      }
     } else {
       include_str!("runtime/startup_entrypoint.js")
     };
@@ -0,0 +1,34 @@
+use rspack_collections::Identifier;
+use rspack_core::{
+  impl_runtime_module,
+  rspack_sources::{BoxSource, RawSource, SourceExt},
+  Compilation, RuntimeGlobals, RuntimeModule,
+};
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct UncaughtErrorHandlerRuntimeModule {
+  id: Identifier,
+}
+
+impl Default for UncaughtErrorHandlerRuntimeModule {
+  fn default() -> Self {
+    Self::with_default(Identifier::from("webpack/runtime/uncaught_error_handler"))
+  }
+}
+
+impl RuntimeModule for UncaughtErrorHandlerRuntimeModule {
+  fn name(&self) -> Identifier {
+    self.id
+  }
+
+  fn generate(&self, _: &Compilation) -> rspack_error::Result<BoxSource> {
+    Ok(
+      RawSource::from(format!(
+        "{} = function (err) {{\n  console.error(err);\n  throw err;\n}};",
+        RuntimeGlobals::UNCAUGHT_ERROR_HANDLER
+      ))
+      .boxed(),
+    )
+  }
+}
@@ -2,7 +2,7 @@ use itertools::Itertools;
 use rspack_collections::{UkeyIndexMap, UkeyIndexSet};
 use rspack_core::{
   get_chunk_from_ukey, get_js_chunk_filename_template, stringify_map, Chunk, ChunkKind,
-  ChunkLoading, ChunkUkey, Compilation, PathData, SourceType,
+  ChunkLoading, ChunkUkey, Compilation, Environment, PathData, SourceType,
 };
 use rspack_util::test::{
   HOT_TEST_ACCEPT, HOT_TEST_DISPOSE, HOT_TEST_OUTDATED, HOT_TEST_RUNTIME, HOT_TEST_UPDATED,
@@ -142,16 +142,22 @@ pub fn get_output_dir(
   ))
 }
 
+pub fn get_chunk_loading<'a>(
+  chunk_ukey: &ChunkUkey,
+  compilation: &'a Compilation,
+) -> &'a ChunkLoading {
+  get_chunk_from_ukey(chunk_ukey, &compilation.chunk_by_ukey)
+    .and_then(|chunk| chunk.get_entry_options(&compilation.chunk_group_by_ukey))
+    .and_then(|options| options.chunk_loading.as_ref())
+    .unwrap_or(&compilation.options.output.chunk_loading)
+}
+
 pub fn is_enabled_for_chunk(
   chunk_ukey: &ChunkUkey,
   expected: &ChunkLoading,
   compilation: &Compilation,
 ) -> bool {
-  let chunk_loading = get_chunk_from_ukey(chunk_ukey, &compilation.chunk_by_ukey)
-    .and_then(|chunk| chunk.get_entry_options(&compilation.chunk_group_by_ukey))
-    .and_then(|options| options.chunk_loading.as_ref())
-    .unwrap_or(&compilation.options.output.chunk_loading);
-  chunk_loading == expected
+  get_chunk_loading(chunk_ukey, compilation) == expected
 }
 
 pub fn unquoted_stringify(chunk: &Chunk, str: &String) -> String {
@@ -257,6 +263,20 @@ pub fn create_fake_chunk(
   fake_chunk
 }
 
+/// Render the `$FUNCTION$(args)$ARROW$ {` markers of a runtime template as arrow functions
+/// when the environment supports them, and as function expressions otherwise.
+///
+/// Only mark the functions which don't rely on `this` or `arguments`.
+pub fn render_basic_functions(environment: &Environment, template: &str) -> String {
+  if environment.supports_arrow_function() {
+    template.replace("$FUNCTION$", "").replace("$ARROW$", " =>")
+  } else {
+    template
+      .replace("$FUNCTION$", "function ")
+      .replace("$ARROW$", "")
+  }
+}
+
 #[test]
 fn test_get_undo_path() {
   assert_eq!(get_undo_path("a", "/a/b/c".to_string(), true), "./");
@@ -275,3 +295,41 @@ pub fn generate_javascript_hmr_runtime(method: &str) -> String {
     .replace("$HOT_TEST_RUNTIME$", &HOT_TEST_RUNTIME)
     .replace("$HOT_TEST_ACCEPT$", &HOT_TEST_ACCEPT)
 }
+
+#[test]
+fn test_render_basic_functions() {
+  let template =
+    "var f = $FUNCTION$(a, b)$ARROW$ {\n  return [1].map($FUNCTION$(x)$ARROW$ { return x; });\n};";
+  let mut environment = Environment::default();
+  assert_eq!(
+    render_basic_functions(&environment, template),
+    "var f = function (a, b) {\n  return [1].map(function (x) { return x; });\n};"
+  );
+  environment.arrow_function = Some(true);
+  assert_eq!(
+    render_basic_functions(&environment, template),
+    "var f = (a, b) => {\n  return [1].map((x) => { return x; });\n};"
+  );
+}
+
+#[test]
+fn test_basic_function_templates() {
+  // the marked functions are rendered as arrow functions, which have no `this` nor `arguments`
+  let templates = [
+    include_str!("runtime/define_property_getters.js"),
+    include_str!("runtime/ensure_chunk.js"),
+    include_str!("runtime/ensure_chunk_with_inline.js"),
+    include_str!("runtime/jsonp_chunk_loading.js"),
+    include_str!("runtime/jsonp_chunk_loading_with_callback.js"),
+    include_str!("runtime/load_script.js"),
+  ];
+  for template in templates {
+    assert!(template.contains("$FUNCTION$"));
+    let code = template
+      .lines()
+      .filter(|line| !line.trim_start().starts_with("//"))
+      .join("\n");
+    assert!(!code.contains("this"));
+    assert!(!code.contains("arguments"));
+  }
+}
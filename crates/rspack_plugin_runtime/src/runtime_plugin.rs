@@ -2,29 +2,31 @@ use std::hash::Hash;
 use std::sync::LazyLock;
 
 use async_trait::async_trait;
+use itertools::Itertools;
 use rspack_core::{
   get_css_chunk_filename_template, get_js_chunk_filename_template, has_hash_placeholder,
-  ApplyContext, ChunkLoading, ChunkUkey, Compilation, CompilationParams,
-  CompilationRuntimeRequirementInModule, CompilationRuntimeRequirementInTree, CompilerCompilation,
-  CompilerOptions, ModuleIdentifier, Plugin, PluginContext, PublicPath, RuntimeGlobals,
-  RuntimeModuleExt, SourceType,
+  ApplyContext, ChunkLoading, ChunkLoadingType, ChunkUkey, Compilation, CompilationParams,
+  CompilationRuntimeRequirementInModule, CompilationRuntimeRequirementInTree, CompilationSeal,
+  CompilerCompilation, CompilerOptions, ModuleIdentifier, Plugin, PluginContext, PublicPath,
+  RuntimeGlobals, RuntimeModuleExt, SourceType,
 };
-use rspack_error::Result;
+use rspack_error::{error, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::{JavascriptModulesChunkHash, JsPlugin};
 
 use crate::runtime_module::{
-  chunk_has_css, is_enabled_for_chunk, AsyncRuntimeModule, AutoPublicPathRuntimeModule,
-  BaseUriRuntimeModule, ChunkNameRuntimeModule, ChunkPrefetchPreloadFunctionRuntimeModule,
-  CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
-  CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule,
-  GetChunkFilenameRuntimeModule, GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule,
-  GetMainFilenameRuntimeModule, GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule,
-  HarmonyModuleDecoratorRuntimeModule, HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule,
-  MakeNamespaceObjectRuntimeModule, NodeModuleDecoratorRuntimeModule, NonceRuntimeModule,
-  OnChunkLoadedRuntimeModule, PublicPathRuntimeModule, RelativeUrlRuntimeModule,
-  RuntimeIdRuntimeModule, SystemContextRuntimeModule,
+  chunk_has_css, is_enabled_for_chunk, AmdDefineRuntimeModule, AmdOptionsRuntimeModule,
+  AsyncRuntimeModule, AutoPublicPathRuntimeModule, BaseUriRuntimeModule, ChunkNameRuntimeModule,
+  ChunkPrefetchPreloadFunctionRuntimeModule, CompatGetDefaultExportRuntimeModule,
+  CreateFakeNamespaceObjectRuntimeModule, CreateScriptUrlRuntimeModule,
+  DefinePropertyGettersRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
+  GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
+  GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HarmonyModuleDecoratorRuntimeModule,
+  HasOwnPropertyRuntimeModule, LoadScriptRuntimeModule, MakeNamespaceObjectRuntimeModule,
+  NodeModuleDecoratorRuntimeModule, NonceRuntimeModule, OnChunkLoadedRuntimeModule,
+  PublicPathRuntimeModule, RelativeUrlRuntimeModule, RuntimeIdRuntimeModule,
+  SystemContextRuntimeModule, UncaughtErrorHandlerRuntimeModule,
 };
 
 static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
@@ -50,7 +52,7 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::BASE_URI,
     RuntimeGlobals::RELATIVE_URL,
     RuntimeGlobals::SCRIPT_NONCE,
-    // RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
+    RuntimeGlobals::UNCAUGHT_ERROR_HANDLER,
     RuntimeGlobals::ASYNC_MODULE,
     // RuntimeGlobals::WASM_INSTANCES,
     RuntimeGlobals::INSTANTIATE_WASM,
@@ -59,6 +61,8 @@ static GLOBALS_ON_REQUIRE: LazyLock<Vec<RuntimeGlobals>> = LazyLock::new(|| {
     RuntimeGlobals::LOAD_SCRIPT,
     RuntimeGlobals::SYSTEM_CONTEXT,
     RuntimeGlobals::ON_CHUNKS_LOADED,
+    RuntimeGlobals::AMD_DEFINE,
+    RuntimeGlobals::AMD_OPTIONS,
   ]
 });
 
@@ -155,6 +159,39 @@ async fn compilation(
   Ok(())
 }
 
+#[plugin_hook(CompilationSeal for RuntimePlugin)]
+async fn seal(&self, compilation: &mut Compilation) -> Result<()> {
+  // report the chunk loading types without a runtime once, instead of once per chunk
+  let output = &compilation.options.output;
+  let unknown_chunk_loadings = [&output.chunk_loading, &output.worker_chunk_loading]
+    .into_iter()
+    .chain(
+      compilation
+        .entries
+        .values()
+        .filter_map(|entry| entry.options.chunk_loading.as_ref()),
+    )
+    .filter_map(|chunk_loading| match chunk_loading {
+      ChunkLoading::Enable(ChunkLoadingType::Custom(name))
+        if !output.custom_chunk_loading_types.contains(name) =>
+      {
+        Some(name.clone())
+      }
+      _ => None,
+    })
+    .unique()
+    .collect::<Vec<_>>();
+  for name in unknown_chunk_loadings {
+    compilation.push_diagnostic(
+      error!(
+        "Unknown chunk loading type \"{name}\", a CustomChunkLoadingPlugin is needed to provide its runtime"
+      )
+      .into(),
+    );
+  }
+  Ok(())
+}
+
 #[plugin_hook(JavascriptModulesChunkHash for RuntimePlugin)]
 async fn js_chunk_hash(
   &self,
@@ -403,6 +440,23 @@ fn runtime_requirements_in_tree(
       RuntimeGlobals::GLOBAL => {
         compilation.add_runtime_module(chunk_ukey, GlobalRuntimeModule::default().boxed())?;
       }
+      RuntimeGlobals::AMD_DEFINE => {
+        compilation.add_runtime_module(chunk_ukey, AmdDefineRuntimeModule::default().boxed())?;
+      }
+      RuntimeGlobals::AMD_OPTIONS => {
+        if let Some(options) = &compilation.options.amd {
+          compilation.add_runtime_module(
+            chunk_ukey,
+            AmdOptionsRuntimeModule::new(options.clone()).boxed(),
+          )?;
+        }
+      }
+      RuntimeGlobals::UNCAUGHT_ERROR_HANDLER => {
+        compilation.add_runtime_module(
+          chunk_ukey,
+          UncaughtErrorHandlerRuntimeModule::default().boxed(),
+        )?;
+      }
       RuntimeGlobals::CREATE_SCRIPT_URL => {
         compilation
           .add_runtime_module(chunk_ukey, CreateScriptUrlRuntimeModule::default().boxed())?;
@@ -514,6 +568,7 @@ impl Plugin for RuntimePlugin {
       .compiler_hooks
       .compilation
       .tap(compilation::new(self));
+    ctx.context.compilation_hooks.seal.tap(seal::new(self));
     ctx
       .context
       .compilation_hooks
@@ -14,7 +14,7 @@ use rspack_core::rspack_sources::{ConcatSource, MapOptions, RawSource, SourceExt
 use rspack_core::rspack_sources::{Source, SourceMapSource, SourceMapSourceOptions};
 use rspack_core::{
   AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
-  CompilerCompilation, Plugin, PluginContext,
+  CompilerCompilation, EsVersion, Plugin, PluginContext, TargetEsVersion,
 };
 use rspack_error::miette::IntoDiagnostic;
 use rspack_error::{Diagnostic, Result};
@@ -152,6 +152,7 @@ async fn js_chunk_hash(
 async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   let options = &self.options;
   let minimizer_options = &self.options.minimizer_options;
+  let ecma = target_ecma(compilation);
 
   let (tx, rx) = mpsc::channel::<Vec<Diagnostic>>();
   // collect all extracted comments info
@@ -207,6 +208,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
           inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
           module: is_module,
+          ecma: ecma.clone(),
           ..Default::default()
           };
         let extract_comments_option = options.extract_comments.as_ref().map(|extract_comments| {
@@ -321,6 +323,50 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
   }
 }
 
+/// Minifies an inline script, e.g. a `<script>` element of a html page, with the default
+/// options of the minimizer. Returns `None` when the script can't be parsed.
+pub fn minify_inline_script(
+  compilation: &Compilation,
+  code: String,
+  filename: &str,
+  module: bool,
+) -> Option<String> {
+  let options = JsMinifyOptions {
+    minify: true,
+    module: Some(module),
+    ecma: target_ecma(compilation),
+    ..Default::default()
+  };
+  minify(&options, code, filename, &Default::default(), &None)
+    .ok()
+    .map(|output| output.code)
+}
+
+/// The ecma version of the syntax the target supports
+fn target_ecma(compilation: &Compilation) -> TerserEcmaVersion {
+  match compilation.options.target.es_version {
+    TargetEsVersion::Esx(es_version) | TargetEsVersion::BrowsersList(es_version) => {
+      es_version_to_ecma(es_version)
+    }
+    _ => Default::default(),
+  }
+}
+
+fn es_version_to_ecma(es_version: EsVersion) -> TerserEcmaVersion {
+  TerserEcmaVersion::Num(match es_version {
+    EsVersion::Es3 => 3,
+    EsVersion::Es5 => 5,
+    EsVersion::Es2015 => 2015,
+    EsVersion::Es2016 => 2016,
+    EsVersion::Es2017 => 2017,
+    EsVersion::Es2018 => 2018,
+    EsVersion::Es2019 => 2019,
+    EsVersion::Es2020 => 2020,
+    EsVersion::Es2021 => 2021,
+    EsVersion::Es2022 | EsVersion::EsNext => 2022,
+  })
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct JsMinifyOptions {
   pub minify: bool,
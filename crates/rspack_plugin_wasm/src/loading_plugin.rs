@@ -1,17 +1,40 @@
 use rspack_core::{
   BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginContext,
-  PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoadingType,
+  PluginExt, RuntimeGlobals, RuntimeModuleExt, WasmLoading, WasmLoadingType,
 };
-use rspack_error::Result;
+use rspack_error::{error, Result};
 use rspack_hook::{plugin, plugin_hook};
 
 use crate::AsyncWasmLoadingRuntimeModule;
 
+/// The wasm loading of the entrypoint of the chunk, `output.wasmLoading` when it has none.
+pub(crate) fn get_wasm_loading<'a>(
+  chunk_ukey: &ChunkUkey,
+  compilation: &'a Compilation,
+) -> &'a WasmLoading {
+  compilation
+    .chunk_by_ukey
+    .expect_get(chunk_ukey)
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.wasm_loading.as_ref())
+    .unwrap_or(&compilation.options.output.wasm_loading)
+}
+
+pub(crate) fn is_enabled_for_chunk(
+  chunk_ukey: &ChunkUkey,
+  expected: &WasmLoadingType,
+  compilation: &Compilation,
+) -> bool {
+  matches!(get_wasm_loading(chunk_ukey, compilation), WasmLoading::Enable(wasm_loading) if wasm_loading == expected)
+}
+
 pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlugin {
   match wasm_loading_type {
     WasmLoadingType::Fetch => FetchCompileAsyncWasmPlugin::default().boxed(),
     WasmLoadingType::AsyncNode => ReadFileCompileAsyncWasmPlugin::new(false).boxed(),
     WasmLoadingType::AsyncNodeModule => ReadFileCompileAsyncWasmPlugin::new(true).boxed(),
+    WasmLoadingType::Universal => UniversalCompileAsyncWasmPlugin::default().boxed(),
+    WasmLoadingType::Inline => InlineCompileAsyncWasmPlugin::default().boxed(),
   }
 }
 
@@ -27,7 +50,9 @@ fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM)
+    && is_enabled_for_chunk(chunk_ukey, &WasmLoadingType::Fetch, compilation)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
@@ -82,7 +107,14 @@ fn read_file_compile_async_wasm_plugin_runtime_requirements_in_tree(
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
 ) -> Result<Option<()>> {
-  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
+  let wasm_loading = if self.import {
+    WasmLoadingType::AsyncNodeModule
+  } else {
+    WasmLoadingType::AsyncNode
+  };
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM)
+    && is_enabled_for_chunk(chunk_ukey, &wasm_loading, compilation)
+  {
     runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
     compilation.add_runtime_module(
       chunk_ukey,
@@ -120,3 +152,116 @@ impl Plugin for ReadFileCompileAsyncWasmPlugin {
     Ok(())
   }
 }
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct UniversalCompileAsyncWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for UniversalCompileAsyncWasmPlugin)]
+fn universal_compile_async_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  _runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM)
+    && is_enabled_for_chunk(chunk_ukey, &WasmLoadingType::Universal, compilation)
+  {
+    // the runtime resolves the binary with `import.meta.url`, which is only available in ES modules
+    if !compilation.options.output.module {
+      let chunk = compilation.chunk_by_ukey.expect_get(chunk_ukey);
+      let name = chunk
+        .name
+        .as_deref()
+        .or(chunk.id.as_deref())
+        .unwrap_or_default();
+      compilation.push_diagnostic(
+        error!(
+          "Chunk \"{name}\" uses the \"universal\" wasm loading, which requires \"output.module\" to be enabled"
+        )
+        .into(),
+      );
+    }
+    compilation.add_runtime_module(
+      chunk_ukey,
+      AsyncWasmLoadingRuntimeModule::new(
+        include_str!("runtime/universal_compile_async_wasm.js").to_string(),
+        // the fake response of fs can't be streamed
+        false,
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for UniversalCompileAsyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "UniversalCompileAsyncWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(universal_compile_async_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
+
+#[plugin]
+#[derive(Debug, Default)]
+pub struct InlineCompileAsyncWasmPlugin;
+
+#[plugin_hook(CompilationRuntimeRequirementInTree for InlineCompileAsyncWasmPlugin)]
+fn inline_compile_async_wasm_plugin_runtime_requirements_in_tree(
+  &self,
+  compilation: &mut Compilation,
+  chunk_ukey: &ChunkUkey,
+  runtime_requirements: &RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+) -> Result<Option<()>> {
+  if runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM)
+    && is_enabled_for_chunk(chunk_ukey, &WasmLoadingType::Inline, compilation)
+  {
+    // large binaries are still emitted and fetched
+    runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+    compilation.add_runtime_module(
+      chunk_ukey,
+      AsyncWasmLoadingRuntimeModule::new_inline(
+        include_str!("runtime/inline_compile_async_wasm.js").to_string(),
+        *chunk_ukey,
+      )
+      .boxed(),
+    )?;
+  }
+
+  Ok(None)
+}
+
+impl Plugin for InlineCompileAsyncWasmPlugin {
+  fn name(&self) -> &'static str {
+    "InlineCompileAsyncWasmPlugin"
+  }
+
+  fn apply(
+    &self,
+    ctx: PluginContext<&mut rspack_core::ApplyContext>,
+    _options: &mut rspack_core::CompilerOptions,
+  ) -> Result<()> {
+    ctx
+      .context
+      .compilation_hooks
+      .runtime_requirement_in_tree
+      .tap(inline_compile_async_wasm_plugin_runtime_requirements_in_tree::new(self));
+    Ok(())
+  }
+}
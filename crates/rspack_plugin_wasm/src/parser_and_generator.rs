@@ -10,14 +10,16 @@ use rspack_core::{
   AssetInfo, BoxDependency, BuildMetaExportsType, Compilation, FilenameTemplate, GenerateContext,
   Module, ModuleDependency, ModuleIdentifier, NormalModule, ParseContext, ParseResult,
   ParserAndGenerator, PathData, RuntimeGlobals, SourceType, StaticExportsDependency,
-  StaticExportsSpec, UsedName,
+  StaticExportsSpec, UsedName, WasmLoadingType,
 };
 use rspack_error::{Diagnostic, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_util::infallible::ResultInfallibleExt as _;
+use rustc_hash::FxHashSet;
 use swc_core::atoms::Atom;
 use wasmparser::{Import, Parser, Payload};
 
 use crate::dependency::WasmImportDependency;
+use crate::loading_plugin::is_enabled_for_chunk;
 use crate::ModuleIdToFileName;
 
 #[derive(Debug)]
@@ -231,20 +233,30 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
           .collect::<Vec<_>>();
 
         let imports_obj = if !import_obj_request_items.is_empty() {
-          Some(format!(
-            ", {{\n{}\n}}",
-            &import_obj_request_items.join(",\n")
-          ))
+          Some(format!("{{\n{}\n}}", &import_obj_request_items.join(",\n")))
         } else {
           None
         };
 
-        let instantiate_call = format!(
-          "{}(exports, module.id, {} {})",
-          RuntimeGlobals::INSTANTIATE_WASM,
-          serde_json::to_string(&hash).expect("should be ok"),
-          imports_obj.unwrap_or_default()
-        );
+        let instantiate_call = if should_inline_wasm(compilation, &module.identifier(), source) {
+          format!(
+            "{}(exports, module.id, {}, {}, {})",
+            RuntimeGlobals::INSTANTIATE_WASM,
+            serde_json::to_string(&hash).expect("should be ok"),
+            imports_obj.as_deref().unwrap_or("undefined"),
+            serde_json::to_string(&rspack_base64::encode_to_string(source.buffer()))
+              .expect("should be ok")
+          )
+        } else {
+          format!(
+            "{}(exports, module.id, {} {})",
+            RuntimeGlobals::INSTANTIATE_WASM,
+            serde_json::to_string(&hash).expect("should be ok"),
+            imports_obj
+              .map(|imports_obj| format!(", {imports_obj}"))
+              .unwrap_or_default()
+          )
+        };
 
         let source = if !promises.is_empty() {
           generate_context
@@ -293,6 +305,45 @@ impl ParserAndGenerator for AsyncWasmParserAndGenerator {
   }
 }
 
+/// Wasm binaries up to this size are embedded into the JavaScript with the inline wasm loading.
+const INLINE_WASM_MAX_SIZE: usize = 8 * 1024;
+
+/// Small binaries are embedded when every runtime the module is used in loads wasm inline,
+/// as the code of the module is shared by all of them.
+pub(crate) fn should_inline_wasm(
+  compilation: &Compilation,
+  module: &ModuleIdentifier,
+  source: &BoxSource,
+) -> bool {
+  if source.size() > INLINE_WASM_MAX_SIZE {
+    return false;
+  }
+  let module_runtimes = compilation
+    .chunk_graph
+    .get_module_chunks(*module)
+    .iter()
+    .map(|chunk| compilation.chunk_by_ukey.expect_get(chunk))
+    .flat_map(|chunk| chunk.runtime.iter())
+    .collect::<FxHashSet<_>>();
+  compilation
+    .entrypoints
+    .values()
+    .chain(compilation.async_entrypoints.iter())
+    .map(|entrypoint| compilation.chunk_group_by_ukey.expect_get(entrypoint))
+    .map(|entrypoint| entrypoint.get_runtime_chunk(&compilation.chunk_group_by_ukey))
+    .filter(|runtime_chunk| {
+      compilation
+        .chunk_by_ukey
+        .expect_get(runtime_chunk)
+        .runtime
+        .iter()
+        .any(|runtime| module_runtimes.contains(runtime))
+    })
+    .all(|runtime_chunk| {
+      is_enabled_for_chunk(&runtime_chunk, &WasmLoadingType::Inline, compilation)
+    })
+}
+
 fn render_wasm_name(
   compilation: &Compilation,
   normal_module: &NormalModule,
@@ -12,6 +12,8 @@ pub struct AsyncWasmLoadingRuntimeModule {
   id: Identifier,
   generate_load_binary_code: String,
   supports_streaming: bool,
+  /// Whether the binary may be passed to the loading function as base64
+  inline: bool,
   chunk: ChunkUkey,
 }
 
@@ -25,6 +27,17 @@ impl AsyncWasmLoadingRuntimeModule {
       Identifier::from("webpack/runtime/async_wasm_loading"),
       generate_load_binary_code,
       supports_streaming,
+      false,
+      chunk,
+    )
+  }
+
+  pub fn new_inline(generate_load_binary_code: String, chunk: ChunkUkey) -> Self {
+    Self::with_default(
+      Identifier::from("webpack/runtime/async_wasm_loading"),
+      generate_load_binary_code,
+      false,
+      true,
       chunk,
     )
   }
@@ -68,6 +81,7 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
             compilation.options.output.import_meta_name.as_str(),
           ),
         self.supports_streaming,
+        self.inline,
       ))
       .boxed(),
     )
@@ -78,7 +92,7 @@ impl RuntimeModule for AsyncWasmLoadingRuntimeModule {
   }
 }
 
-fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
+fn get_async_wasm_loading(req: &str, supports_streaming: bool, inline: bool) -> String {
   let fallback_code = r#"
           .then(function(x) { return x.arrayBuffer();})
           .then(function(bytes) { return WebAssembly.instantiate(bytes, importsObj);})
@@ -104,10 +118,16 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
       });
 "#;
 
+  let params = if inline {
+    "exports, wasmModuleId, wasmModuleHash, importsObj, wasmModuleBase64"
+  } else {
+    "exports, wasmModuleId, wasmModuleHash, importsObj"
+  };
+
   if supports_streaming {
     format!(
       r#"
-    __webpack_require__.v = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{
+    __webpack_require__.v = function({params}) {{
       var req = {req};
       var fallback = function() {{
         return req{fallback_code}
@@ -120,7 +140,7 @@ fn get_async_wasm_loading(req: &str, supports_streaming: bool) -> String {
     let req = req.trim_end_matches(';');
     format!(
       r#"
-    __webpack_require__.v = function(exports, wasmModuleId, wasmModuleHash, importsObj) {{
+    __webpack_require__.v = function({params}) {{
       return {req}{fallback_code}
     }};
       "#
@@ -0,0 +1,13 @@
+(wasmModuleBase64
+  ? new Promise(function (resolve) {
+      var binary = typeof atob === 'function'
+        ? atob(wasmModuleBase64)
+        : Buffer.from(wasmModuleBase64, 'base64').toString('binary');
+      var bytes = new Uint8Array(binary.length);
+      for (var i = 0; i < binary.length; i++) bytes[i] = binary.charCodeAt(i);
+      // Fake fetch response
+      resolve({
+        arrayBuffer() { return bytes.buffer; }
+      });
+    })
+  : fetch(__webpack_require__.p + $PATH));
\ No newline at end of file
@@ -0,0 +1,11 @@
+(typeof document !== 'undefined' || typeof self !== 'undefined'
+  ? fetch(new URL($PATH, $IMPORT_META_NAME.url))
+  : Promise.all([import('fs'), import('url')]).then(([{ readFile }, { URL }]) => new Promise((resolve, reject) => {
+      readFile(new URL($PATH, $IMPORT_META_NAME.url), (err, buffer) => {
+          if (err) return reject(err);
+          // Fake fetch response
+          resolve({
+              arrayBuffer() { return buffer; }
+          })
+      });
+  })));
\ No newline at end of file
@@ -10,6 +10,7 @@ use rspack_core::{
 use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
 
+use crate::parser_and_generator::should_inline_wasm;
 use crate::{AsyncWasmParserAndGenerator, ModuleIdToFileName};
 
 pub struct EnableWasmLoadingPlugin;
@@ -60,14 +61,17 @@ async fn render_manifest(
         .code_generation_results
         .get(&m.identifier(), Some(&chunk.runtime));
 
-      let result = code_gen_result.get(&SourceType::Wasm).map(|source| {
-        let (output_path, asset_info) = self
-          .module_id_to_filename_without_ext
-          .get(&m.identifier())
-          .map(|s| s.clone())
-          .expect("should have wasm_filename");
-        RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
-      });
+      let result = code_gen_result
+        .get(&SourceType::Wasm)
+        .filter(|source| !should_inline_wasm(compilation, &m.identifier(), source))
+        .map(|source| {
+          let (output_path, asset_info) = self
+            .module_id_to_filename_without_ext
+            .get(&m.identifier())
+            .map(|s| s.clone())
+            .expect("should have wasm_filename");
+          RenderManifestEntry::new(source.clone(), output_path, asset_info, false, false)
+        });
 
       Ok(result)
     })
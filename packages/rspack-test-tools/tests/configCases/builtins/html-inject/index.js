@@ -38,3 +38,19 @@ it("false-index.html inject", () => {
 	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
 	expect(htmlContent.includes('<script src="bundle4.js"')).toBe(false);
 });
+
+it("head-prepend-index.html inject", () => {
+	const htmlPath = path.join(__dirname, "./head-prepend-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(
+		htmlContent.includes('<head><script defer src="bundle5.js"></script>')
+	).toBe(true);
+});
+
+it("body-prepend-index.html inject", () => {
+	const htmlPath = path.join(__dirname, "./body-prepend-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(
+		htmlContent.includes('<body><script defer src="bundle6.js"></script>')
+	).toBe(true);
+});
@@ -41,5 +41,21 @@ module.exports = [
 				inject: false
 			})
 		]
+	},
+	{
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "head-prepend-index.html",
+				inject: "head-prepend"
+			})
+		]
+	},
+	{
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "body-prepend-index.html",
+				inject: "body-prepend"
+			})
+		]
 	}
 ];
@@ -0,0 +1,23 @@
+const fs = require("fs");
+const path = require("path");
+
+it("html minify should remove comments and collapse whitespaces", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).not.toContain("<!--");
+	expect(htmlContent).toContain("hello world");
+	expect(htmlContent).not.toContain("\n");
+});
+
+it("html minify should minify inline styles with lightningcss", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).toContain(".test{background:red}");
+	expect(htmlContent).toContain('style="color:#00f"');
+});
+
+it("html minify should minify inline scripts with the swc minimizer", () => {
+	const htmlPath = path.join(__dirname, "./index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(htmlContent).toContain("<script>var a=123;console.log(a);</script>");
+});
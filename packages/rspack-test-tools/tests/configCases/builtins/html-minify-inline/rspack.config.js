@@ -0,0 +1,11 @@
+const { rspack } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new rspack.HtmlRspackPlugin({
+			template: "./index.html",
+			minify: true
+		})
+	]
+};
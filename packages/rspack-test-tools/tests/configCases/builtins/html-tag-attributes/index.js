@@ -0,0 +1,27 @@
+const fs = require("fs");
+const path = require("path");
+
+it("function-index.html attributes", () => {
+	const htmlPath = path.join(__dirname, "./function-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(
+		htmlContent.includes(
+			'<script data-entry defer nonce="abc" src="bundle0.js"></script>'
+		)
+	).toBe(true);
+	expect(htmlContent.includes("<title nonce")).toBe(false);
+});
+
+it("object-index.html attributes", () => {
+	const htmlPath = path.join(__dirname, "./object-index.html");
+	const htmlContent = fs.readFileSync(htmlPath, "utf-8");
+	expect(
+		htmlContent.includes(
+			'<script defer fetchpriority="high" src="bundle1.js"></script>'
+		)
+	).toBe(true);
+	// only the script and link tags get the attributes of the object form
+	expect(htmlContent).toMatch(/<meta[^>]*name="viewport"/);
+	expect(htmlContent).not.toMatch(/<meta[^>]*fetchpriority/);
+	expect(htmlContent).not.toMatch(/<title[^>]*fetchpriority/);
+});
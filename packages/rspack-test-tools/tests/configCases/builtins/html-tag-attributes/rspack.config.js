@@ -0,0 +1,25 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+module.exports = [
+	{
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "function-index.html",
+				attributes: tag => {
+					if (tag.tagName === "script") {
+						return { nonce: "abc", "data-entry": true };
+					}
+				}
+			})
+		]
+	},
+	{
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: "object-index.html",
+				meta: { viewport: "width=device-width" },
+				attributes: { fetchpriority: "high" }
+			})
+		]
+	}
+];
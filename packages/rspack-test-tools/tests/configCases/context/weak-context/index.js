@@ -0,0 +1,23 @@
+import c from "./modules/c";
+
+it("should throw when the module of a weak import context is not available", async () => {
+	const name = "a";
+	await expect(
+		import(/* webpackMode: "weak" */ `./modules/${name}`)
+	).rejects.toThrow(/Module '\.\/a' \('.*'\) is not available \(weak dependency\)/);
+});
+
+it("should use the module of a weak import context when it's available", async () => {
+	const name = "c";
+	const module = await import(/* webpackMode: "weak" */ `./modules/${name}`);
+	expect(module.default).toBe(c);
+});
+
+it("should resolve the id of a weak context module without bundling the module", () => {
+	const a = "a";
+	const b = "b";
+	const idA = require.resolveWeak(`./modules/${a}`);
+	const idB = require.resolveWeak("./modules/" + b);
+	expect(__webpack_modules__[idA]).toBeUndefined();
+	expect(__webpack_modules__[idB]).toBeUndefined();
+});
@@ -0,0 +1,4 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js"
+};
@@ -0,0 +1,27 @@
+import * as styles from "./style.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should export :export values and the values imported with :import", () => {
+	expect(styles.plain).toBe("10px");
+	expect(styles.brand).toBe("#f00");
+});
+
+it("should compose global classes", () => {
+	expect(styles.link.split(" ")).toContain("global-link");
+	expect(styles.link.split(" ")).not.toContain("link");
+});
+
+it("should substitute the values imported with :import", async () => {
+	const css = (
+		await fs.promises.readFile(path.resolve(__dirname, "bundle0.css"), "utf-8")
+	).replace(/\s+/g, " ");
+
+	expect(css).toContain("color: #f00;");
+	expect(css).toContain("margin: 4px 0;");
+	expect(css).not.toContain(":import");
+	expect(css).not.toContain(":export");
+	expect(css).not.toContain("themePrimary");
+	expect(css).not.toContain("composes");
+});
@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: false,
+	mode: "development",
+	module: {
+		generator: {
+			"css/auto": {
+				exportsOnly: false,
+				localIdentName: "[local]-[hash:4]"
+			}
+		}
+	},
+	experiments: {
+		css: true
+	}
+};
@@ -0,0 +1,39 @@
+import "./index.css";
+import "./layered.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const squash = css => css.replace(/\s+/g, " ");
+
+it("should wrap imported css in the conditions of the @import", async () => {
+	const css = squash(
+		await fs.promises.readFile(path.resolve(__dirname, "bundle0.css"), "utf-8")
+	);
+
+	expect(css).toContain("@layer base { .layered { color: blue; }");
+	expect(css).toContain("@layer { .anonymous { color: green; }");
+	expect(css).toContain(
+		"@supports (display: grid) { @media screen and (min-width: 100px) { .supported { display: grid; }"
+	);
+	expect(css).toContain("@layer outer { @media print { .chained {");
+});
+
+it("should nest the conditions of chained imports", async () => {
+	const css = squash(
+		await fs.promises.readFile(path.resolve(__dirname, "bundle0.css"), "utf-8")
+	);
+
+	expect(css).toContain(
+		"@layer outer { @media print { @layer inner { @supports (display: flex) { .inner { display: flex; }"
+	);
+});
+
+it("should create a separate module for each set of conditions", async () => {
+	const css = squash(
+		await fs.promises.readFile(path.resolve(__dirname, "bundle0.css"), "utf-8")
+	);
+
+	expect(css.match(/\.layered \{/g)).toHaveLength(2);
+	expect(css).toContain("} .layered { color: blue; }");
+});
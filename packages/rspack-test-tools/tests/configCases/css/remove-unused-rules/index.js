@@ -0,0 +1,24 @@
+import * as styles from "./style.module.css";
+
+it("should remove the rules of unused local idents", async () => {
+	const fs = __non_webpack_require__("fs");
+	const path = __non_webpack_require__("path");
+	const checkMap = __non_webpack_require__("../../../../../dist/helper/util/checkSourceMap.js").default;
+	expect(styles.used).toBe("used_local");
+	expect(styles["used-after"]).toBe("used-after_local");
+
+	const css = await fs.promises.readFile(path.resolve(__dirname, "./bundle0.css"), "utf-8");
+	expect(css).not.toContain("unused-a_local");
+	expect(css).not.toContain("color: blue");
+	expect(css).not.toContain("@media");
+	expect(css).toContain(".unused_local, div");
+
+	const map = await fs.promises.readFile(path.resolve(__dirname, "./bundle0.css.map"), "utf-8");
+	expect(
+		await checkMap(css, map, {
+			"color: red;": "webpack:///./style.module.css",
+			"color: orange;": "webpack:///./style.module.css",
+			"color: rebeccapurple;": "webpack:///./style.module.css"
+		})
+	).toBe(true);
+});
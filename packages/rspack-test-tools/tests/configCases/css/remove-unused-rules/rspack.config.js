@@ -0,0 +1,27 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	node: {
+		__dirname: false,
+		__filename: false
+	},
+	devtool: "source-map",
+	module: {
+		generator: {
+			"css/auto": {
+				localIdentName: "[local]_local",
+				exportsOnly: false,
+				removeUnusedRules: true
+			}
+		}
+	},
+	optimization: {
+		minimize: false,
+		usedExports: true
+	},
+	externals: ["source-map"],
+	externalsType: "commonjs",
+	experiments: {
+		css: true
+	}
+};
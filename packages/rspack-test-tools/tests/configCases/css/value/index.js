@@ -0,0 +1,26 @@
+import * as styles from "./style.module.css";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should export the declared and imported values alongside the class names", () => {
+	expect(styles.gutter).toBe("8px");
+	expect(styles.spacing).toBe("8px");
+	expect(styles.primary).toBe("#BF4040");
+	expect(styles.accent).toBe("#1F4F7F");
+	expect(styles.tablet).toBe("(min-width: 768px)");
+	expect(typeof styles.title).toBe("string");
+});
+
+it("should substitute the values in declarations and media queries", async () => {
+	const css = (
+		await fs.promises.readFile(path.resolve(__dirname, "bundle0.css"), "utf-8")
+	).replace(/\s+/g, " ");
+
+	expect(css).toContain("color: #BF4040;");
+	expect(css).toContain("border-color: #1F4F7F;");
+	expect(css).toContain("margin: 8px 0;");
+	expect(css).toContain("@media (min-width: 768px) {");
+	expect(css).toContain("padding: 8px;");
+	expect(css).not.toContain("@value");
+});
@@ -0,0 +1,3 @@
+export { b } from "./b";
+export default "dll";
+require("./a");
@@ -0,0 +1,27 @@
+const path = require("path");
+const { DllPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		vendor: ["./index", "./c"]
+	},
+	output: {
+		filename: "dll.js",
+		library: {
+			type: "commonjs2"
+		}
+	},
+	optimization: {
+		moduleIds: "named"
+	},
+	plugins: [
+		new DllPlugin({
+			path: path.resolve(
+				__dirname,
+				"../../../js/config/dll-plugin/manifest.json"
+			),
+			name: "[name]_dll"
+		})
+	]
+};
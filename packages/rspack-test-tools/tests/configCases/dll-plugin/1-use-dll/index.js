@@ -0,0 +1,44 @@
+import value, { b } from "dll/index";
+
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should emit the manifest of the entry modules", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.resolve(__dirname, "../manifest.json"), "utf-8")
+	);
+	expect(manifest.name).toBe("vendor_dll");
+	expect(Object.keys(manifest.content).sort()).toEqual([
+		"./c.js",
+		"./index.js"
+	]);
+	expect(manifest.content["./index.js"].id).toBe("./index.js");
+	expect(manifest.content["./index.js"].buildMeta.exportsType).toBe(
+		"namespace"
+	);
+	expect(manifest.content["./index.js"].exports.sort()).toEqual([
+		"b",
+		"default"
+	]);
+});
+
+it("should require the modules of the dll by their ids", () => {
+	expect(value).toBe("dll");
+	expect(b).toBe("b");
+	expect(require("dll/c")).toBe("c");
+});
+
+it("should delegate the requests to the dll", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toContain('("./index.js");');
+	expect(
+		Object.keys(__webpack_modules__)
+			.filter(m => !m.startsWith("../.."))
+			.sort()
+	).toEqual([
+		"./index.js",
+		"dll-reference ../0-create-dll/dll.js",
+		"dll/c.js",
+		"dll/index.js"
+	]);
+});
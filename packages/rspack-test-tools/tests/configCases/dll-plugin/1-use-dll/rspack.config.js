@@ -0,0 +1,20 @@
+const path = require("path");
+const { DllReferencePlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		moduleIds: "named"
+	},
+	plugins: [
+		new DllReferencePlugin({
+			manifest: path.resolve(
+				__dirname,
+				"../../../js/config/dll-plugin/manifest.json"
+			),
+			name: "../0-create-dll/dll.js",
+			scope: "dll",
+			sourceType: "commonjs2"
+		})
+	]
+};
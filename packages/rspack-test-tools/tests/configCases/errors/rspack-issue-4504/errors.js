@@ -1,3 +1,3 @@
 module.exports = [
-	[/Expected 'md4' \| 'xxhash64', received 'abc' at "output.hashFunction"/]
+	[/Expected 'md4' \| 'xxhash64' \| 'xxhash3' \| 'sha256' \| 'blake3', received 'abc' at "output.hashFunction"/]
 ];
@@ -0,0 +1,5 @@
+it("dirname eval-only", function () {
+	expect(__dirname).toMatch(/[\\/]dirname-eval-only$/);
+	expect(__filename).toMatch(/\.js$/);
+	expect(__filename).not.toMatch(/[\\/]index\.js$/);
+});
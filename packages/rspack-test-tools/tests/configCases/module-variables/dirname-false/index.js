@@ -0,0 +1,5 @@
+it("dirname false", function () {
+	expect(__dirname).not.toBe("/");
+	expect(__filename).toMatch(/\.js$/);
+	expect(__filename).not.toBe("/index.js");
+});
@@ -0,0 +1,12 @@
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	context: __dirname,
+	entry: {
+		main: "./index"
+	},
+	target: "node",
+	node: {
+		__dirname: false,
+		__filename: false
+	}
+};
@@ -2,3 +2,8 @@ it("dirname mock", function () {
 	expect(__dirname).toBe("/");
 	expect(__filename).toBe("/index.js");
 });
+
+it("dirname mock should be evaluated to the mock value", function () {
+	if (__dirname !== "/") require("./missing");
+	if (__filename !== "/index.js") require("./missing");
+});
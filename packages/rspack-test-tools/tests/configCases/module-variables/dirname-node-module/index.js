@@ -0,0 +1,8 @@
+it("dirname node-module", function () {
+	expect(__dirname).toMatch(/[\\/]dirname-node-module$/);
+	expect(__filename).toMatch(/[\\/]main\.mjs$/);
+});
+
+it("dirname node-module should not be evaluated", function () {
+	expect(__filename === "/index.js").toBe(false);
+});
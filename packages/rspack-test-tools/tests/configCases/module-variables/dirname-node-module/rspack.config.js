@@ -0,0 +1,19 @@
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	context: __dirname,
+	entry: {
+		main: "./index"
+	},
+	output: {
+		filename: "[name].mjs",
+		module: true
+	},
+	experiments: {
+		outputModule: true
+	},
+	target: "node14",
+	node: {
+		__dirname: "node-module",
+		__filename: "node-module"
+	}
+};
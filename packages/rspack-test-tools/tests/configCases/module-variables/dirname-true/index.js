@@ -6,3 +6,8 @@ it("dirname mock", function () {
 	expect(__filename).toBe("index.js");
 	expect(filename.replace(/\\/g, "/")).toBe("child/child.js");
 });
+
+it("dirname true should be evaluated to the relative path", function () {
+	if (__dirname !== "") require("./missing");
+	if (__filename !== "index.js") require("./missing");
+});
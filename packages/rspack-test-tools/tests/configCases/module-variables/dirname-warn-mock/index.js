@@ -0,0 +1,4 @@
+it("dirname warn-mock", function () {
+	expect(__dirname).toBe("/");
+	expect(__filename).toBe("/index.js");
+});
@@ -0,0 +1,11 @@
+/** @type {import("../../../../src/index").RspackOptions} */
+module.exports = {
+	context: __dirname,
+	entry: {
+		main: "./index"
+	},
+	node: {
+		__dirname: "warn-mock",
+		__filename: "warn-mock"
+	}
+};
@@ -0,0 +1,4 @@
+module.exports = [
+	[/__dirname is a Node.js feature and isn't available in browsers/],
+	[/__filename is a Node.js feature and isn't available in browsers/]
+];
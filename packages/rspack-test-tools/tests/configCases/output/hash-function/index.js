@@ -0,0 +1,5 @@
+import digest from "./content.txt";
+
+it("should hash with the configured hash function", () => {
+	expect(digest).toBe(EXPECTED_DIGEST);
+});
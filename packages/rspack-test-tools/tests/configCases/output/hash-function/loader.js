@@ -0,0 +1,4 @@
+module.exports = function (content) {
+	const digest = this.utils.createHash().update(content).digest("hex");
+	return `module.exports = ${JSON.stringify(digest)};`;
+};
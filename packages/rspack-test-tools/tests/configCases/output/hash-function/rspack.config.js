@@ -0,0 +1,39 @@
+const { DefinePlugin } = require("@rspack/core");
+
+const digests = {
+	xxhash3: "86ef8d87c2bb88654a43955d546d478e",
+	sha256: "1b6ad26cb42f96fee714b6fde5902eb74063ae5b372489e3942376dbe560bff8",
+	blake3: "8b54e9892af19dc858bab3fab9dba0a7dcfc1f97ea69e9aaebc7e2addc6e0aef"
+};
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = Object.entries(digests).map(([hashFunction, digest]) => ({
+	target: "node",
+	output: {
+		hashFunction,
+		filename: `${hashFunction}.[contenthash].js`
+	},
+	module: {
+		rules: [
+			{
+				test: /\.txt$/,
+				use: "./loader.js"
+			}
+		]
+	},
+	plugins: [
+		new DefinePlugin({
+			EXPECTED_DIGEST: JSON.stringify(digest)
+		}),
+		{
+			apply(compiler) {
+				compiler.hooks.compilation.tap("Test", compilation => {
+					// the js side hash of the chunk hash hook is created with `output.hashFunction`
+					compilation.hooks.chunkHash.tap("Test", (_chunk, hash) => {
+						hash.update(hashFunction);
+					});
+				});
+			}
+		}
+	]
+}));
@@ -0,0 +1,12 @@
+const fs = require("fs");
+
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: (i, options) => {
+		const prefix = `${options.output.hashFunction}.`;
+		return fs
+			.readdirSync(options.output.path)
+			.filter(file => file.startsWith(prefix) && file.endsWith(".js"))
+			.map(file => `./${file}`);
+	}
+};
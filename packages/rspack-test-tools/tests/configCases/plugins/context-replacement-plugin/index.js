@@ -0,0 +1,16 @@
+it("should narrow the context with a new regExp", function () {
+	function load(name) {
+		return require("./locales/" + name);
+	}
+	expect(load("en")).toBe("en");
+	expect(load("de")).toBe("de");
+	expect(() => load("fr")).toThrow();
+});
+
+it("should replace the directory of the context with a callback", function () {
+	function load(name) {
+		return require("./other/" + name);
+	}
+	expect(load("en")).toBe("replaced-en");
+	expect(load("de")).toBe("replaced-de");
+});
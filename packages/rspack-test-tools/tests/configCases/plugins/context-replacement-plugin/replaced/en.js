@@ -0,0 +1 @@
+module.exports = "replaced-en";
@@ -0,0 +1,12 @@
+const { ContextReplacementPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		new ContextReplacementPlugin(/locales$/, /^\.\/(en|de)(\.js)?$/),
+		new ContextReplacementPlugin(/other$/, result => {
+			if (result.resource) {
+				result.resource = "../replaced";
+			}
+		})
+	]
+});
@@ -0,0 +1,8 @@
+const { NormalModuleReplacementPlugin } = require("@rspack/core");
+
+module.exports = /** @type {import("@rspack/core").Configuration} */ ({
+	plugins: [
+		new NormalModuleReplacementPlugin(/request\.v1$/, "./request.v2"),
+		new NormalModuleReplacementPlugin(/resource\.foo\.js$/, "./resource.bar.js")
+	]
+});
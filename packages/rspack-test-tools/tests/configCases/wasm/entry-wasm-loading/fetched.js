@@ -0,0 +1,4 @@
+it("should emit wasm for the entry with the output wasm loading", async () => {
+	const { mul } = await import("./mul.wasm");
+	expect(mul(6, 7)).toBe(42);
+});
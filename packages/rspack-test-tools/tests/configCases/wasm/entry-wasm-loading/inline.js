@@ -0,0 +1,10 @@
+const fs = require("fs");
+
+it("should inline wasm for the entry with inline wasm loading", async () => {
+	const { add } = await import("./add.wasm");
+	expect(add(6, 7)).toBe(13);
+	const wasmFiles = fs
+		.readdirSync(__dirname)
+		.filter(file => file.endsWith(".wasm"));
+	expect(wasmFiles).toHaveLength(1);
+});
@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: {
+		inline: {
+			import: "./inline.js",
+			wasmLoading: "inline"
+		},
+		fetched: "./fetched.js"
+	},
+	output: {
+		filename: "[name].js",
+		wasmLoading: "async-node"
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
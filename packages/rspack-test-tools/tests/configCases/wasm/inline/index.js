@@ -0,0 +1,9 @@
+const fs = require("fs");
+
+it("should embed small wasm binaries in the bundle", async () => {
+	const { add } = await import("./add.wasm");
+	expect(add(6, 7)).toBe(13);
+	expect(fs.readdirSync(__dirname).some(file => file.endsWith(".wasm"))).toBe(
+		false
+	);
+});
@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		wasmLoading: "inline"
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
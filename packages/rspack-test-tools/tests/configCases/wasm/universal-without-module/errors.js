@@ -0,0 +1,3 @@
+module.exports = [
+	[/uses the "universal" wasm loading, which requires "output.module" to be enabled/]
+];
@@ -0,0 +1 @@
+import("./add.wasm");
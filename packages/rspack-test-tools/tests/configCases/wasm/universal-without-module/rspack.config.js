@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		wasmLoading: "universal"
+	},
+	experiments: {
+		asyncWebAssembly: true
+	}
+};
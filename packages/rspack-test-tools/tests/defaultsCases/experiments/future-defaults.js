@@ -85,12 +85,14 @@ module.exports = {
 		+         "exportsConvention": "as-is",
 		+         "exportsOnly": false,
 		+         "localIdentName": "[uniqueName]-[id]-[local]",
+		+         "removeUnusedRules": false,
 		+       },
 		+       "css/module": Object {
 		+         "esModule": true,
 		+         "exportsConvention": "as-is",
 		+         "exportsOnly": false,
 		+         "localIdentName": "[uniqueName]-[id]-[local]",
+		+         "removeUnusedRules": false,
 		+       },
 		+     },
 		@@ ... @@
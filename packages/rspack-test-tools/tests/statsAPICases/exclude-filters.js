@@ -0,0 +1,49 @@
+/** @type {import('../..').TStatsAPICaseConfig} */
+module.exports = {
+	description:
+		"should exclude modules and assets matching stats.excludeModules and stats.excludeAssets",
+	options(context) {
+		return {
+			context: context.getSource(),
+			entry: "./fixtures/abc",
+			devtool: "source-map",
+			stats: {
+				excludeModules: ["b.js", /c\.js$/],
+				excludeAssets: /\.map$/
+			}
+		};
+	},
+	async check(stats) {
+		const statsOptions = {
+			all: false,
+			modules: true,
+			assets: true
+		};
+		const { modules, assets, filteredModules, filteredAssets } =
+			stats?.toJson(statsOptions);
+		const moduleNames = modules.map(m => m.name);
+		expect(moduleNames).toContain("./fixtures/a.js");
+		expect(moduleNames).toContain("./fixtures/abc.js");
+		expect(moduleNames).not.toContain("./fixtures/b.js");
+		expect(moduleNames).not.toContain("./fixtures/c.js");
+		expect(assets.map(a => a.name)).toEqual(["main.js"]);
+		// the source map is listed as a related asset of `main.js` instead
+		expect(filteredAssets).toBe(0);
+		expect(filteredModules).toBe(2);
+		expect(stats?.toString(statsOptions)).toContain("2 modules");
+
+		// the filters passed to `toJson` take precedence over the ones of the config
+		const overridden = stats?.toJson({
+			...statsOptions,
+			excludeModules: ["a.js"],
+			excludeAssets: /main\.js$/
+		});
+		const overriddenNames = overridden.modules.map(m => m.name);
+		expect(overriddenNames).not.toContain("./fixtures/a.js");
+		expect(overriddenNames).toContain("./fixtures/b.js");
+		expect(overriddenNames).toContain("./fixtures/c.js");
+		expect(overridden.filteredModules).toBe(1);
+		expect(overridden.assets).toEqual([]);
+		expect(overridden.filteredAssets).toBe(1);
+	}
+};
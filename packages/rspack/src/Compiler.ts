@@ -55,6 +55,7 @@ import type { CompilationParams } from "./Compilation";
 import type { FileSystemInfoEntry } from "./FileSystemInfo";
 import type {
 	ContextModuleFactoryAfterResolveResult,
+	ContextModuleFactoryBeforeResolveResult,
 	ResolveData
 } from "./Module";
 import type { NormalModuleCreateData } from "./NormalModuleFactory";
@@ -1141,7 +1142,34 @@ class Compiler {
 								| false
 								| binding.JsContextModuleFactoryBeforeResolveData
 						) => {
-							return queried.promise(bindingData);
+							const data = bindingData
+								? ({
+										context: bindingData.context,
+										request: bindingData.request,
+										recursive: bindingData.recursive,
+										regExp: bindingData.regExp
+											? new RegExp(
+													bindingData.regExp.source,
+													bindingData.regExp.flags
+												)
+											: undefined
+									} satisfies ContextModuleFactoryBeforeResolveResult)
+								: false;
+							const ret = await queried.promise(data);
+							const result = ret
+								? ({
+										context: ret.context,
+										request: ret.request,
+										recursive: ret.recursive,
+										regExp: ret.regExp
+											? {
+													source: ret.regExp.source,
+													flags: ret.regExp.flags
+												}
+											: undefined
+									} satisfies binding.JsContextModuleFactoryBeforeResolveData)
+								: false;
+							return result;
 						}
 				),
 			registerContextModuleFactoryAfterResolveTaps:
@@ -1166,6 +1194,7 @@ class Compiler {
 											: undefined,
 										request: bindingData.request,
 										context: bindingData.context,
+										recursive: bindingData.recursive,
 										// TODO: Dependencies are not fully supported yet; this is a placeholder to prevent errors in moment-locales-webpack-plugin.
 										dependencies: []
 									} satisfies ContextModuleFactoryAfterResolveResult)
@@ -1176,6 +1205,7 @@ class Compiler {
 										resource: ret.resource,
 										context: ret.context,
 										request: ret.request,
+										recursive: ret.recursive,
 										regExp: ret.regExp
 											? {
 													source: ret.regExp.source,
@@ -39,6 +39,8 @@ export type ContextModuleFactoryBeforeResolveResult =
 	| {
 			context: string;
 			request?: string;
+			recursive: boolean;
+			regExp?: RegExp;
 	  };
 
 export type ContextModuleFactoryAfterResolveResult =
@@ -47,6 +49,7 @@ export type ContextModuleFactoryAfterResolveResult =
 			resource: string;
 			context: string;
 			request: string;
+			recursive: boolean;
 			regExp?: RegExp;
 			dependencies: Array<any>;
 	  };
@@ -9,7 +9,7 @@
  */
 import type * as binding from "@rspack/binding";
 
-import type { Compilation } from "./Compilation";
+import type { Compilation, NormalizedStatsOptions } from "./Compilation";
 import type { StatsOptions, StatsValue } from "./config";
 import type { StatsCompilation } from "./stats/statsFactoryUtils";
 
@@ -89,7 +89,9 @@ export class Stats {
 						return statsCompilationMap.get(compilation)!;
 					}
 					const innerStats = this.#getInnerByCompilation(compilation);
-					const innerStatsCompilation = innerStats.toJson(options);
+					const innerStatsCompilation = innerStats.toJson(
+						getRawStatsOptions(options, opts)
+					);
 					statsCompilationMap.set(compilation, innerStatsCompilation);
 					return innerStatsCompilation;
 				},
@@ -132,7 +134,9 @@ export class Stats {
 						return statsCompilationMap.get(compilation)!;
 					}
 					const innerStats = this.#getInnerByCompilation(compilation);
-					const innerStatsCompilation = innerStats.toJson(options);
+					const innerStatsCompilation = innerStats.toJson(
+						getRawStatsOptions(options, opts)
+					);
 					statsCompilationMap.set(compilation, innerStatsCompilation);
 					return innerStatsCompilation;
 				},
@@ -155,6 +159,40 @@ export class Stats {
 	}
 }
 
+// Only strings and regexps are applied natively, the others are applied by the stats factory
+export function getRawStatsFilters(
+	filters: StatsOptions["excludeModules"] | StatsOptions["excludeAssets"]
+): (string | RegExp)[] {
+	const array: unknown[] = Array.isArray(filters)
+		? filters
+		: filters
+			? [filters]
+			: [];
+	return array.filter(
+		(filter): filter is string | RegExp =>
+			typeof filter === "string" || filter instanceof RegExp
+	);
+}
+
+// The exclude filters of the call take precedence over the ones of the `stats` config
+function getRawStatsOptions(
+	options: NormalizedStatsOptions,
+	opts: StatsValue | undefined
+): binding.JsStatsOptions {
+	const callOptions = typeof opts === "object" && opts !== null ? opts : {};
+	return {
+		...options,
+		excludeModules:
+			callOptions.excludeModules === undefined
+				? undefined
+				: getRawStatsFilters(callOptions.excludeModules),
+		excludeAssets:
+			callOptions.excludeAssets === undefined
+				? undefined
+				: getRawStatsFilters(callOptions.excludeAssets)
+	};
+}
+
 export function normalizeStatsPreset(options?: StatsValue): StatsOptions {
 	if (typeof options === "boolean" || typeof options === "string")
 		return presetToOptions(options);
@@ -0,0 +1,13 @@
+import {
+	BuiltinPluginName,
+	type RawContextReplacementPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export const BuiltinContextReplacementPlugin = create(
+	BuiltinPluginName.ContextReplacementPlugin,
+	(
+		options: RawContextReplacementPluginOptions
+	): RawContextReplacementPluginOptions => options
+);
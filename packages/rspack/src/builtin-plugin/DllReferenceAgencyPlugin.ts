@@ -0,0 +1,15 @@
+import {
+	BuiltinPluginName,
+	type RawDllReferencePluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export type DllReferenceAgencyPluginOptions = RawDllReferencePluginOptions;
+
+export const DllReferenceAgencyPlugin = create(
+	BuiltinPluginName.DllReferenceAgencyPlugin,
+	(options: DllReferenceAgencyPluginOptions): RawDllReferencePluginOptions =>
+		options,
+	"compilation"
+);
@@ -11,6 +11,7 @@ import {
 	type Layer,
 	type LibraryOptions,
 	type PublicPath,
+	type WasmLoading,
 	getRawChunkLoading,
 	getRawLibrary
 } from "../config";
@@ -21,6 +22,7 @@ export type EntryOptions = {
 	name?: string;
 	runtime?: EntryRuntime;
 	chunkLoading?: ChunkLoading;
+	wasmLoading?: WasmLoading;
 	asyncChunks?: boolean;
 	publicPath?: PublicPath;
 	baseUri?: string;
@@ -50,6 +52,7 @@ export const EntryPlugin = create(
 export function getRawEntryOptions(entry: EntryOptions): JsEntryOptions {
 	const runtime = entry.runtime;
 	const chunkLoading = entry.chunkLoading;
+	const wasmLoading = entry.wasmLoading;
 	return {
 		name: entry.name,
 		publicPath: entry.publicPath,
@@ -58,6 +61,11 @@ export function getRawEntryOptions(entry: EntryOptions): JsEntryOptions {
 		chunkLoading: !isNil(chunkLoading)
 			? getRawChunkLoading(chunkLoading)
 			: undefined,
+		wasmLoading: !isNil(wasmLoading)
+			? wasmLoading === false
+				? "false"
+				: wasmLoading
+			: undefined,
 		asyncChunks: entry.asyncChunks,
 		filename: entry.filename,
 		library: entry.library && getRawLibrary(entry.library),
@@ -39,6 +39,16 @@ const templateParamFunction = z
 		z.record(z.string(), z.any()).or(z.promise(z.record(z.string(), z.any())))
 	);
 
+const tagAttributesFunction = z
+	.function()
+	.args(z.custom<JsHtmlPluginTag>())
+	.returns(
+		z
+			.record(z.string(), z.string().or(z.boolean()))
+			.or(z.promise(z.record(z.string(), z.string().or(z.boolean()))))
+			.or(z.void())
+	);
+
 const htmlRspackPluginOptions = z.strictObject({
 	filename: z.string().optional(),
 	template: z
@@ -57,7 +67,10 @@ const htmlRspackPluginOptions = z.strictObject({
 		.or(z.boolean())
 		.or(templateParamFunction)
 		.optional(),
-	inject: z.enum(["head", "body"]).or(z.boolean()).optional(),
+	inject: z
+		.enum(["head", "body", "head-prepend", "body-prepend"])
+		.or(z.boolean())
+		.optional(),
 	publicPath: z.string().optional(),
 	base: z
 		.string()
@@ -78,7 +91,11 @@ const htmlRspackPluginOptions = z.strictObject({
 	title: z.string().optional(),
 	favicon: z.string().optional(),
 	meta: z.record(z.string().or(z.record(z.string()))).optional(),
-	hash: z.boolean().optional()
+	hash: z.boolean().optional(),
+	attributes: z
+		.record(z.string(), z.string().or(z.boolean()))
+		.or(tagAttributesFunction)
+		.optional()
 });
 export type HtmlRspackPluginOptions = z.infer<typeof htmlRspackPluginOptions>;
 
@@ -214,6 +231,18 @@ const HtmlRspackPluginImpl = create(
 			templateParameters = rawTemplateParameters;
 		}
 
+		const rawAttributes = c.attributes;
+		let attributes;
+		if (typeof rawAttributes === "function") {
+			attributes = async (tag: JsHtmlPluginTag) =>
+				(await rawAttributes(tag)) ?? {};
+		} else if (rawAttributes) {
+			// the attributes of the object form are set on the injected script and
+			// link tags only
+			attributes = async (tag: JsHtmlPluginTag) =>
+				tag.tagName === "script" || tag.tagName === "link" ? rawAttributes : {};
+		}
+
 		return {
 			filename: c.filename,
 			template: c.template,
@@ -231,7 +260,8 @@ const HtmlRspackPluginImpl = create(
 			base,
 			templateFn,
 			templateContent,
-			templateParameters
+			templateParameters,
+			attributes
 		};
 	}
 );
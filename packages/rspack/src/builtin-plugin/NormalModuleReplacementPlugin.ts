@@ -0,0 +1,17 @@
+import {
+	BuiltinPluginName,
+	type RawNormalModuleReplacementPluginOptions
+} from "@rspack/binding";
+
+import { create } from "./base";
+
+export const BuiltinNormalModuleReplacementPlugin = create(
+	BuiltinPluginName.NormalModuleReplacementPlugin,
+	(
+		resourceRegExp: RegExp,
+		newResource: string
+	): RawNormalModuleReplacementPluginOptions => ({
+		resourceRegExp,
+		newResource
+	})
+);
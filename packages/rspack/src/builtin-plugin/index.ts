@@ -8,6 +8,7 @@ export * from "./BannerPlugin";
 export * from "./BundlerInfoRspackPlugin";
 export * from "./ChunkPrefetchPreloadPlugin";
 export * from "./CommonJsChunkFormatPlugin";
+export * from "./ContextReplacementPlugin";
 export * from "./CopyRspackPlugin";
 export * from "./css-extract";
 export * from "./CssModulesPlugin";
@@ -15,6 +16,8 @@ export * from "./DataUriPlugin";
 export * from "./DefinePlugin";
 export * from "./DeterministicChunkIdsPlugin";
 export * from "./DeterministicModuleIdsPlugin";
+export * from "./DllEntryPlugin";
+export * from "./DllReferenceAgencyPlugin";
 export * from "./DynamicEntryPlugin";
 export * from "./ElectronTargetPlugin";
 export * from "./EnableChunkLoadingPlugin";
@@ -26,6 +29,7 @@ export * from "./EvalDevToolModulePlugin";
 export * from "./EvalSourceMapDevToolPlugin";
 export * from "./ExternalsPlugin";
 export * from "./FileUriPlugin";
+export * from "./FlagAllModulesAsUsedPlugin";
 export * from "./FlagDependencyExportsPlugin";
 export * from "./FlagDependencyUsagePlugin";
 export * from "./HotModuleReplacementPlugin";
@@ -37,6 +41,7 @@ export * from "./JavascriptModulesPlugin";
 export * from "./JsLoaderRspackPlugin";
 export * from "./JsonModulesPlugin";
 export * from "./lazy-compilation/plugin";
+export * from "./LibManifestPlugin";
 export * from "./LimitChunkCountPlugin";
 export * from "./MangleExportsPlugin";
 export * from "./MergeDuplicateChunksPlugin";
@@ -47,6 +52,7 @@ export * from "./NamedModuleIdsPlugin";
 export * from "./NaturalChunkIdsPlugin";
 export * from "./NaturalModuleIdsPlugin";
 export * from "./NodeTargetPlugin";
+export * from "./NormalModuleReplacementPlugin";
 export * from "./ProgressPlugin";
 export * from "./ProvidePlugin";
 export * from "./RealContentHashPlugin";
@@ -1,6 +1,7 @@
 import {
 	BuiltinPluginName,
 	type JsModule,
+	type RawLazyCompilationServerBackendOptions,
 	type RawRegex
 } from "@rspack/binding";
 
@@ -9,15 +10,18 @@ import { create } from "../base";
 export const BuiltinLazyCompilationPlugin = create(
 	BuiltinPluginName.LazyCompilationPlugin,
 	(
-		module: (args: { module: string; path: string }) => {
-			active: boolean;
-			data: string;
-			client: string;
-		},
+		module:
+			| ((args: { module: string; path: string }) => {
+					active: boolean;
+					data: string;
+					client: string;
+			  })
+			| undefined,
 		cacheable: boolean,
 		entries: boolean,
 		imports: boolean,
-		test?: RawRegex | ((m: JsModule) => boolean)
-	) => ({ module, cacheable, imports, entries, test }),
+		test?: RawRegex | ((m: JsModule) => boolean),
+		serverBackend?: RawLazyCompilationServerBackendOptions
+	) => ({ module, serverBackend, cacheable, imports, entries, test }),
 	"thisCompilation"
 );
@@ -1,4 +1,8 @@
-import type { JsModule, RawRegex } from "@rspack/binding";
+import type {
+	JsModule,
+	RawLazyCompilationServerBackendOptions,
+	RawRegex
+} from "@rspack/binding";
 
 import type { Compiler } from "../..";
 import getBackend, {
@@ -30,13 +34,31 @@ export default class LazyCompilationPlugin {
 	}
 
 	apply(compiler: Compiler) {
+		const client = require.resolve(
+			`../../../hot/lazy-compilation-${
+				compiler.options.externalsPresets.node ? "node" : "web"
+			}.js`
+		);
+		const serverBackend = getServerBackendOptions(
+			{ ...this.backend, client },
+			compiler
+		);
+		if (serverBackend) {
+			// the native backend starts its server with the first proxy module
+			new BuiltinLazyCompilationPlugin(
+				undefined,
+				this.cacheable,
+				this.entries,
+				this.imports,
+				this.test,
+				serverBackend
+			).apply(compiler);
+			return;
+		}
+
 		const backend = getBackend({
 			...this.backend,
-			client: require.resolve(
-				`../../../hot/lazy-compilation-${
-					compiler.options.externalsPresets.node ? "node" : "web"
-				}.js`
-			)
+			client
 		});
 
 		new BuiltinLazyCompilationPlugin(
@@ -65,4 +87,38 @@ export default class LazyCompilationPlugin {
 	}
 }
 
+// The native backend serves plain http on a host and port, the JS one is used for the others
+function getServerBackendOptions(
+	options: LazyCompilationDefaultBackendOptions & { client: string },
+	compiler: Compiler
+): RawLazyCompilationServerBackendOptions | undefined {
+	const { client, listen, protocol, server } = options;
+	if (protocol === "https" || server !== undefined) {
+		return undefined;
+	}
+	let host: string | undefined;
+	let port: number | undefined;
+	if (typeof listen === "number") {
+		port = listen;
+	} else if (typeof listen === "object") {
+		const { host: listenHost, port: listenPort, ...rest } = listen;
+		if (Object.keys(rest).length > 0) {
+			return undefined;
+		}
+		host = listenHost;
+		port = listenPort;
+	} else if (listen !== undefined) {
+		return undefined;
+	}
+	return {
+		client,
+		host,
+		port,
+		invalidate: (err, files) => {
+			if (err) throw err;
+			compiler.watching?.lazyCompilationInvalidate(new Set(files));
+		}
+	};
+}
+
 export { LazyCompilationPlugin };
@@ -27,7 +27,7 @@ import {
 } from "@rspack/binding";
 
 import type { Compiler } from "../Compiler";
-import { normalizeStatsPreset } from "../Stats";
+import { getRawStatsFilters, normalizeStatsPreset } from "../Stats";
 import { isNil } from "../util";
 import { parseResource } from "../util/identifier";
 import {
@@ -117,6 +117,7 @@ export const getRawOptions = (
 		},
 		experiments,
 		node: getRawNode(options.node),
+		amd: options.amd !== false ? JSON.stringify(options.amd || {}) : undefined,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
 		profile: options.profile!,
 		// SAFETY: applied default value in `applyRspackOptionsDefaults`.
@@ -850,7 +851,8 @@ function getRawCssAutoOrModuleGeneratorOptions(
 		localIdentName: options.localIdentName,
 		exportsConvention: options.exportsConvention,
 		exportsOnly: options.exportsOnly,
-		esModule: options.esModule
+		esModule: options.esModule,
+		removeUnusedRules: options.removeUnusedRules
 	};
 }
 
@@ -878,9 +880,17 @@ function getRawOptimization(
 }
 
 function getRawSnapshotOptions(
-	_snapshot: SnapshotOptions
+	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	return {};
+	const { resolve, module, buildDependencies, managedPaths, immutablePaths } =
+		snapshot;
+	return {
+		resolve,
+		module,
+		buildDependencies,
+		managedPaths,
+		immutablePaths
+	};
 }
 
 function getRawExperiments(
@@ -921,7 +931,9 @@ function getRawNode(node: Node): RawOptions["node"] {
 function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
-		colors: statsOptions.colors ?? false
+		colors: statsOptions.colors ?? false,
+		excludeModules: getRawStatsFilters(statsOptions.excludeModules),
+		excludeAssets: getRawStatsFilters(statsOptions.excludeAssets)
 	};
 }
 
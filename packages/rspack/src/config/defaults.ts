@@ -361,6 +361,7 @@ const applyModuleDefaults = (
 			"[uniqueName]-[id]-[local]"
 		);
 		D(module.generator["css/auto"], "esModule", true);
+		D(module.generator["css/auto"], "removeUnusedRules", false);
 
 		F(module.generator, "css/module", () => ({}));
 		assertNotNill(module.generator["css/module"]);
@@ -376,6 +377,7 @@ const applyModuleDefaults = (
 			"[uniqueName]-[id]-[local]"
 		);
 		D(module.generator["css/module"], "esModule", true);
+		D(module.generator["css/module"], "removeUnusedRules", false);
 	}
 
 	// IGNORE(module.defaultRules): Rspack does not support `rule.assert`
@@ -788,11 +790,11 @@ const applyOutputDefaults = (
 		if (output.workerWasmLoading) {
 			enabledWasmLoadingTypes.add(output.workerWasmLoading);
 		}
-		// forEachEntry(desc => {
-		// 	if (desc.wasmLoading) {
-		// 		enabledWasmLoadingTypes.add(desc.wasmLoading);
-		// 	}
-		// });
+		forEachEntry(desc => {
+			if (desc.wasmLoading) {
+				enabledWasmLoadingTypes.add(desc.wasmLoading);
+			}
+		});
 		return Array.from(enabledWasmLoadingTypes);
 	});
 
@@ -1075,7 +1077,7 @@ const getResolveDefaults = ({
 				preferRelative: true
 			},
 			commonjs: cjsDeps(),
-			// amd: cjsDeps(),
+			amd: cjsDeps(),
 			// for backward-compat: loadModule
 			// loader: cjsDeps(),
 			// for backward-compat: Custom Dependency and getResolve without dependencyType
@@ -10,6 +10,7 @@
 
 import type { Compilation } from "../Compilation";
 import type {
+	Amd,
 	AssetModuleFilename,
 	AsyncChunks,
 	Bail,
@@ -261,7 +262,7 @@ export const getNormalizedRspackOptions = (
 				}
 		),
 		loader: cloneObject(config.loader),
-		snapshot: nestedConfig(config.snapshot, _snapshot => ({})),
+		snapshot: nestedConfig(config.snapshot, snapshot => ({ ...snapshot })),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
 			if (stats === false) {
@@ -314,6 +315,7 @@ export const getNormalizedRspackOptions = (
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
+		amd: config.amd,
 		profile: config.profile,
 		bail: config.bail
 	};
@@ -352,6 +354,7 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 				publicPath: value.publicPath,
 				baseUri: value.baseUri,
 				chunkLoading: value.chunkLoading,
+				wasmLoading: value.wasmLoading,
 				asyncChunks: value.asyncChunks,
 				filename: value.filename,
 				library: value.library,
@@ -446,6 +449,7 @@ export interface EntryDescriptionNormalized {
 	import?: string[];
 	runtime?: EntryRuntime;
 	chunkLoading?: ChunkLoading;
+	wasmLoading?: WasmLoading;
 	asyncChunks?: AsyncChunks;
 	publicPath?: PublicPath;
 	baseUri?: BaseUri;
@@ -564,6 +568,7 @@ export interface RspackOptionsNormalized {
 	devServer?: DevServer;
 	ignoreWarnings?: IgnoreWarningsNormalized;
 	performance?: Performance;
+	amd?: Amd;
 	profile?: Profile;
 	bail?: Bail;
 }
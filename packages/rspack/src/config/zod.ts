@@ -75,7 +75,7 @@ const asyncChunks = z.boolean();
 export type AsyncChunks = z.infer<typeof asyncChunks>;
 
 const wasmLoadingType = z
-	.enum(["fetch-streaming", "fetch", "async-node"])
+	.enum(["fetch-streaming", "fetch", "async-node", "universal", "inline"])
 	.or(z.string());
 export type WasmLoadingType = z.infer<typeof wasmLoadingType>;
 
@@ -303,7 +303,7 @@ export type HashDigest = z.infer<typeof hashDigest>;
 const hashDigestLength = z.number();
 export type HashDigestLength = z.infer<typeof hashDigestLength>;
 
-const hashFunction = z.enum(["md4", "xxhash64"]);
+const hashFunction = z.enum(["md4", "xxhash64", "xxhash3", "sha256", "blake3"]);
 export type HashFunction = z.infer<typeof hashFunction>;
 
 const hashSalt = z.string();
@@ -716,6 +716,11 @@ export type CssGeneratorLocalIdentName = z.infer<
 const cssGeneratorEsModule = z.boolean();
 export type CssGeneratorEsModule = z.infer<typeof cssGeneratorEsModule>;
 
+const cssGeneratorRemoveUnusedRules = z.boolean();
+export type CssGeneratorRemoveUnusedRules = z.infer<
+	typeof cssGeneratorRemoveUnusedRules
+>;
+
 const cssGeneratorOptions = z.strictObject({
 	exportsOnly: cssGeneratorExportsOnly.optional(),
 	esModule: cssGeneratorEsModule.optional()
@@ -726,7 +731,8 @@ const cssAutoGeneratorOptions = z.strictObject({
 	exportsConvention: cssGeneratorExportsConvention.optional(),
 	exportsOnly: cssGeneratorExportsOnly.optional(),
 	localIdentName: cssGeneratorLocalIdentName.optional(),
-	esModule: cssGeneratorEsModule.optional()
+	esModule: cssGeneratorEsModule.optional(),
+	removeUnusedRules: cssGeneratorRemoveUnusedRules.optional()
 });
 export type CssAutoGeneratorOptions = z.infer<typeof cssAutoGeneratorOptions>;
 
@@ -734,7 +740,8 @@ const cssModuleGeneratorOptions = z.strictObject({
 	exportsConvention: cssGeneratorExportsConvention.optional(),
 	exportsOnly: cssGeneratorExportsOnly.optional(),
 	localIdentName: cssGeneratorLocalIdentName.optional(),
-	esModule: cssGeneratorEsModule.optional()
+	esModule: cssGeneratorEsModule.optional(),
+	removeUnusedRules: cssGeneratorRemoveUnusedRules.optional()
 });
 export type CssModuleGeneratorOptions = z.infer<
 	typeof cssModuleGeneratorOptions
@@ -1034,7 +1041,22 @@ export type Loader = z.infer<typeof loader>;
 //#endregion
 
 //#region Snapshot
-const snapshotOptions = z.strictObject({});
+const snapshotStrategy = z.strictObject({
+	hash: z.boolean().optional(),
+	timestamp: z.boolean().optional()
+});
+export type SnapshotStrategy = z.infer<typeof snapshotStrategy>;
+
+const snapshotPaths = z.array(z.string().or(z.instanceof(RegExp)));
+export type SnapshotPaths = z.infer<typeof snapshotPaths>;
+
+const snapshotOptions = z.strictObject({
+	resolve: snapshotStrategy.optional(),
+	module: snapshotStrategy.optional(),
+	buildDependencies: snapshotStrategy.optional(),
+	managedPaths: snapshotPaths.optional(),
+	immutablePaths: snapshotPaths.optional()
+});
 export type SnapshotOptions = z.infer<typeof snapshotOptions>;
 //#endregion
 
@@ -1422,6 +1444,11 @@ const profile = z.boolean();
 export type Profile = z.infer<typeof profile>;
 //#endregion
 
+//#region Amd
+const amd = z.literal(false).or(z.record(z.any()));
+export type Amd = z.infer<typeof amd>;
+//#endregion
+
 //#region Bail
 const bail = z.boolean();
 export type Bail = z.infer<typeof bail>;
@@ -1455,6 +1482,7 @@ export const rspackOptions = z.strictObject({
 	context: context.optional(),
 	devtool: devTool.optional(),
 	node: node.optional(),
+	amd: amd.optional(),
 	loader: loader.optional(),
 	ignoreWarnings: ignoreWarnings.optional(),
 	watchOptions: watchOptions.optional(),
@@ -106,6 +106,15 @@ export { EnvironmentPlugin } from "./lib/EnvironmentPlugin";
 export { LoaderOptionsPlugin } from "./lib/LoaderOptionsPlugin";
 export { LoaderTargetPlugin } from "./lib/LoaderTargetPlugin";
 export { NormalModuleReplacementPlugin } from "./lib/NormalModuleReplacementPlugin";
+export { ContextReplacementPlugin } from "./lib/ContextReplacementPlugin";
+export { DllPlugin, type DllPluginOptions } from "./lib/DllPlugin";
+export {
+	DllReferencePlugin,
+	type DllReferencePluginOptions,
+	type DllReferencePluginOptionsSourceType,
+	type DllReferencePluginOptionsContent,
+	type DllReferencePluginOptionsManifest
+} from "./lib/DllReferencePlugin";
 
 import { FetchCompileAsyncWasmPlugin } from "./builtin-plugin";
 interface Web {
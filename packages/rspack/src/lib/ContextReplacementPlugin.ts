@@ -0,0 +1,126 @@
+/**
+ * The following code is modified based on
+ * https://github.com/webpack/webpack/blob/4b4ca3b/lib/ContextReplacementPlugin.js
+ *
+ * MIT Licensed
+ * Author Tobias Koppers @sokra
+ * Copyright (c) JS Foundation and other contributors
+ * https://github.com/webpack/webpack/blob/main/LICENSE
+ */
+
+import * as NodePath from "node:path";
+
+import type { Compiler } from "../Compiler";
+import type {
+	ContextModuleFactoryAfterResolveResult,
+	ContextModuleFactoryBeforeResolveResult
+} from "../Module";
+import { BuiltinContextReplacementPlugin } from "../builtin-plugin";
+
+type ContextReplacer = (
+	result:
+		| Exclude<ContextModuleFactoryBeforeResolveResult, false>
+		| Exclude<ContextModuleFactoryAfterResolveResult, false>
+) => void;
+
+const isAbsolute = (path: string) =>
+	NodePath.posix.isAbsolute(path) || NodePath.win32.isAbsolute(path);
+
+export class ContextReplacementPlugin {
+	resourceRegExp: RegExp;
+	newContentCallback?: ContextReplacer;
+	newContentResource?: string;
+	newContentRecursive?: boolean;
+	newContentRegExp?: RegExp;
+	newContentCreateContextMap?: Record<string, string>;
+
+	/**
+	 * Create an instance of the plugin
+	 * @param resourceRegExp the context matcher
+	 * @param newContentResource the new directory, or a callback receiving the resolve data
+	 * @param newContentRecursive whether the new context is recursive, or a map from the
+	 * requests of the new context to the requests they are resolved to
+	 * @param newContentRegExp the regexp of the new context
+	 */
+	constructor(
+		resourceRegExp: RegExp,
+		newContentResource?: string | boolean | RegExp | ContextReplacer,
+		newContentRecursive?: boolean | RegExp | Record<string, string>,
+		newContentRegExp?: RegExp
+	) {
+		this.resourceRegExp = resourceRegExp;
+
+		if (typeof newContentResource === "function") {
+			this.newContentCallback = newContentResource;
+		} else if (
+			typeof newContentResource === "string" &&
+			typeof newContentRecursive === "object" &&
+			!(newContentRecursive instanceof RegExp)
+		) {
+			this.newContentResource = newContentResource;
+			this.newContentCreateContextMap = newContentRecursive;
+		} else {
+			let resource: string | undefined;
+			let recursive: boolean | RegExp | undefined;
+			let regExp: RegExp | undefined = newContentRegExp;
+			if (typeof newContentResource !== "string") {
+				regExp = newContentRecursive as RegExp | undefined;
+				recursive = newContentResource;
+			} else {
+				resource = newContentResource;
+				recursive = newContentRecursive as boolean | RegExp | undefined;
+			}
+			if (typeof recursive !== "boolean") {
+				regExp = recursive;
+				recursive = undefined;
+			}
+			this.newContentResource = resource;
+			this.newContentRecursive = recursive;
+			this.newContentRegExp = regExp;
+		}
+	}
+
+	/**
+	 * Apply the plugin
+	 * @param compiler the compiler instance
+	 * @returns
+	 */
+	apply(compiler: Compiler) {
+		const { resourceRegExp, newContentCallback } = this;
+
+		if (typeof newContentCallback !== "function") {
+			new BuiltinContextReplacementPlugin({
+				resourceRegExp,
+				newContentResource: this.newContentResource,
+				newContentRecursive: this.newContentRecursive,
+				newContentRegExp: this.newContentRegExp,
+				newContentCreateContextMap: this.newContentCreateContextMap
+			}).apply(compiler);
+			return;
+		}
+
+		compiler.hooks.contextModuleFactory.tap("ContextReplacementPlugin", cmf => {
+			cmf.hooks.beforeResolve.tap("ContextReplacementPlugin", result => {
+				if (!result) return result;
+				if (resourceRegExp.test(result.request ?? "")) {
+					newContentCallback(result);
+				}
+				return result;
+			});
+			cmf.hooks.afterResolve.tap("ContextReplacementPlugin", result => {
+				if (!result) return result;
+				if (resourceRegExp.test(result.resource)) {
+					const origResource = result.resource;
+					newContentCallback(result);
+					if (
+						result.resource !== origResource &&
+						!isAbsolute(result.resource)
+					) {
+						result.resource = NodePath.join(origResource, result.resource);
+					}
+				}
+				return result;
+			});
+		});
+	}
+}
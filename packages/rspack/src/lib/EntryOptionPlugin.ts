@@ -79,7 +79,7 @@ export class EntryOptionPlugin {
 			publicPath: desc.publicPath,
 			chunkLoading: desc.chunkLoading,
 			asyncChunks: desc.asyncChunks,
-			// wasmLoading: desc.wasmLoading,
+			wasmLoading: desc.wasmLoading,
 			library: desc.library
 		};
 		if (desc.layer !== undefined && !compiler.options.experiments.layers) {
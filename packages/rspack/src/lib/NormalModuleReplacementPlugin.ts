@@ -8,10 +8,9 @@
  * https://github.com/webpack/webpack/blob/main/LICENSE
  */
 
-import * as NodePath from "node:path";
-
 import type { Compiler } from "../Compiler";
 import type { ResolveData } from "../Module";
+import { BuiltinNormalModuleReplacementPlugin } from "../builtin-plugin";
 
 type ModuleReplacer = (createData: ResolveData) => void;
 
@@ -34,36 +33,26 @@ export class NormalModuleReplacementPlugin {
 	apply(compiler: Compiler) {
 		const { resourceRegExp, newResource } = this;
 
+		if (typeof newResource === "string") {
+			new BuiltinNormalModuleReplacementPlugin(
+				resourceRegExp,
+				newResource
+			).apply(compiler);
+			return;
+		}
+
 		compiler.hooks.normalModuleFactory.tap(
 			"NormalModuleReplacementPlugin",
 			nmf => {
 				nmf.hooks.beforeResolve.tap("NormalModuleReplacementPlugin", result => {
 					if (resourceRegExp.test(result.request)) {
-						if (typeof newResource === "function") {
-							newResource(result);
-						} else {
-							result.request = newResource;
-						}
+						newResource(result);
 					}
 				});
 				nmf.hooks.afterResolve.tap("NormalModuleReplacementPlugin", result => {
 					const createData = result.createData || {};
 					if (resourceRegExp.test(createData.resource || "")) {
-						if (typeof newResource === "function") {
-							newResource(result);
-						} else {
-							if (
-								NodePath.posix.isAbsolute(newResource) ||
-								NodePath.win32.isAbsolute(newResource)
-							) {
-								createData.resource = newResource;
-							} else {
-								createData.resource = NodePath.join(
-									NodePath.dirname(createData.resource || ""),
-									newResource
-								);
-							}
-						}
+						newResource(result);
 					}
 				});
 			}
@@ -902,7 +902,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 				options.assetsSpace ?? Number.POSITIVE_INFINITY
 			);
 			object.assets = limited.children;
-			object.filteredAssets = limited.filteredChildren;
+			// the assets excluded natively are not part of the grouped assets
+			object.filteredAssets =
+				limited.filteredChildren + (statsCompilation.filteredAssets ?? 0);
 		},
 		chunks: (
 			object,
@@ -929,7 +931,9 @@ const SIMPLE_EXTRACTORS: SimpleExtractors = {
 			const groupedModules = factory.create(`${type}.modules`, array, context);
 			const limited = spaceLimited(groupedModules, options.modulesSpace!);
 			object.modules = limited.children;
-			object.filteredModules = limited.filteredChildren;
+			// the modules excluded natively are not part of the grouped modules
+			object.filteredModules =
+				limited.filteredChildren + (statsCompilation.filteredModules ?? 0);
 		},
 		entrypoints: (
 			object,
@@ -11,7 +11,9 @@
 import crypto from "node:crypto";
 import Hash from "./hash";
 import BatchedHash from "./hash/BatchedHash";
+import createBlake3 from "./hash/blake3";
 import createMd4 from "./hash/md4";
+import createXXHash3 from "./hash/xxhash3";
 import createXXHash64 from "./hash/xxhash64";
 
 const BULK_SIZE = 2000;
@@ -157,6 +159,8 @@ export const createHash = (
 	algorithm:
 		| "debug"
 		| "xxhash64"
+		| "xxhash3"
+		| "blake3"
 		| "md4"
 		| "native-md4"
 		| (string & {})
@@ -171,6 +175,11 @@ export const createHash = (
 			return new DebugHash();
 		case "xxhash64":
 			return new BatchedHash(createXXHash64());
+		// node:crypto doesn't provide xxhash3 and blake3
+		case "xxhash3":
+			return new BulkUpdateDecorator(createXXHash3, "xxhash3");
+		case "blake3":
+			return new BulkUpdateDecorator(createBlake3, "blake3");
 		case "md4":
 			return new BatchedHash(createMd4());
 		case "native-md4":
@@ -0,0 +1,292 @@
+/**
+ * The following code is modified based on
+ * https://github.com/BLAKE3-team/BLAKE3/blob/1.5.0/reference_impl/reference_impl.rs
+ *
+ * CC0-1.0 OR Apache-2.0 Licensed
+ * Copyright (c) Jack O'Connor and Samuel Neves
+ * https://github.com/BLAKE3-team/BLAKE3/blob/master/LICENSE
+ */
+import Hash from ".";
+
+const OUT_LEN = 32;
+const BLOCK_LEN = 64;
+const CHUNK_LEN = 1024;
+
+const CHUNK_START = 1 << 0;
+const CHUNK_END = 1 << 1;
+const PARENT = 1 << 2;
+const ROOT = 1 << 3;
+
+const IV = new Uint32Array([
+	0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c,
+	0x1f83d9ab, 0x5be0cd19
+]);
+
+const MSG_PERMUTATION = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const rotr = (x: number, n: number) => (x >>> n) | (x << (32 - n));
+
+const g = (
+	state: Uint32Array,
+	a: number,
+	b: number,
+	c: number,
+	d: number,
+	mx: number,
+	my: number
+) => {
+	state[a] = state[a] + state[b] + mx;
+	state[d] = rotr(state[d] ^ state[a], 16);
+	state[c] = state[c] + state[d];
+	state[b] = rotr(state[b] ^ state[c], 12);
+	state[a] = state[a] + state[b] + my;
+	state[d] = rotr(state[d] ^ state[a], 8);
+	state[c] = state[c] + state[d];
+	state[b] = rotr(state[b] ^ state[c], 7);
+};
+
+const round = (state: Uint32Array, m: Uint32Array) => {
+	// mix the columns
+	g(state, 0, 4, 8, 12, m[0], m[1]);
+	g(state, 1, 5, 9, 13, m[2], m[3]);
+	g(state, 2, 6, 10, 14, m[4], m[5]);
+	g(state, 3, 7, 11, 15, m[6], m[7]);
+	// mix the diagonals
+	g(state, 0, 5, 10, 15, m[8], m[9]);
+	g(state, 1, 6, 11, 12, m[10], m[11]);
+	g(state, 2, 7, 8, 13, m[12], m[13]);
+	g(state, 3, 4, 9, 14, m[14], m[15]);
+};
+
+const permute = (m: Uint32Array) => {
+	const permuted = new Uint32Array(16);
+	for (let i = 0; i < 16; i++) {
+		permuted[i] = m[MSG_PERMUTATION[i]];
+	}
+	m.set(permuted);
+};
+
+const compress = (
+	chainingValue: Uint32Array,
+	blockWords: Uint32Array,
+	counter: number,
+	blockLen: number,
+	flags: number
+): Uint32Array => {
+	const state = new Uint32Array(16);
+	state.set(chainingValue, 0);
+	state.set(IV.subarray(0, 4), 8);
+	state[12] = counter >>> 0;
+	state[13] = Math.floor(counter / 0x100000000);
+	state[14] = blockLen;
+	state[15] = flags;
+	const block = blockWords.slice();
+	for (let i = 0; i < 7; i++) {
+		round(state, block);
+		if (i < 6) permute(block);
+	}
+	for (let i = 0; i < 8; i++) {
+		state[i] ^= state[i + 8];
+		state[i + 8] ^= chainingValue[i];
+	}
+	return state;
+};
+
+const wordsFromLittleEndianBytes = (bytes: Uint8Array): Uint32Array => {
+	const words = new Uint32Array(16);
+	for (let i = 0; i < 16; i++) {
+		words[i] =
+			bytes[i * 4] |
+			(bytes[i * 4 + 1] << 8) |
+			(bytes[i * 4 + 2] << 16) |
+			(bytes[i * 4 + 3] << 24);
+	}
+	return words;
+};
+
+class Output {
+	constructor(
+		public inputChainingValue: Uint32Array,
+		public blockWords: Uint32Array,
+		public counter: number,
+		public blockLen: number,
+		public flags: number
+	) {}
+
+	chainingValue(): Uint32Array {
+		return compress(
+			this.inputChainingValue,
+			this.blockWords,
+			this.counter,
+			this.blockLen,
+			this.flags
+		).subarray(0, 8);
+	}
+
+	rootOutputBytes(): Buffer {
+		const words = compress(
+			this.inputChainingValue,
+			this.blockWords,
+			0,
+			this.blockLen,
+			this.flags | ROOT
+		);
+		const out = Buffer.alloc(OUT_LEN);
+		for (let i = 0; i < OUT_LEN / 4; i++) {
+			out.writeUInt32LE(words[i], i * 4);
+		}
+		return out;
+	}
+}
+
+class ChunkState {
+	chainingValue: Uint32Array;
+	block = new Uint8Array(BLOCK_LEN);
+	blockLen = 0;
+	blocksCompressed = 0;
+
+	constructor(
+		keyWords: Uint32Array,
+		public chunkCounter: number,
+		public flags: number
+	) {
+		this.chainingValue = keyWords.slice();
+	}
+
+	len(): number {
+		return BLOCK_LEN * this.blocksCompressed + this.blockLen;
+	}
+
+	startFlag(): number {
+		return this.blocksCompressed === 0 ? CHUNK_START : 0;
+	}
+
+	update(input: Uint8Array) {
+		let offset = 0;
+		while (offset < input.length) {
+			// if the block buffer is full, compress it and clear it, more input is coming
+			if (this.blockLen === BLOCK_LEN) {
+				this.chainingValue = compress(
+					this.chainingValue,
+					wordsFromLittleEndianBytes(this.block),
+					this.chunkCounter,
+					BLOCK_LEN,
+					this.flags | this.startFlag()
+				).slice(0, 8);
+				this.blocksCompressed += 1;
+				this.block.fill(0);
+				this.blockLen = 0;
+			}
+			const take = Math.min(BLOCK_LEN - this.blockLen, input.length - offset);
+			this.block.set(input.subarray(offset, offset + take), this.blockLen);
+			this.blockLen += take;
+			offset += take;
+		}
+	}
+
+	output(): Output {
+		return new Output(
+			this.chainingValue,
+			wordsFromLittleEndianBytes(this.block),
+			this.chunkCounter,
+			this.blockLen,
+			this.flags | this.startFlag() | CHUNK_END
+		);
+	}
+}
+
+const parentOutput = (
+	leftChildCv: Uint32Array,
+	rightChildCv: Uint32Array,
+	keyWords: Uint32Array,
+	flags: number
+): Output => {
+	const blockWords = new Uint32Array(16);
+	blockWords.set(leftChildCv, 0);
+	blockWords.set(rightChildCv, 8);
+	return new Output(keyWords, blockWords, 0, BLOCK_LEN, PARENT | flags);
+};
+
+class Blake3Hash extends Hash {
+	chunkState: ChunkState;
+	keyWords = IV;
+	cvStack: Uint32Array[] = [];
+	flags = 0;
+
+	constructor() {
+		super();
+		this.chunkState = new ChunkState(this.keyWords, 0, this.flags);
+	}
+
+	/**
+	 * Update hash {@link https://nodejs.org/api/crypto.html#crypto_hash_update_data_inputencoding}
+	 * @param data data
+	 * @param inputEncoding data encoding
+	 * @returns updated hash
+	 */
+	update(data: string | Buffer, inputEncoding?: string): this {
+		const input =
+			typeof data === "string"
+				? Buffer.from(data, inputEncoding as BufferEncoding)
+				: data;
+		let offset = 0;
+		while (offset < input.length) {
+			// if the current chunk is complete, finalize it and reset the chunk state,
+			// more input is coming, so this chunk is not the root
+			if (this.chunkState.len() === CHUNK_LEN) {
+				const chunkCv = this.chunkState.output().chainingValue();
+				const totalChunks = this.chunkState.chunkCounter + 1;
+				this.addChunkChainingValue(chunkCv, totalChunks);
+				this.chunkState = new ChunkState(
+					this.keyWords,
+					totalChunks,
+					this.flags
+				);
+			}
+			const take = Math.min(
+				CHUNK_LEN - this.chunkState.len(),
+				input.length - offset
+			);
+			this.chunkState.update(input.subarray(offset, offset + take));
+			offset += take;
+		}
+		return this;
+	}
+
+	/**
+	 * Calculates the digest {@link https://nodejs.org/api/crypto.html#crypto_hash_digest_encoding}
+	 * @param encoding encoding of the return value
+	 * @returns digest
+	 */
+	digest(encoding?: BufferEncoding): string | Buffer {
+		let output = this.chunkState.output();
+		for (let i = this.cvStack.length - 1; i >= 0; i--) {
+			output = parentOutput(
+				this.cvStack[i],
+				output.chainingValue(),
+				this.keyWords,
+				this.flags
+			);
+		}
+		const bytes = output.rootOutputBytes();
+		return encoding ? bytes.toString(encoding) : bytes;
+	}
+
+	// merge the completed subtrees, the number of them is the number of 1 bits in `totalChunks`
+	addChunkChainingValue(chunkCv: Uint32Array, totalChunks: number) {
+		let newCv = chunkCv;
+		let total = totalChunks;
+		while (total % 2 === 0) {
+			newCv = parentOutput(
+				this.cvStack.pop()!,
+				newCv,
+				this.keyWords,
+				this.flags
+			).chainingValue();
+			total /= 2;
+		}
+		this.cvStack.push(newCv);
+	}
+}
+
+export default () => new Blake3Hash();
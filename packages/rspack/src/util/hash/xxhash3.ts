@@ -0,0 +1,325 @@
+/**
+ * The following code is modified based on
+ * https://github.com/DoumanAsh/xxhash-rust/blob/v0.8.10/src/xxh3.rs
+ *
+ * BSL-1.0 Licensed
+ * Copyright (c) Douman
+ * https://github.com/DoumanAsh/xxhash-rust/blob/master/LICENSE
+ */
+import Hash from ".";
+
+const MASK_64 = 0xffffffffffffffffn;
+const MASK_32 = 0xffffffffn;
+
+const PRIME32_1 = 0x9e3779b1n;
+const PRIME32_2 = 0x85ebca77n;
+const PRIME32_3 = 0xc2b2ae3dn;
+const PRIME64_1 = 0x9e3779b185ebca87n;
+const PRIME64_2 = 0xc2b2ae3d27d4eb4fn;
+const PRIME64_3 = 0x165667b19e3779f9n;
+const PRIME64_4 = 0x85ebca77c2b2ae63n;
+const PRIME64_5 = 0x27d4eb2f165667c5n;
+
+const STRIPE_LEN = 64;
+const SECRET_CONSUME_RATE = 8;
+const ACC_NB = 8;
+const SECRET_MERGEACCS_START = 11;
+const SECRET_LASTACC_START = 7;
+const MID_SIZE_MAX = 240;
+const SECRET_SIZE_MIN = 136;
+
+const SECRET = Buffer.from(
+	"b8fe6c3923a44bbe7c01812cf721ad1cded46de9839097db7240a4a4b7b3671fcb79e64eccc0e578825ad07dccff7221b8084674f743248ee03590e6813a264c3c2852bb91c300cb88d0658b1b532ea371644897a20df94e3819ef46a9deacd8a8fa763fe39c343ff9dcbbc7c70b4f1d8a51e04bcdb45931c89f7ec9d9787364eac5ac8334d3ebc3c581a0fffa1363eb170ddd51b7f0da49d316552629d4689e2b16be587d47a1fc8ff8b8d17ad031ce45cb3a8f95160428afd7fbcabb4b407e",
+	"hex"
+);
+
+const mul64 = (left: bigint, right: bigint) => (left * right) & MASK_64;
+
+const mul128Fold64 = (left: bigint, right: bigint) => {
+	const product = left * right;
+	return (product & MASK_64) ^ ((product >> 64n) & MASK_64);
+};
+
+const swap64 = (value: bigint) => {
+	const buffer = Buffer.alloc(8);
+	buffer.writeBigUInt64LE(value);
+	return buffer.readBigUInt64BE();
+};
+
+const rotl32 = (value: bigint, n: bigint) =>
+	((value << n) | (value >> (32n - n))) & MASK_32;
+
+const xorshift64 = (value: bigint, shift: bigint) => value ^ (value >> shift);
+
+const avalanche = (value: bigint) =>
+	xorshift64(mul64(xorshift64(value, 37n), 0x165667919e3779f9n), 32n);
+
+const xxh64Avalanche = (value: bigint) => {
+	let h = value;
+	h = mul64(h ^ (h >> 33n), PRIME64_2);
+	h = mul64(h ^ (h >> 29n), PRIME64_3);
+	return h ^ (h >> 32n);
+};
+
+const read64 = (buffer: Buffer, offset: number) =>
+	buffer.readBigUInt64LE(offset);
+
+const read32 = (buffer: Buffer, offset: number) =>
+	BigInt(buffer.readUInt32LE(offset));
+
+const mix16B = (input: Buffer, inputOffset: number, secretOffset: number) =>
+	mul128Fold64(
+		read64(input, inputOffset) ^ read64(SECRET, secretOffset),
+		read64(input, inputOffset + 8) ^ read64(SECRET, secretOffset + 8)
+	);
+
+const mix32B = (
+	acc: [bigint, bigint],
+	input: Buffer,
+	input1: number,
+	input2: number,
+	secretOffset: number
+) => {
+	acc[0] = (acc[0] + mix16B(input, input1, secretOffset)) & MASK_64;
+	acc[0] ^= (read64(input, input2) + read64(input, input2 + 8)) & MASK_64;
+	acc[1] = (acc[1] + mix16B(input, input2, secretOffset + 16)) & MASK_64;
+	acc[1] ^= (read64(input, input1) + read64(input, input1 + 8)) & MASK_64;
+};
+
+const hash0To16 = (input: Buffer): [bigint, bigint] => {
+	const len = input.length;
+	if (len > 8) {
+		const flipLo = read64(SECRET, 32) ^ read64(SECRET, 40);
+		const flipHi = read64(SECRET, 48) ^ read64(SECRET, 56);
+		const inputLo = read64(input, 0);
+		let inputHi = read64(input, len - 8);
+		let product = (inputLo ^ inputHi ^ flipLo) * PRIME64_1;
+		let mulLow = product & MASK_64;
+		let mulHigh = product >> 64n;
+		mulLow = (mulLow + (BigInt(len - 1) << 54n)) & MASK_64;
+		inputHi ^= flipHi;
+		mulHigh =
+			(mulHigh + inputHi + (inputHi & MASK_32) * (PRIME32_2 - 1n)) & MASK_64;
+		mulLow ^= swap64(mulHigh);
+		product = mulLow * PRIME64_2;
+		const resultLo = product & MASK_64;
+		const resultHi = ((product >> 64n) + mulHigh * PRIME64_2) & MASK_64;
+		return [avalanche(resultLo), avalanche(resultHi)];
+	}
+	if (len >= 4) {
+		const inputLo = read32(input, 0);
+		const inputHi = read32(input, len - 4);
+		const input64 = inputLo + (inputHi << 32n);
+		const flip = read64(SECRET, 16) ^ read64(SECRET, 24);
+		const product = (input64 ^ flip) * ((PRIME64_1 + BigInt(len << 2)) & MASK_64);
+		let lo = product & MASK_64;
+		let hi = product >> 64n;
+		hi = (hi + (lo << 1n)) & MASK_64;
+		lo ^= hi >> 3n;
+		lo = mul64(xorshift64(lo, 35n), 0x9fb21c651e98df25n);
+		lo = xorshift64(lo, 28n);
+		return [lo, avalanche(hi)];
+	}
+	if (len > 0) {
+		const c1 = BigInt(input[0]);
+		const c2 = BigInt(input[len >> 1]);
+		const c3 = BigInt(input[len - 1]);
+		const inputLo = (c1 << 16n) | (c2 << 24n) | c3 | (BigInt(len) << 8n);
+		const swapped =
+			((inputLo & 0xffn) << 24n) |
+			((inputLo & 0xff00n) << 8n) |
+			((inputLo >> 8n) & 0xff00n) |
+			(inputLo >> 24n);
+		const inputHi = rotl32(swapped, 13n);
+		const flipLo = read32(SECRET, 0) ^ read32(SECRET, 4);
+		const flipHi = read32(SECRET, 8) ^ read32(SECRET, 12);
+		return [xxh64Avalanche(inputLo ^ flipLo), xxh64Avalanche(inputHi ^ flipHi)];
+	}
+	const flipLo = read64(SECRET, 64) ^ read64(SECRET, 72);
+	const flipHi = read64(SECRET, 80) ^ read64(SECRET, 88);
+	return [xxh64Avalanche(flipLo), xxh64Avalanche(flipHi)];
+};
+
+const finishMid = (acc: [bigint, bigint], len: number): [bigint, bigint] => {
+	const [lo, hi] = acc;
+	const resultLo = (lo + hi) & MASK_64;
+	const resultHi =
+		(lo * PRIME64_1 + hi * PRIME64_4 + BigInt(len) * PRIME64_2) & MASK_64;
+	return [avalanche(resultLo), (0n - avalanche(resultHi)) & MASK_64];
+};
+
+const hash17To128 = (input: Buffer): [bigint, bigint] => {
+	const len = input.length;
+	const acc: [bigint, bigint] = [mul64(BigInt(len), PRIME64_1), 0n];
+	if (len > 32) {
+		if (len > 64) {
+			if (len > 96) {
+				mix32B(acc, input, 48, len - 64, 96);
+			}
+			mix32B(acc, input, 32, len - 48, 64);
+		}
+		mix32B(acc, input, 16, len - 32, 32);
+	}
+	mix32B(acc, input, 0, len - 16, 0);
+	return finishMid(acc, len);
+};
+
+const hash129To240 = (input: Buffer): [bigint, bigint] => {
+	const START_OFFSET = 3;
+	const LAST_OFFSET = 17;
+	const len = input.length;
+	const nbRounds = Math.floor(len / 32);
+	const acc: [bigint, bigint] = [mul64(BigInt(len), PRIME64_1), 0n];
+	for (let idx = 0; idx < 4; idx++) {
+		mix32B(acc, input, 32 * idx, 32 * idx + 16, 32 * idx);
+	}
+	acc[0] = avalanche(acc[0]);
+	acc[1] = avalanche(acc[1]);
+	for (let idx = 4; idx < nbRounds; idx++) {
+		mix32B(
+			acc,
+			input,
+			32 * idx,
+			32 * idx + 16,
+			START_OFFSET + 32 * (idx - 4)
+		);
+	}
+	mix32B(
+		acc,
+		input,
+		len - 16,
+		len - 32,
+		SECRET_SIZE_MIN - LAST_OFFSET - 16
+	);
+	return finishMid(acc, len);
+};
+
+const accumulate512 = (
+	acc: bigint[],
+	input: Buffer,
+	inputOffset: number,
+	secretOffset: number
+) => {
+	for (let idx = 0; idx < ACC_NB; idx++) {
+		const dataVal = read64(input, inputOffset + 8 * idx);
+		const dataKey = dataVal ^ read64(SECRET, secretOffset + 8 * idx);
+		acc[idx ^ 1] = (acc[idx ^ 1] + dataVal) & MASK_64;
+		acc[idx] = (acc[idx] + (dataKey & MASK_32) * (dataKey >> 32n)) & MASK_64;
+	}
+};
+
+const scrambleAcc = (acc: bigint[], secretOffset: number) => {
+	for (let idx = 0; idx < ACC_NB; idx++) {
+		const key = read64(SECRET, secretOffset + 8 * idx);
+		acc[idx] = mul64(xorshift64(acc[idx], 47n) ^ key, PRIME32_1);
+	}
+};
+
+const mergeAccs = (acc: bigint[], secretOffset: number, start: bigint) => {
+	let result = start;
+	for (let idx = 0; idx < 4; idx++) {
+		const offset = secretOffset + 16 * idx;
+		result =
+			(result +
+				mul128Fold64(
+					acc[2 * idx] ^ read64(SECRET, offset),
+					acc[2 * idx + 1] ^ read64(SECRET, offset + 8)
+				)) &
+			MASK_64;
+	}
+	return avalanche(result);
+};
+
+const hashLong = (input: Buffer): [bigint, bigint] => {
+	const len = input.length;
+	const acc = [
+		PRIME32_3,
+		PRIME64_1,
+		PRIME64_2,
+		PRIME64_3,
+		PRIME64_4,
+		PRIME32_2,
+		PRIME64_5,
+		PRIME32_1
+	];
+	const stripesPerBlock = (SECRET.length - STRIPE_LEN) / SECRET_CONSUME_RATE;
+	const blockLen = STRIPE_LEN * stripesPerBlock;
+	const nbBlocks = Math.floor((len - 1) / blockLen);
+	for (let block = 0; block < nbBlocks; block++) {
+		for (let stripe = 0; stripe < stripesPerBlock; stripe++) {
+			accumulate512(
+				acc,
+				input,
+				block * blockLen + stripe * STRIPE_LEN,
+				stripe * SECRET_CONSUME_RATE
+			);
+		}
+		scrambleAcc(acc, SECRET.length - STRIPE_LEN);
+	}
+	// last partial block
+	const nbStripes = Math.floor((len - 1 - blockLen * nbBlocks) / STRIPE_LEN);
+	for (let stripe = 0; stripe < nbStripes; stripe++) {
+		accumulate512(
+			acc,
+			input,
+			nbBlocks * blockLen + stripe * STRIPE_LEN,
+			stripe * SECRET_CONSUME_RATE
+		);
+	}
+	// last stripe
+	accumulate512(
+		acc,
+		input,
+		len - STRIPE_LEN,
+		SECRET.length - STRIPE_LEN - SECRET_LASTACC_START
+	);
+	const lo = mergeAccs(acc, SECRET_MERGEACCS_START, mul64(BigInt(len), PRIME64_1));
+	const hi = mergeAccs(
+		acc,
+		SECRET.length - ACC_NB * 8 - SECRET_MERGEACCS_START,
+		~mul64(BigInt(len), PRIME64_2) & MASK_64
+	);
+	return [lo, hi];
+};
+
+const xxh3128 = (input: Buffer): [bigint, bigint] => {
+	if (input.length <= 16) return hash0To16(input);
+	if (input.length <= 128) return hash17To128(input);
+	if (input.length <= MID_SIZE_MAX) return hash129To240(input);
+	return hashLong(input);
+};
+
+class Xxhash3Hash extends Hash {
+	buffers: Buffer[] = [];
+
+	/**
+	 * Update hash {@link https://nodejs.org/api/crypto.html#crypto_hash_update_data_inputencoding}
+	 * @param data data
+	 * @param inputEncoding data encoding
+	 * @returns updated hash
+	 */
+	update(data: string | Buffer, inputEncoding?: string): this {
+		this.buffers.push(
+			typeof data === "string"
+				? Buffer.from(data, inputEncoding as BufferEncoding)
+				: data
+		);
+		return this;
+	}
+
+	/**
+	 * Calculates the digest {@link https://nodejs.org/api/crypto.html#crypto_hash_digest_encoding}
+	 * @param encoding encoding of the return value
+	 * @returns digest
+	 */
+	digest(encoding?: BufferEncoding): string | Buffer {
+		const [lo, hi] = xxh3128(Buffer.concat(this.buffers));
+		// big endian, the same as the digest of rspack_hash
+		const bytes = Buffer.alloc(16);
+		bytes.writeBigUInt64BE(hi, 0);
+		bytes.writeBigUInt64BE(lo, 8);
+		return encoding ? bytes.toString(encoding) : bytes;
+	}
+}
+
+export default () => new Xxhash3Hash();
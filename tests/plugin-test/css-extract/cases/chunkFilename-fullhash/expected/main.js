@@ -42,9 +42,9 @@ __webpack_require__.m = __webpack_modules__;
 __webpack_require__.f = {};
 // This file contains only the entry chunk.
 // The chunk loading function for additional chunks
-__webpack_require__.e = function (chunkId) {
+__webpack_require__.e = (chunkId) => {
 	return Promise.all(
-		Object.keys(__webpack_require__.f).reduce(function (promises, key) {
+		Object.keys(__webpack_require__.f).reduce((promises, key) => {
 			__webpack_require__.f[key](chunkId, promises);
 			return promises;
 		}, [])
@@ -106,7 +106,7 @@ var inProgress = {};
 
 
 // loadScript function to load a script via script tag
-__webpack_require__.l = function (url, done, key, chunkId) {
+__webpack_require__.l = (url, done, key, chunkId) => {
 	if (inProgress[url]) {
 		inProgress[url].push(done);
 		return;
@@ -138,14 +138,14 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 		
 	}
 	inProgress[url] = [done];
-	var onScriptComplete = function (prev, event) {
+	var onScriptComplete = (prev, event) => {
 		script.onerror = script.onload = null;
 		clearTimeout(timeout);
 		var doneFns = inProgress[url];
 		delete inProgress[url];
 		script.parentNode && script.parentNode.removeChild(script);
 		doneFns &&
-			doneFns.forEach(function (fn) {
+			doneFns.forEach((fn) => {
 				return fn(event);
 			});
 		if (prev) return prev(event);
@@ -317,7 +317,7 @@ if (installedChunkData !== 0) {
 	} else {
 		if (true) {
 			// setup Promise in chunk cache
-			var promise = new Promise(function (resolve, reject) {
+			var promise = new Promise((resolve, reject) => {
 				installedChunkData = installedChunks[chunkId] = [resolve, reject];
 			});
 			promises.push((installedChunkData[2] = promise));
@@ -326,22 +326,16 @@ if (installedChunkData !== 0) {
 			var url = __webpack_require__.p + __webpack_require__.u(chunkId);
 			// create error before stack unwound to get useful stacktrace later
 			var error = new Error();
-			var loadingEnded = function (event) {
+			var loadingEnded = (event) => {
 				if (__webpack_require__.o(installedChunks, chunkId)) {
 					installedChunkData = installedChunks[chunkId];
 					if (installedChunkData !== 0) installedChunks[chunkId] = undefined;
 					if (installedChunkData) {
 						var errorType =
 							event && (event.type === 'load' ? 'missing' : event.type);
-						var realSrc = event && event.target && event.target.src;
+						var realSrc = event?.target?.src;
 						error.message =
-							'Loading chunk ' +
-							chunkId +
-							' failed.\n(' +
-							errorType +
-							': ' +
-							realSrc +
-							')';
+							`Loading chunk ${chunkId} failed.\n(${errorType}: ${realSrc})`;
 						error.name = 'ChunkLoadError';
 						error.type = errorType;
 						error.request = realSrc;
@@ -356,16 +350,14 @@ if (installedChunkData !== 0) {
 
         }
         // install a JSONP callback for chunk loading
-var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
-	var chunkIds = data[0];
-	var moreModules = data[1];
-	var runtime = data[2];
+var webpackJsonpCallback = (parentChunkLoadingFunction, data) => {
+	var [chunkIds, moreModules, runtime] = data;
 	// add "moreModules" to the modules object,
 	// then flag all "chunkIds" as loaded and fire callback
 	var moduleId,
 		chunkId,
 		i = 0;
-	if (chunkIds.some(function (id) { return installedChunks[id] !== 0 })) {
+	if (chunkIds.some((id) => { return installedChunks[id] !== 0 })) {
 		for (moduleId in moreModules) {
 			if (__webpack_require__.o(moreModules, moduleId)) {
 				__webpack_require__.m[moduleId] = moreModules[moduleId];
@@ -374,8 +366,7 @@ var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
 		if (runtime) var result = runtime(__webpack_require__);
 	}
 	if (parentChunkLoadingFunction) parentChunkLoadingFunction(data);
-	for (; i < chunkIds.length; i++) {
-		chunkId = chunkIds[i];
+	for (chunkId of chunkIds) {
 		if (
 			__webpack_require__.o(installedChunks, chunkId) &&
 			installedChunks[chunkId]
@@ -106,16 +106,14 @@ __webpack_require__.O = function (result, chunkIds, fn, priority) {
 	return installedChunks[chunkId] === 0;
 };
 // install a JSONP callback for chunk loading
-var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
-	var chunkIds = data[0];
-	var moreModules = data[1];
-	var runtime = data[2];
+var webpackJsonpCallback = (parentChunkLoadingFunction, data) => {
+	var [chunkIds, moreModules, runtime] = data;
 	// add "moreModules" to the modules object,
 	// then flag all "chunkIds" as loaded and fire callback
 	var moduleId,
 		chunkId,
 		i = 0;
-	if (chunkIds.some(function (id) { return installedChunks[id] !== 0 })) {
+	if (chunkIds.some((id) => { return installedChunks[id] !== 0 })) {
 		for (moduleId in moreModules) {
 			if (__webpack_require__.o(moreModules, moduleId)) {
 				__webpack_require__.m[moduleId] = moreModules[moduleId];
@@ -124,8 +122,7 @@ var webpackJsonpCallback = function (parentChunkLoadingFunction, data) {
 		if (runtime) var result = runtime(__webpack_require__);
 	}
 	if (parentChunkLoadingFunction) parentChunkLoadingFunction(data);
-	for (; i < chunkIds.length; i++) {
-		chunkId = chunkIds[i];
+	for (chunkId of chunkIds) {
 		if (
 			__webpack_require__.o(installedChunks, chunkId) &&
 			installedChunks[chunkId]
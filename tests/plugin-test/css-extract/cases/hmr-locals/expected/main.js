@@ -305,7 +305,7 @@ __webpack_require__.i = [];
 /************************************************************************/
 // webpack/runtime/define_property_getters
 (() => {
-__webpack_require__.d = function(exports, definition) {
+__webpack_require__.d = (exports, definition) => {
 	for(var key in definition) {
         if(__webpack_require__.o(definition, key) && !__webpack_require__.o(exports, key)) {
             Object.defineProperty(exports, key, { enumerable: true, get: definition[key] });
@@ -734,7 +734,7 @@ var inProgress = {};
 
 
 // loadScript function to load a script via script tag
-__webpack_require__.l = function (url, done, key, chunkId) {
+__webpack_require__.l = (url, done, key, chunkId) => {
 	if (inProgress[url]) {
 		inProgress[url].push(done);
 		return;
@@ -766,14 +766,14 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 		
 	}
 	inProgress[url] = [done];
-	var onScriptComplete = function (prev, event) {
+	var onScriptComplete = (prev, event) => {
 		script.onerror = script.onload = null;
 		clearTimeout(timeout);
 		var doneFns = inProgress[url];
 		delete inProgress[url];
 		script.parentNode && script.parentNode.removeChild(script);
 		doneFns &&
-			doneFns.forEach(function (fn) {
+			doneFns.forEach((fn) => {
 				return fn(event);
 			});
 		if (prev) return prev(event);
@@ -721,7 +721,7 @@ var inProgress = {};
 
 
 // loadScript function to load a script via script tag
-__webpack_require__.l = function (url, done, key, chunkId) {
+__webpack_require__.l = (url, done, key, chunkId) => {
 	if (inProgress[url]) {
 		inProgress[url].push(done);
 		return;
@@ -753,14 +753,14 @@ __webpack_require__.l = function (url, done, key, chunkId) {
 		
 	}
 	inProgress[url] = [done];
-	var onScriptComplete = function (prev, event) {
+	var onScriptComplete = (prev, event) => {
 		script.onerror = script.onload = null;
 		clearTimeout(timeout);
 		var doneFns = inProgress[url];
 		delete inProgress[url];
 		script.parentNode && script.parentNode.removeChild(script);
 		doneFns &&
-			doneFns.forEach(function (fn) {
+			doneFns.forEach((fn) => {
 				return fn(event);
 			});
 		if (prev) return prev(event);
@@ -1,11 +0,0 @@
-
-/*
-module.exports = function(config) {
-	// This test can't run in development mode as it depends on the flagIncludedChunks optimization
-	return config.mode !== "development";
-};
-
-*/
-module.exports = () => {return "https://github.com/web-infra-dev/rspack/issues/4304"}
-
-							
\ No newline at end of file
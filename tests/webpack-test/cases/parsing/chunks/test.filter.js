@@ -1,11 +0,0 @@
-/*
-var supportsES6 = require("../../../helpers/supportsES6");
-
-module.exports = function (config) {
-	return supportsES6();
-};
-
-*/
-module.exports = () => {
-	return "https://github.com/web-infra-dev/rspack/issues/4304";
-};
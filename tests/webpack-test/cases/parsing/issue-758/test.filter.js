@@ -1,4 +0,0 @@
-
-module.exports = () => {return "https://github.com/web-infra-dev/rspack/issues/4313, https://github.com/web-infra-dev/rspack/issues/4304"}
-
-							
\ No newline at end of file
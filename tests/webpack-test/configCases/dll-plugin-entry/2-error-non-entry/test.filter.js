@@ -1 +0,0 @@
-module.exports = () => {return false}
\ No newline at end of file